@@ -0,0 +1,317 @@
+// 자동화용 로컬 HTTP/JSON API (설정의 api.enabled)
+//
+// 127.0.0.1에서만 요청을 받으며 모든 요청에 `Authorization: Bearer <토큰>` 헤더가 필요하다.
+// 처리는 Tauri 커맨드와 같은 함수를 사용하므로 열린 윈도우에도 같은 이벤트가 전달된다.
+//
+// GET    /notes?sort=title&includeArchived=true  노트 목록
+// GET    /notes/{id}                              노트 메타데이터와 본문
+// POST   /notes        {"content": "..."}         새 노트
+// PUT    /notes/{id}   {"content": "..."}         노트 저장
+// DELETE /notes/{id}                              노트 삭제
+// GET    /search?q=...&includeArchived=false      검색
+
+use crate::settings::{read_settings, write_settings};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::Emitter;
+use tiny_http::{Header, Request, Response, Server};
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(2);
+// 요청 본문 최대 크기
+const MAX_BODY: u64 = 10 * 1024 * 1024;
+
+#[derive(Debug, PartialEq)]
+enum Route {
+    ListNotes,
+    GetNote(String),
+    CreateNote,
+    UpdateNote(String),
+    DeleteNote(String),
+    Search,
+}
+
+#[derive(Deserialize)]
+struct NoteBody {
+    content: String,
+}
+
+// 요청 경로를 처리 함수로 연결, 실패하면 HTTP 상태 코드
+fn route(method: &str, path: &str) -> Result<Route, u16> {
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    match (method, segments.as_slice()) {
+        ("GET", ["notes"]) => Ok(Route::ListNotes),
+        ("POST", ["notes"]) => Ok(Route::CreateNote),
+        ("GET", ["notes", id]) => Ok(Route::GetNote(id.to_string())),
+        ("PUT", ["notes", id]) => Ok(Route::UpdateNote(id.to_string())),
+        ("DELETE", ["notes", id]) => Ok(Route::DeleteNote(id.to_string())),
+        ("GET", ["search"]) => Ok(Route::Search),
+        (_, ["notes"] | ["notes", _] | ["search"]) => Err(405),
+        _ => Err(404),
+    }
+}
+
+// 쿼리 문자열 해석 ("a=1&b=%ED%95%9C" -> {a: 1, b: 한})
+pub(crate) fn parse_query(query: &str) -> BTreeMap<String, String> {
+    let decode = |s: &str| {
+        percent_encoding::percent_decode_str(&s.replace('+', " "))
+            .decode_utf8_lossy()
+            .to_string()
+    };
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| match pair.split_once('=') {
+            Some((key, value)) => (decode(key), decode(value)),
+            None => (decode(pair), String::new()),
+        })
+        .collect()
+}
+
+// Bearer 토큰 확인 (길이가 같으면 항상 전체를 비교)
+fn authorized(header: Option<&str>, token: &str) -> bool {
+    let Some(given) = header.and_then(|h| h.trim().strip_prefix("Bearer ")) else {
+        return false;
+    };
+    let (given, token) = (given.trim().as_bytes(), token.as_bytes());
+    !token.is_empty()
+        && given.len() == token.len()
+        && given.iter().zip(token).fold(0, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
+// 커맨드 오류를 HTTP 상태 코드로 변환
+fn error_status(error: &str) -> u16 {
+    match error {
+        "Note not found" => 404,
+        crate::encryption::LOCKED | crate::vault::LIBRARY_LOCKED => 423,
+        _ => 500,
+    }
+}
+
+fn parse_body(body: &str) -> Result<NoteBody, (u16, String)> {
+    serde_json::from_str(body).map_err(|e| (400, format!("Invalid request body: {}", e)))
+}
+
+fn to_json<T: Serialize>(value: &T) -> Result<Value, (u16, String)> {
+    serde_json::to_value(value).map_err(|e| (500, e.to_string()))
+}
+
+fn handle(
+    app: &tauri::AppHandle,
+    route: Route,
+    query: &BTreeMap<String, String>,
+    body: &str,
+) -> Result<(u16, Value), (u16, String)> {
+    let command_error = |e: String| (error_status(&e), e);
+    let include_archived = query.get("includeArchived").map(|v| v == "true");
+
+    match route {
+        Route::ListNotes => {
+            let sort = match query.get("sort") {
+                Some(sort) => Some(
+                    serde_json::from_value(Value::String(sort.clone()))
+                        .map_err(|_| (400, format!("Unknown sort: {}", sort)))?,
+                ),
+                None => None,
+            };
+            let index = crate::get_notes_list(sort, include_archived).map_err(command_error)?;
+            Ok((200, to_json(&index.notes)?))
+        }
+        Route::GetNote(id) => {
+            let note = crate::read_index()
+                .map_err(command_error)?
+                .notes
+                .into_iter()
+                .find(|n| n.id == id)
+                .ok_or_else(|| command_error("Note not found".to_string()))?;
+            let content = crate::load_note_content(app.clone(), id).map_err(command_error)?;
+            Ok((200, json!({ "note": to_json(&note)?, "content": content })))
+        }
+        Route::CreateNote => {
+            let body = parse_body(body)?;
+            let id = crate::generate_new_note_id();
+            let note =
+                crate::save_new_note(app.clone(), id, body.content).map_err(command_error)?;
+            Ok((201, to_json(&note)?))
+        }
+        Route::UpdateNote(id) => {
+            let body = parse_body(body)?;
+            let note = crate::save_note_by_id(app.clone(), id.clone(), body.content)
+                .map_err(command_error)?;
+            // 열린 노트 윈도우가 바뀐 내용을 다시 불러오도록 알림
+            let _ = app.emit("note-file-changed", &id);
+            Ok((200, to_json(&note)?))
+        }
+        Route::DeleteNote(id) => {
+            crate::delete_note(app.clone(), id.clone()).map_err(command_error)?;
+            Ok((200, json!({ "deleted": id })))
+        }
+        Route::Search => {
+            let q = query.get("q").cloned().unwrap_or_default();
+            let notes = crate::search_notes(q, include_archived).map_err(command_error)?;
+            Ok((200, to_json(&notes)?))
+        }
+    }
+}
+
+fn respond(request: Request, status: u16, body: Value) {
+    let header =
+        Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..]).expect("valid header");
+    let response = Response::from_string(body.to_string())
+        .with_status_code(status)
+        .with_header(header);
+    let _ = request.respond(response);
+}
+
+fn serve(app: &tauri::AppHandle, mut request: Request, token: &str) {
+    let authorization = request
+        .headers()
+        .iter()
+        .find(|h| h.field.equiv("Authorization"))
+        .map(|h| h.value.as_str().to_string());
+    if !authorized(authorization.as_deref(), token) {
+        return respond(request, 401, json!({ "error": "Unauthorized" }));
+    }
+
+    let url = request.url().to_string();
+    let (path, query) = url.split_once('?').unwrap_or((url.as_str(), ""));
+    let route = match route(request.method().as_str(), path) {
+        Ok(route) => route,
+        Err(405) => return respond(request, 405, json!({ "error": "Method not allowed" })),
+        Err(status) => return respond(request, status, json!({ "error": "Not found" })),
+    };
+
+    let mut body = String::new();
+    if let Err(e) = request.as_reader().take(MAX_BODY).read_to_string(&mut body) {
+        return respond(request, 400, json!({ "error": e.to_string() }));
+    }
+
+    match handle(app, route, &parse_query(query), &body) {
+        Ok((status, value)) => respond(request, status, value),
+        Err((status, error)) => respond(request, status, json!({ "error": error })),
+    }
+}
+
+// 토큰이 없으면 새로 만들어 설정에 저장
+fn ensure_token() -> Result<String, String> {
+    let mut settings = read_settings()?;
+    if let Some(token) = settings.api.token.clone().filter(|t| !t.is_empty()) {
+        return Ok(token);
+    }
+    let token = new_token();
+    settings.api.token = Some(token.clone());
+    write_settings(&settings)?;
+    Ok(token)
+}
+
+fn new_token() -> String {
+    format!(
+        "{}{}",
+        uuid::Uuid::new_v4().simple(),
+        uuid::Uuid::new_v4().simple()
+    )
+}
+
+// API 토큰 재발급 커맨드 (이전 토큰은 바로 무효)
+#[tauri::command]
+pub fn regenerate_api_token(app: tauri::AppHandle) -> Result<String, String> {
+    let mut settings = read_settings()?;
+    let token = new_token();
+    settings.api.token = Some(token.clone());
+    write_settings(&settings)?;
+
+    let _ = app.emit("settings-changed", ());
+    Ok(token)
+}
+
+// 설정에 따라 API 서버를 시작/중지 (포트가 바뀌면 다시 시작)
+pub fn start_server(app: tauri::AppHandle) {
+    std::thread::spawn(move || {
+        let mut running: Option<(u16, Arc<Server>)> = None;
+        loop {
+            let settings = read_settings().map(|s| s.api).unwrap_or_default();
+            let wanted = settings.enabled.then_some(settings.port);
+
+            if running.as_ref().map(|(port, _)| *port) != wanted {
+                if let Some((_, server)) = running.take() {
+                    server.unblock();
+                    println!("API server stopped");
+                }
+                if let Some(port) = wanted {
+                    if let Err(e) = ensure_token() {
+                        println!("Failed to create API token: {}", e);
+                    }
+                    match Server::http(("127.0.0.1", port)) {
+                        Ok(server) => {
+                            let server = Arc::new(server);
+                            let worker = server.clone();
+                            let app = app.clone();
+                            std::thread::spawn(move || {
+                                for request in worker.incoming_requests() {
+                                    // 토큰 재발급이 바로 반영되도록 요청마다 확인
+                                    match ensure_token() {
+                                        Ok(token) => serve(&app, request, &token),
+                                        Err(e) => respond(request, 500, json!({ "error": e })),
+                                    }
+                                }
+                            });
+                            println!("API server listening on 127.0.0.1:{}", port);
+                            running = Some((port, server));
+                        }
+                        Err(e) => println!("Failed to start API server on port {}: {}", port, e),
+                    }
+                }
+            }
+            std::thread::sleep(CHECK_INTERVAL);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_route() {
+        assert_eq!(route("GET", "/notes"), Ok(Route::ListNotes));
+        assert_eq!(route("GET", "/notes/"), Ok(Route::ListNotes));
+        assert_eq!(route("POST", "/notes"), Ok(Route::CreateNote));
+        assert_eq!(
+            route("GET", "/notes/abc"),
+            Ok(Route::GetNote("abc".to_string()))
+        );
+        assert_eq!(
+            route("PUT", "/notes/abc"),
+            Ok(Route::UpdateNote("abc".to_string()))
+        );
+        assert_eq!(
+            route("DELETE", "/notes/abc"),
+            Ok(Route::DeleteNote("abc".to_string()))
+        );
+        assert_eq!(route("GET", "/search"), Ok(Route::Search));
+        assert_eq!(route("DELETE", "/notes"), Err(405));
+        assert_eq!(route("GET", "/notes/abc/content"), Err(404));
+        assert_eq!(route("GET", "/"), Err(404));
+    }
+
+    #[test]
+    fn test_parse_query() {
+        let query =
+            parse_query("q=%ED%9A%8C%EC%9D%98+%EB%A9%94%EB%AA%A8&includeArchived=true&flag");
+        assert_eq!(query["q"], "회의 메모");
+        assert_eq!(query["includeArchived"], "true");
+        assert_eq!(query["flag"], "");
+    }
+
+    #[test]
+    fn test_authorized() {
+        assert!(authorized(Some("Bearer secret"), "secret"));
+        assert!(!authorized(Some("Bearer secreT"), "secret"));
+        assert!(!authorized(Some("secret"), "secret"));
+        assert!(!authorized(None, "secret"));
+        assert!(!authorized(Some("Bearer "), ""));
+    }
+}
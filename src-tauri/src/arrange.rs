@@ -0,0 +1,458 @@
+// 노트 윈도우 정렬과 스냅
+//
+// 열려 있는 노트 윈도우(note_*)를 바둑판/계단식으로 배치하거나, 화면 가장자리를 따라 쌓거나,
+// 한쪽 가장자리에 맞춰 정렬한다. ids가 없으면 화면에 보이는 노트 윈도우 전체가 대상이다.
+// 바둑판/계단식/쌓기는 각 윈도우가 있는 모니터별로 그 모니터의 작업 영역 안에 배치한다.
+// 드래그가 끝나면 (프론트엔드에서 마우스 버튼을 놓은 뒤 호출) 화면 가장자리나 다른 노트 가장자리에 붙인다.
+// 옮긴 위치와 크기는 각 윈도우의 save_window_state로 저장된다.
+
+use crate::placement::{self, Area};
+use crate::settings::read_settings;
+use serde::Deserialize;
+use tauri::{Manager, PhysicalPosition, PhysicalSize, WebviewWindow};
+
+// 윈도우 외곽 (물리 픽셀)
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Frame {
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+}
+
+impl Frame {
+    fn right(&self) -> i32 {
+        self.x + self.width
+    }
+
+    fn bottom(&self) -> i32 {
+        self.y + self.height
+    }
+}
+
+// 쌓거나 정렬할 기준 가장자리
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Edge {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+struct NoteWindow {
+    window: WebviewWindow,
+    frame: Frame,
+}
+
+// 작업 영역을 작업 영역 비율에 맞는 격자로 나눈 칸
+fn tile(area: &Area, count: usize, gap: i32) -> Vec<Frame> {
+    if count == 0 {
+        return Vec::new();
+    }
+    let ratio = area.width as f64 / area.height as f64;
+    let columns = ((count as f64 * ratio).sqrt().ceil() as usize).clamp(1, count);
+    let rows = count.div_ceil(columns);
+    // 마지막 줄이 비지 않도록 열 수를 다시 맞춤 (4개 -> 3+1이 아니라 2x2)
+    let columns = count.div_ceil(rows);
+
+    let width = (area.width - gap * (columns as i32 + 1)) / columns as i32;
+    let height = (area.height - gap * (rows as i32 + 1)) / rows as i32;
+    (0..count)
+        .map(|i| {
+            let (row, column) = ((i / columns) as i32, (i % columns) as i32);
+            Frame {
+                x: area.x + gap + column * (width + gap),
+                y: area.y + gap + row * (height + gap),
+                width,
+                height,
+            }
+        })
+        .collect()
+}
+
+// 크기는 그대로 두고 계단식으로 배치
+fn cascade(area: &Area, frames: &[Frame]) -> Vec<Frame> {
+    let mut occupied = Vec::new();
+    frames
+        .iter()
+        .map(|frame| {
+            let size = (
+                frame.width as f64 / area.scale,
+                frame.height as f64 / area.scale,
+            );
+            let (x, y) = placement::cascade(area, &occupied, size);
+            occupied.push((x, y));
+            Frame { x, y, ..*frame }
+        })
+        .collect()
+}
+
+// 크기는 그대로 두고 가장자리를 따라 쌓기 (끝에 닿으면 안쪽으로 다음 줄 시작)
+fn stack(area: &Area, edge: Edge, frames: &[Frame], gap: i32) -> Vec<Frame> {
+    let vertical = matches!(edge, Edge::Left | Edge::Right);
+    let (start, end) = if vertical {
+        (area.y + gap, area.bottom() - gap)
+    } else {
+        (area.x + gap, area.right() - gap)
+    };
+    // along: 쌓는 방향의 현재 위치, inset: 가장자리에서 현재 줄까지 거리, line: 현재 줄의 두께
+    let (mut along, mut inset, mut line) = (start, gap, 0);
+
+    frames
+        .iter()
+        .map(|frame| {
+            let (length, thickness) = if vertical {
+                (frame.height, frame.width)
+            } else {
+                (frame.width, frame.height)
+            };
+            if along + length > end && along > start {
+                inset += line + gap;
+                along = start;
+                line = 0;
+            }
+            let position = along;
+            along += length + gap;
+            line = line.max(thickness);
+
+            let (x, y) = match edge {
+                Edge::Left => (area.x + inset, position),
+                Edge::Right => (area.right() - inset - frame.width, position),
+                Edge::Top => (position, area.y + inset),
+                Edge::Bottom => (position, area.bottom() - inset - frame.height),
+            };
+            Frame { x, y, ..*frame }
+        })
+        .collect()
+}
+
+// 선택한 윈도우 중 가장 바깥쪽 가장자리에 맞춤
+fn align(frames: &[Frame], edge: Edge) -> Vec<Frame> {
+    let left = frames.iter().map(|f| f.x).min().unwrap_or_default();
+    let right = frames.iter().map(|f| f.right()).max().unwrap_or_default();
+    let top = frames.iter().map(|f| f.y).min().unwrap_or_default();
+    let bottom = frames.iter().map(|f| f.bottom()).max().unwrap_or_default();
+
+    frames
+        .iter()
+        .map(|frame| match edge {
+            Edge::Left => Frame { x: left, ..*frame },
+            Edge::Right => Frame {
+                x: right - frame.width,
+                ..*frame
+            },
+            Edge::Top => Frame { y: top, ..*frame },
+            Edge::Bottom => Frame {
+                y: bottom - frame.height,
+                ..*frame
+            },
+        })
+        .collect()
+}
+
+// 화면 가장자리와 다른 노트 가장자리 중 distance 안에서 가장 가까운 곳에 붙인 위치
+// 정렬 명령과 같은 간격(gap)을 두고 붙이므로 정렬한 배치는 스냅해도 바뀌지 않음
+fn snap(frame: Frame, areas: &[Area], others: &[Frame], distance: i32, gap: i32) -> (i32, i32) {
+    let (width, height) = (frame.width, frame.height);
+    let mut xs = Vec::new();
+    let mut ys = Vec::new();
+
+    for area in areas {
+        xs.extend([
+            area.x,
+            area.x + gap,
+            area.right() - width,
+            area.right() - gap - width,
+        ]);
+        ys.extend([
+            area.y,
+            area.y + gap,
+            area.bottom() - height,
+            area.bottom() - gap - height,
+        ]);
+    }
+    for other in others {
+        // 옆에 붙이거나 가장자리를 맞추려면 세로(가로) 범위가 가까워야 함
+        if frame.y < other.bottom() + distance && frame.bottom() > other.y - distance {
+            xs.extend([
+                other.right() + gap,
+                other.x - gap - width,
+                other.x,
+                other.right() - width,
+            ]);
+        }
+        if frame.x < other.right() + distance && frame.right() > other.x - distance {
+            ys.extend([
+                other.bottom() + gap,
+                other.y - gap - height,
+                other.y,
+                other.bottom() - height,
+            ]);
+        }
+    }
+
+    let nearest = |current: i32, candidates: Vec<i32>| {
+        candidates
+            .into_iter()
+            .min_by_key(|c| (c - current).abs())
+            .filter(|c| (c - current).abs() <= distance)
+            .unwrap_or(current)
+    };
+    (nearest(frame.x, xs), nearest(frame.y, ys))
+}
+
+fn frame_of(window: &WebviewWindow) -> Option<Frame> {
+    let position = window.outer_position().ok()?;
+    let size = window.outer_size().ok()?;
+    Some(Frame {
+        x: position.x,
+        y: position.y,
+        width: size.width as i32,
+        height: size.height as i32,
+    })
+}
+
+// 대상 노트 윈도우 (보이고 최소화되지 않은 것, 위에서 아래로, 왼쪽에서 오른쪽 순)
+fn note_windows(app: &tauri::AppHandle, ids: Option<&[String]>) -> Vec<NoteWindow> {
+    let mut windows: Vec<NoteWindow> = app
+        .webview_windows()
+        .into_iter()
+        .filter(|(label, _)| match (label.strip_prefix("note_"), ids) {
+            (Some(id), Some(ids)) => ids.iter().any(|i| i == id),
+            (Some(_), None) => true,
+            (None, _) => false,
+        })
+        .map(|(_, window)| window)
+        .filter(|w| w.is_visible().unwrap_or(false) && !w.is_minimized().unwrap_or(false))
+        .filter_map(|window| {
+            let frame = frame_of(&window)?;
+            Some(NoteWindow { window, frame })
+        })
+        .collect();
+    windows.sort_by_key(|w| (w.frame.y, w.frame.x));
+    windows
+}
+
+fn move_window(window: &WebviewWindow, frame: Frame, resize: bool) -> Result<(), String> {
+    window
+        .set_position(PhysicalPosition::new(frame.x, frame.y))
+        .map_err(|e| e.to_string())?;
+    if resize {
+        window
+            .set_size(PhysicalSize::new(
+                frame.width.max(1) as u32,
+                frame.height.max(1) as u32,
+            ))
+            .map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+// 각 윈도우가 있는 모니터별로 나누어 배치, 옮긴 윈도우 수 반환
+fn arrange_by_monitor(
+    app: &tauri::AppHandle,
+    ids: Option<&[String]>,
+    resize: bool,
+    layout: impl Fn(&Area, &[Frame], i32) -> Vec<Frame>,
+) -> Result<usize, String> {
+    let gap = read_settings()?.windows.gap as f64;
+    let areas = placement::monitor_areas(app);
+    let windows = note_windows(app, ids);
+
+    for area in &areas {
+        let group: Vec<&NoteWindow> = windows
+            .iter()
+            .filter(|w| {
+                let (x, y) = (
+                    w.frame.x + w.frame.width / 2,
+                    w.frame.y + w.frame.height / 2,
+                );
+                placement::nearest(&areas, x, y).is_some_and(|a| std::ptr::eq(a, area))
+            })
+            .collect();
+        let frames: Vec<Frame> = group.iter().map(|w| w.frame).collect();
+        for (note, frame) in group.iter().zip(layout(area, &frames, area.physical(gap))) {
+            move_window(&note.window, frame, resize)?;
+        }
+    }
+    Ok(windows.len())
+}
+
+// 노트 윈도우 바둑판식 배치 커맨드
+#[tauri::command]
+pub fn tile_note_windows(app: tauri::AppHandle, ids: Option<Vec<String>>) -> Result<usize, String> {
+    arrange_by_monitor(&app, ids.as_deref(), true, |area, frames, gap| {
+        tile(area, frames.len(), gap)
+    })
+}
+
+// 노트 윈도우 계단식 배치 커맨드
+#[tauri::command]
+pub fn cascade_note_windows(
+    app: tauri::AppHandle,
+    ids: Option<Vec<String>>,
+) -> Result<usize, String> {
+    arrange_by_monitor(&app, ids.as_deref(), false, |area, frames, _| {
+        cascade(area, frames)
+    })
+}
+
+// 노트 윈도우를 화면 가장자리를 따라 쌓는 커맨드
+#[tauri::command]
+pub fn stack_note_windows(
+    app: tauri::AppHandle,
+    edge: Edge,
+    ids: Option<Vec<String>>,
+) -> Result<usize, String> {
+    arrange_by_monitor(&app, ids.as_deref(), false, |area, frames, gap| {
+        stack(area, edge, frames, gap)
+    })
+}
+
+// 노트 윈도우 가장자리 맞춤 커맨드 (모니터와 관계없이 선택한 윈도우 전체 기준)
+#[tauri::command]
+pub fn align_note_windows(
+    app: tauri::AppHandle,
+    edge: Edge,
+    ids: Option<Vec<String>>,
+) -> Result<usize, String> {
+    let windows = note_windows(&app, ids.as_deref());
+    let frames: Vec<Frame> = windows.iter().map(|w| w.frame).collect();
+    for (note, frame) in windows.iter().zip(align(&frames, edge)) {
+        move_window(&note.window, frame, false)?;
+    }
+    Ok(windows.len())
+}
+
+// 호출한 노트 윈도우를 가까운 가장자리에 붙이는 커맨드, 옮겼는지 반환
+#[tauri::command]
+pub fn snap_note_window(window: WebviewWindow) -> Result<bool, String> {
+    let settings = read_settings()?.windows;
+    if !settings.snap {
+        return Ok(false);
+    }
+    let frame = frame_of(&window).ok_or("Failed to read window position")?;
+    let scale = window.scale_factor().map_err(|e| e.to_string())?;
+    let physical = |logical: u32| (logical as f64 * scale).round() as i32;
+
+    let app = window.app_handle();
+    let others: Vec<Frame> = note_windows(app, None)
+        .into_iter()
+        .filter(|w| w.window.label() != window.label())
+        .map(|w| w.frame)
+        .collect();
+    let (x, y) = snap(
+        frame,
+        &placement::monitor_areas(app),
+        &others,
+        physical(settings.snap_distance),
+        physical(settings.gap),
+    );
+    if (x, y) == (frame.x, frame.y) {
+        return Ok(false);
+    }
+    window
+        .set_position(PhysicalPosition::new(x, y))
+        .map_err(|e| e.to_string())?;
+    Ok(true)
+}
+
+// 노트 컨텍스트 메뉴의 정렬 항목 처리 (열려 있는 노트 전체 대상)
+pub fn handle_menu_event(app: &tauri::AppHandle, id: &str) {
+    let app = app.clone();
+    let result = match id {
+        "arrange_tile" => tile_note_windows(app, None),
+        "arrange_cascade" => cascade_note_windows(app, None),
+        "arrange_stack_left" => stack_note_windows(app, Edge::Left, None),
+        "arrange_stack_right" => stack_note_windows(app, Edge::Right, None),
+        "arrange_align_left" => align_note_windows(app, Edge::Left, None),
+        "arrange_align_top" => align_note_windows(app, Edge::Top, None),
+        _ => return,
+    };
+    if let Err(e) = result {
+        println!("Failed to arrange note windows: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AREA: Area = Area {
+        x: 0,
+        y: 0,
+        width: 1920,
+        height: 1040,
+        scale: 1.0,
+    };
+
+    fn frame(x: i32, y: i32, width: i32, height: i32) -> Frame {
+        Frame {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn test_tile() {
+        // 4개는 2x2
+        let frames = tile(&AREA, 4, 8);
+        assert_eq!(frames[0], frame(8, 8, 948, 508));
+        assert_eq!(frames[1], frame(964, 8, 948, 508));
+        assert_eq!(frames[3], frame(964, 524, 948, 508));
+        // 3개는 넓은 화면에서 한 줄
+        let frames = tile(&AREA, 3, 8);
+        assert!(frames.iter().all(|f| f.y == 8 && f.height == 1024));
+        assert!(tile(&AREA, 0, 8).is_empty());
+    }
+
+    #[test]
+    fn test_stack_wraps_to_next_column() {
+        let frames = vec![frame(500, 500, 300, 400); 3];
+        let stacked = stack(&AREA, Edge::Left, &frames, 8);
+        assert_eq!(stacked[0], frame(8, 8, 300, 400));
+        assert_eq!(stacked[1], frame(8, 416, 300, 400));
+        // 세 번째는 아래 끝을 넘으므로 오른쪽 다음 줄
+        assert_eq!(stacked[2], frame(316, 8, 300, 400));
+
+        let stacked = stack(&AREA, Edge::Bottom, &frames, 8);
+        assert_eq!(stacked[0], frame(8, 632, 300, 400));
+        assert_eq!(stacked[2], frame(624, 632, 300, 400));
+    }
+
+    #[test]
+    fn test_align() {
+        let frames = [frame(100, 50, 300, 200), frame(400, 300, 200, 100)];
+        let aligned = align(&frames, Edge::Left);
+        assert_eq!((aligned[0].x, aligned[1].x), (100, 100));
+        let aligned = align(&frames, Edge::Right);
+        assert_eq!((aligned[0].right(), aligned[1].right()), (600, 600));
+        let aligned = align(&frames, Edge::Bottom);
+        assert_eq!((aligned[0].bottom(), aligned[1].bottom()), (400, 400));
+        // 다른 방향은 그대로
+        assert_eq!((aligned[0].x, aligned[1].x), (100, 400));
+    }
+
+    #[test]
+    fn test_snap() {
+        let note = frame(20, 500, 300, 300);
+        // 화면 왼쪽 가장자리에서 간격만큼 떨어진 곳이 가장 가까움
+        assert_eq!(snap(note, &[AREA], &[], 16, 8), (8, 500));
+        // 멀면 그대로
+        assert_eq!(
+            snap(frame(100, 500, 300, 300), &[AREA], &[], 16, 8),
+            (100, 500)
+        );
+
+        // 다른 노트 오른쪽에 붙이고 위쪽 가장자리를 맞춤
+        let other = frame(400, 200, 300, 300);
+        let note = frame(712, 190, 300, 300);
+        assert_eq!(snap(note, &[AREA], &[other], 16, 8), (708, 200));
+        // 세로로 멀리 떨어진 노트에는 붙이지 않음
+        let note = frame(712, 800, 300, 200);
+        assert_eq!(snap(note, &[], &[other], 16, 8), (712, 800));
+    }
+}
@@ -0,0 +1,423 @@
+// 주기적 자동 백업 및 보관 정책
+//
+// 백업 파일은 저장소 루트(인덱스, 노트, 이미지, 템플릿, 설정 등)의 스냅샷 zip이며
+// "backup-YYYYMMDD-HHMMSS.zip" 이름으로 백업 폴더에 저장된다.
+// 파일은 디스크에 있는 그대로 담으므로 저장소 루트 밖에서 연 노트 파일은 포함하지 않는다.
+
+use crate::settings::{read_settings, BackupSettings};
+use crate::{content_hash, get_storage_dir, lock_index, read_file, write_atomic, write_atomic_raw};
+use chrono::{DateTime, Datelike, Local, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::io::{Read, Seek, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tauri::Emitter;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(15 * 60);
+const STATE_FILE: &str = "last_backup.json";
+const NAME_FORMAT: &str = "%Y%m%d-%H%M%S";
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupInfo {
+    name: String,
+    path: String,
+    created_at: String,
+    size: u64,
+}
+
+// 마지막 자동 백업 상태 (변경 여부 판단용)
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BackupState {
+    fingerprint: String,
+    created_at: DateTime<Utc>,
+}
+
+// 백업 스케줄러 시작 (설정은 매 주기마다 다시 읽음)
+pub fn start_scheduler() {
+    std::thread::spawn(|| loop {
+        match run_scheduled_backup() {
+            Ok(Some(path)) => println!("Automatic backup created: {}", path.display()),
+            Ok(None) => {}
+            Err(e) => println!("Automatic backup failed: {}", e),
+        }
+        std::thread::sleep(CHECK_INTERVAL);
+    });
+}
+
+// 백업 목록 조회 커맨드 (최신순)
+#[tauri::command]
+pub fn list_backups() -> Result<Vec<BackupInfo>, String> {
+    let dir = get_backup_dir(&read_settings()?.backup)?;
+
+    Ok(scan_backups(&dir)
+        .into_iter()
+        .map(|(path, created)| BackupInfo {
+            name: path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            created_at: created
+                .and_local_timezone(Local)
+                .single()
+                .map(|d| d.to_rfc3339())
+                .unwrap_or_default(),
+            size: fs::metadata(&path).map(|m| m.len()).unwrap_or(0),
+            path: path.to_string_lossy().to_string(),
+        })
+        .collect())
+}
+
+// 백업 복원 커맨드: 복원 전 현재 상태를 먼저 백업한 뒤 스냅샷의 파일을 저장소 루트에 되돌림
+// (스냅샷 이후에 생긴 파일은 삭제하지 않음), 복원한 파일 개수 반환
+#[tauri::command]
+pub fn restore_backup(app: tauri::AppHandle, name: String) -> Result<usize, String> {
+    let dir = get_backup_dir(&read_settings()?.backup)?;
+
+    // 백업 이름 형식만 허용 (백업 폴더 밖의 파일 접근 방지)
+    if parse_backup_name(&name).is_none() {
+        return Err(format!("Invalid backup name: {}", name));
+    }
+    let path = dir.join(&name);
+    let file = std::io::Cursor::new(read_file(&path)?);
+
+    // 인덱스를 덮어쓰는 동안 다른 곳에서 인덱스를 고치지 않도록 잠금
+    let _guard = lock_index();
+    let root = get_storage_dir()?;
+    create_backup(&dir, &root)?;
+    let restored = restore_snapshot(file, &root)?;
+
+    // 목록 갱신 이벤트 발행
+    let _ = app.emit("refresh-notes-list", ());
+
+    Ok(restored)
+}
+
+fn get_backup_dir(settings: &BackupSettings) -> Result<PathBuf, String> {
+    let dir = match &settings.directory {
+        Some(dir) => PathBuf::from(dir),
+        None => get_storage_dir()?.join("backups"),
+    };
+
+    if !dir.exists() {
+        fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    }
+    Ok(dir)
+}
+
+// 주기가 지났고 마지막 백업 이후 변경이 있으면 백업 생성
+fn run_scheduled_backup() -> Result<Option<PathBuf>, String> {
+    let settings = read_settings()?.backup;
+    if !settings.enabled {
+        return Ok(None);
+    }
+
+    let dir = get_backup_dir(&settings)?;
+    let root = get_storage_dir()?;
+    let fingerprint = snapshot_fingerprint(&snapshot_files(&root, &dir));
+
+    if let Some(state) = read_state(&dir) {
+        let elapsed = Utc::now().signed_duration_since(state.created_at);
+        if elapsed < chrono::Duration::hours(settings.interval_hours as i64) {
+            return Ok(None);
+        }
+        if state.fingerprint == fingerprint {
+            println!("Automatic backup skipped: nothing changed since last backup");
+            return Ok(None);
+        }
+    }
+
+    let path = create_backup(&dir, &root)?;
+    write_state(
+        &dir,
+        &BackupState {
+            fingerprint,
+            created_at: Utc::now(),
+        },
+    )?;
+    prune_backups(&dir, &settings)?;
+
+    Ok(Some(path))
+}
+
+// 저장소 루트(root)의 스냅샷을 백업 폴더(dir)에 생성
+fn create_backup(dir: &Path, root: &Path) -> Result<PathBuf, String> {
+    let name = format!("backup-{}.zip", Local::now().format(NAME_FORMAT));
+    let path = dir.join(name);
+
+    // zip 안의 파일 이름(노트 제목)도 드러나지 않도록 라이브러리 암호화가 켜져 있으면 암호화하여 저장
+    let mut buffer = std::io::Cursor::new(Vec::new());
+    write_snapshot(&snapshot_files(root, dir), &mut buffer)?;
+    write_atomic(&path, buffer.get_ref())?;
+    Ok(path)
+}
+
+// 저장소 루트 아래의 파일 목록 (절대 경로, '/'로 구분한 상대 경로), 상대 경로 순
+// 백업 폴더(exclude)와 write_atomic의 임시 파일은 제외
+fn snapshot_files(root: &Path, exclude: &Path) -> Vec<(PathBuf, String)> {
+    let mut files = Vec::new();
+    let mut dirs = vec![root.to_path_buf()];
+
+    while let Some(dir) = dirs.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for path in entries.flatten().map(|e| e.path()) {
+            if path == exclude {
+                continue;
+            }
+            if path.is_dir() {
+                dirs.push(path);
+                continue;
+            }
+            let name = path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            if name.starts_with('.') && name.ends_with(".tmp") {
+                continue;
+            }
+            let Ok(relative) = path.strip_prefix(root) else {
+                continue;
+            };
+            let relative: Vec<String> = relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy().to_string())
+                .collect();
+            files.push((path.clone(), relative.join("/")));
+        }
+    }
+
+    files.sort_by(|a, b| a.1.cmp(&b.1));
+    files
+}
+
+// 파일 경로, 크기, 수정 시각으로 변경 여부를 판단 (삭제, 추가, 이전 시각으로의 수정 모두 감지)
+fn snapshot_fingerprint(files: &[(PathBuf, String)]) -> String {
+    let listing: Vec<String> = files
+        .iter()
+        .map(|(path, relative)| {
+            let metadata = fs::metadata(path).ok();
+            let size = metadata.as_ref().map(|m| m.len()).unwrap_or(0);
+            let modified = metadata
+                .and_then(|m| m.modified().ok())
+                .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+                .map(|d| d.as_nanos())
+                .unwrap_or(0);
+            format!("{}\t{}\t{}", relative, size, modified)
+        })
+        .collect();
+    content_hash(&listing.join("\n"))
+}
+
+// 파일을 디스크에 있는 그대로(암호화된 경우 암호문) zip으로 기록
+fn write_snapshot<W: Write + Seek>(files: &[(PathBuf, String)], writer: W) -> Result<(), String> {
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    let mut zip = ZipWriter::new(writer);
+
+    for (path, relative) in files {
+        let Ok(bytes) = fs::read(path) else {
+            continue;
+        };
+        zip.start_file(relative.as_str(), options)
+            .map_err(|e| e.to_string())?;
+        zip.write_all(&bytes).map_err(|e| e.to_string())?;
+    }
+
+    zip.finish().map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+// 스냅샷의 파일을 root 아래에 그대로 되돌림, 복원한 파일 개수 반환
+fn restore_snapshot<R: Read + Seek>(reader: R, root: &Path) -> Result<usize, String> {
+    let mut zip = ZipArchive::new(reader).map_err(|e| format!("Invalid backup: {}", e))?;
+    let mut restored = 0;
+
+    for i in 0..zip.len() {
+        let mut entry = zip.by_index(i).map_err(|e| e.to_string())?;
+        // root 밖을 가리키는 항목(../ 등)은 무시
+        let Some(relative) = entry.enclosed_name() else {
+            continue;
+        };
+        if entry.is_dir() {
+            continue;
+        }
+        let mut bytes = Vec::new();
+        entry.read_to_end(&mut bytes).map_err(|e| e.to_string())?;
+
+        let target = root.join(relative);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        // 이미 디스크 형식(암호화 포함)이므로 다시 인코딩하지 않음
+        write_atomic_raw(&target, &bytes)?;
+        restored += 1;
+    }
+    Ok(restored)
+}
+
+fn read_state(dir: &Path) -> Option<BackupState> {
+    let content = fs::read_to_string(dir.join(STATE_FILE)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn write_state(dir: &Path, state: &BackupState) -> Result<(), String> {
+    let content = serde_json::to_string_pretty(state).map_err(|e| e.to_string())?;
+    fs::write(dir.join(STATE_FILE), content).map_err(|e| e.to_string())
+}
+
+fn parse_backup_name(name: &str) -> Option<NaiveDateTime> {
+    let stamp = name.strip_prefix("backup-")?.strip_suffix(".zip")?;
+    NaiveDateTime::parse_from_str(stamp, NAME_FORMAT).ok()
+}
+
+// 백업 폴더의 백업 파일 목록 (최신순)
+fn scan_backups(dir: &Path) -> Vec<(PathBuf, NaiveDateTime)> {
+    let mut backups: Vec<(PathBuf, NaiveDateTime)> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok().map(|e| e.path()))
+                .filter_map(|path| {
+                    let created = parse_backup_name(&path.file_name()?.to_string_lossy())?;
+                    Some((path, created))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    backups.sort_by_key(|(_, created)| std::cmp::Reverse(*created));
+    backups
+}
+
+// 보관 정책에 따라 오래된 백업 삭제
+fn prune_backups(dir: &Path, settings: &BackupSettings) -> Result<(), String> {
+    let backups = scan_backups(dir);
+    let dates: Vec<NaiveDateTime> = backups.iter().map(|(_, d)| *d).collect();
+    let keep = select_retained(&dates, settings.keep_daily, settings.keep_weekly);
+
+    for (i, (path, _)) in backups.iter().enumerate() {
+        if !keep.contains(&i) {
+            fs::remove_file(path).map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(())
+}
+
+// 최신순으로 정렬된 백업 중 남길 인덱스 선택
+// 최근 keep_daily일의 하루 마지막 백업 + 최근 keep_weekly주의 주 마지막 백업
+fn select_retained(
+    dates: &[NaiveDateTime],
+    keep_daily: usize,
+    keep_weekly: usize,
+) -> HashSet<usize> {
+    let mut days = HashSet::new();
+    let mut weeks = HashSet::new();
+    let mut keep = HashSet::new();
+
+    for (i, date) in dates.iter().enumerate() {
+        if days.len() < keep_daily && days.insert(date.date()) {
+            keep.insert(i);
+        }
+        let week = date.iso_week();
+        if weeks.len() < keep_weekly && weeks.insert((week.year(), week.week())) {
+            keep.insert(i);
+        }
+    }
+
+    // 최신 백업은 항상 유지
+    if !dates.is_empty() {
+        keep.insert(0);
+    }
+    keep
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_select_retained_daily_and_weekly() {
+        // 2024-01-01(월)부터 하루 두 번씩 21일간, 최신순
+        let mut dates: Vec<NaiveDateTime> = (0..21)
+            .flat_map(|day| {
+                let date = chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()
+                    + chrono::Duration::days(day);
+                [
+                    date.and_hms_opt(9, 0, 0).unwrap(),
+                    date.and_hms_opt(18, 0, 0).unwrap(),
+                ]
+            })
+            .collect();
+        dates.reverse();
+
+        let keep = select_retained(&dates, 3, 2);
+        let mut kept: Vec<String> = keep
+            .iter()
+            .map(|&i| dates[i].format("%m-%d %H").to_string())
+            .collect();
+        kept.sort();
+
+        // 일별: 01-21, 01-20, 01-19의 마지막 백업
+        // 주별: 이번 주(01-15~21)는 01-21에 포함, 지난주는 01-14의 마지막 백업
+        assert_eq!(kept, vec!["01-14 18", "01-19 18", "01-20 18", "01-21 18"]);
+    }
+
+    #[test]
+    fn test_snapshot_roundtrip_and_fingerprint() {
+        let root =
+            std::env::temp_dir().join(format!("stickermd_snapshot_{}", uuid::Uuid::new_v4()));
+        let backups = root.join("backups");
+        fs::create_dir_all(root.join("notes")).unwrap();
+        fs::create_dir_all(root.join("templates")).unwrap();
+        fs::create_dir_all(&backups).unwrap();
+        fs::write(root.join("index.json"), "{}").unwrap();
+        fs::write(root.join("notes/a.md"), "# A").unwrap();
+        fs::write(root.join("notes/b.md"), "# B").unwrap();
+        fs::write(root.join("templates/Daily.md"), "# {{date}}").unwrap();
+        fs::write(root.join("notes/.c.md.tmp"), "partial").unwrap();
+        fs::write(backups.join("backup-20240101-000000.zip"), "old").unwrap();
+
+        let files = snapshot_files(&root, &backups);
+        let names: Vec<&str> = files.iter().map(|(_, r)| r.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "index.json",
+                "notes/a.md",
+                "notes/b.md",
+                "templates/Daily.md"
+            ]
+        );
+        let before = snapshot_fingerprint(&files);
+
+        let mut buffer = std::io::Cursor::new(Vec::new());
+        write_snapshot(&files, &mut buffer).unwrap();
+
+        // 노트 하나를 지우면 남은 노트의 수정 시각과 관계없이 변경으로 판단
+        fs::remove_file(root.join("notes/b.md")).unwrap();
+        fs::write(root.join("notes/a.md"), "# A edited").unwrap();
+        assert_ne!(
+            snapshot_fingerprint(&snapshot_files(&root, &backups)),
+            before
+        );
+
+        buffer.set_position(0);
+        assert_eq!(restore_snapshot(buffer, &root).unwrap(), 4);
+        assert_eq!(fs::read_to_string(root.join("notes/a.md")).unwrap(), "# A");
+        assert_eq!(fs::read_to_string(root.join("notes/b.md")).unwrap(), "# B");
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_parse_backup_name() {
+        assert!(parse_backup_name("backup-20240131-235959.zip").is_some());
+        assert!(parse_backup_name("last_backup.json").is_none());
+        assert!(parse_backup_name("backup-2024.zip").is_none());
+    }
+}
@@ -0,0 +1,582 @@
+// 전체 노트 라이브러리 백업 아카이브 (zip)
+//
+// 아카이브 구조:
+// - index.json        : file_path가 아카이브 내부 상대 경로("notes/<파일명>")로 바뀐 인덱스
+// - notes/<파일명>    : 노트 마크다운 (이미지 링크는 "../images/<파일명>"으로 변경)
+// - images/<파일명>   : 노트에서 참조하는 로컬 이미지
+
+use crate::groups::NoteGroup;
+use crate::importer::rewrite_image_links;
+use crate::{
+    available_path, get_notes_dir, lock_index, read_file, read_index, sanitize_file_name,
+    unique_name, write_atomic, write_index, NotesIndex,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io::{Read, Seek, Write};
+use std::path::{Path, PathBuf};
+use tauri::Emitter;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+const INDEX_ENTRY: &str = "index.json";
+const ARCHIVE_IMAGE_PREFIX: &str = "../images/";
+
+#[derive(Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RestoreMode {
+    // 기존 노트를 유지하면서 아카이브의 노트를 추가
+    Merge,
+    // 인덱스를 아카이브 내용으로 교체 (기존 파일은 삭제하지 않음)
+    Replace,
+}
+
+#[derive(Deserialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictStrategy {
+    // 같은 id의 노트가 있으면 로컬 노트 유지
+    #[default]
+    KeepLocal,
+    // 아카이브의 내용으로 로컬 노트 덮어쓰기
+    Overwrite,
+    // 새 id를 부여하여 둘 다 유지
+    KeepBoth,
+}
+
+#[derive(Serialize, Default)]
+pub struct RestoreReport {
+    restored: usize,
+    overwritten: usize,
+    duplicated: usize,
+    skipped: usize,
+}
+
+// 전체 백업 내보내기 커맨드 (output_path가 없으면 저장 다이얼로그 표시)
+#[tauri::command]
+pub async fn export_all(
+    app: tauri::AppHandle,
+    output_path: Option<String>,
+) -> Result<String, String> {
+    use tauri_plugin_dialog::{DialogExt, FilePath};
+
+    let output = match output_path {
+        Some(path) => PathBuf::from(path),
+        None => {
+            let default_name = format!(
+                "StickerMD-backup-{}.zip",
+                chrono::Local::now().format("%Y%m%d")
+            );
+            let file_path = app
+                .dialog()
+                .file()
+                .add_filter("Zip", &["zip"])
+                .set_file_name(default_name)
+                .blocking_save_file();
+
+            match file_path {
+                Some(FilePath::Path(path)) => path,
+                _ => return Err("No file selected".to_string()),
+            }
+        }
+    };
+
+    let index = read_index()?;
+    let file = fs::File::create(&output).map_err(|e| e.to_string())?;
+    write_archive(&index, file)?;
+
+    Ok(output.to_string_lossy().to_string())
+}
+
+// 백업 아카이브 복원 커맨드
+#[tauri::command]
+pub async fn import_backup(
+    app: tauri::AppHandle,
+    path: Option<String>,
+    mode: RestoreMode,
+    on_conflict: Option<ConflictStrategy>,
+) -> Result<RestoreReport, String> {
+    use tauri_plugin_dialog::{DialogExt, FilePath};
+
+    let archive_path = match path {
+        Some(path) => PathBuf::from(path),
+        None => match app
+            .dialog()
+            .file()
+            .add_filter("Zip", &["zip"])
+            .blocking_pick_file()
+        {
+            Some(FilePath::Path(path)) => path,
+            _ => return Err("No file selected".to_string()),
+        },
+    };
+
+    let file = fs::File::open(&archive_path).map_err(|e| e.to_string())?;
+    let notes_dir = get_notes_dir()?;
+    let report = {
+        // 복원하는 동안 다른 저장이 인덱스를 덮어쓰지 않도록 쓸 때까지 잠금 유지
+        let _guard = lock_index();
+        let mut index = read_index()?;
+        let report = restore_archive(
+            file,
+            &mut index,
+            &notes_dir,
+            mode,
+            on_conflict.unwrap_or_default(),
+        )?;
+        write_index(&index)?;
+        report
+    };
+
+    // 목록 갱신 이벤트 발행
+    let _ = app.emit("refresh-notes-list", ());
+
+    Ok(report)
+}
+
+// 인덱스에 등록된 모든 노트와 참조 이미지를 zip으로 기록
+pub(crate) fn write_archive<W: Write + Seek>(index: &NotesIndex, writer: W) -> Result<(), String> {
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    let mut zip = ZipWriter::new(writer);
+
+    let mut archive_index = index.clone();
+    let mut used_note_names = HashSet::new();
+    let mut used_image_names = HashSet::new();
+    // 원본 이미지 경로 -> 아카이브 내 파일명
+    let mut images: HashMap<PathBuf, String> = HashMap::new();
+
+    for note in archive_index.notes.iter_mut() {
+        let source = PathBuf::from(&note.file_path);
+        let Ok(bytes) = read_file(&source) else {
+            // 디스크에서 사라진 노트는 인덱스 정보만 유지
+            println!(
+                "Backup: note file missing, skipping content: {}",
+                note.file_path
+            );
+            continue;
+        };
+        let content = String::from_utf8_lossy(&bytes).to_string();
+
+        let content = rewrite_image_links(&content, |src| {
+            let image_path = local_image_path(src, source.parent())?;
+            let name = match images.get(&image_path) {
+                Some(name) => name.clone(),
+                None => {
+                    let file_name = image_path.file_name()?.to_string_lossy().to_string();
+                    let name = unique_name(&mut used_image_names, &file_name);
+                    images.insert(image_path, name.clone());
+                    name
+                }
+            };
+            Some(format!("{}{}", ARCHIVE_IMAGE_PREFIX, name))
+        });
+
+        let file_name = source
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| format!("{}.md", note.id));
+        let entry = format!("notes/{}", unique_name(&mut used_note_names, &file_name));
+
+        zip.start_file(entry.as_str(), options)
+            .map_err(|e| e.to_string())?;
+        zip.write_all(content.as_bytes())
+            .map_err(|e| e.to_string())?;
+        note.file_path = entry;
+    }
+
+    for (path, name) in &images {
+        let Ok(bytes) = read_file(path) else {
+            continue;
+        };
+        zip.start_file(format!("images/{}", name), options)
+            .map_err(|e| e.to_string())?;
+        zip.write_all(&bytes).map_err(|e| e.to_string())?;
+    }
+
+    let index_json = serde_json::to_string_pretty(&archive_index).map_err(|e| e.to_string())?;
+    zip.start_file(INDEX_ENTRY, options)
+        .map_err(|e| e.to_string())?;
+    zip.write_all(index_json.as_bytes())
+        .map_err(|e| e.to_string())?;
+
+    zip.finish().map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+// 아카이브를 notes_dir에 풀고 index에 병합
+pub(crate) fn restore_archive<R: Read + Seek>(
+    reader: R,
+    index: &mut NotesIndex,
+    notes_dir: &Path,
+    mode: RestoreMode,
+    on_conflict: ConflictStrategy,
+) -> Result<RestoreReport, String> {
+    let mut zip = ZipArchive::new(reader).map_err(|e| e.to_string())?;
+    let archive_index: NotesIndex = {
+        let mut entry = zip.by_name(INDEX_ENTRY).map_err(|e| e.to_string())?;
+        let mut json = String::new();
+        entry.read_to_string(&mut json).map_err(|e| e.to_string())?;
+        serde_json::from_str(&json).map_err(|e| e.to_string())?
+    };
+
+    // 1. 이미지 복원 ("../images/<이름>" -> 실제 절대 경로)
+    let images_dir = notes_dir.join("images");
+    let mut image_paths: HashMap<String, String> = HashMap::new();
+    for i in 0..zip.len() {
+        let mut entry = zip.by_index(i).map_err(|e| e.to_string())?;
+        let Some(name) = entry
+            .enclosed_name()
+            .filter(|p| p.starts_with("images"))
+            .and_then(|p| p.file_name().map(|n| n.to_string_lossy().to_string()))
+        else {
+            continue;
+        };
+
+        let mut bytes = Vec::new();
+        entry.read_to_end(&mut bytes).map_err(|e| e.to_string())?;
+
+        fs::create_dir_all(&images_dir).map_err(|e| e.to_string())?;
+        let target = available_path(&images_dir, &name, Some(&bytes));
+        write_atomic(&target, &bytes)?;
+        image_paths.insert(
+            format!("{}{}", ARCHIVE_IMAGE_PREFIX, name),
+            target.to_string_lossy().to_string(),
+        );
+    }
+
+    // 2. 그룹 복원 (노트보다 먼저 처리하여 노트의 group_id를 맞춤)
+    if mode == RestoreMode::Replace {
+        index.notes.clear();
+        index.groups.clear();
+    }
+    let group_ids = merge_groups(&mut index.groups, archive_index.groups, on_conflict);
+
+    // 3. 노트 복원
+
+    let mut report = RestoreReport::default();
+    for mut note in archive_index.notes {
+        let existing = index.notes.iter().position(|n| n.id == note.id);
+        if existing.is_some() && on_conflict == ConflictStrategy::KeepLocal {
+            report.skipped += 1;
+            continue;
+        }
+
+        let content = match zip.by_name(&note.file_path) {
+            Ok(mut entry) => {
+                let mut bytes = Vec::new();
+                entry.read_to_end(&mut bytes).map_err(|e| e.to_string())?;
+                String::from_utf8_lossy(&bytes).to_string()
+            }
+            Err(_) => {
+                report.skipped += 1;
+                continue;
+            }
+        };
+        let content = rewrite_image_links(&content, |src| image_paths.get(src).cloned());
+        if let Some(local_id) = note.group_id.as_ref().and_then(|id| group_ids.get(id)) {
+            note.group_id = Some(local_id.clone());
+        }
+
+        let file_name = Path::new(&note.file_path)
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| format!("{}.md", note.id));
+
+        match existing {
+            Some(pos) if on_conflict == ConflictStrategy::Overwrite => {
+                note.file_path = index.notes[pos].file_path.clone();
+                write_atomic(Path::new(&note.file_path), content.as_bytes())?;
+                index.notes[pos] = note;
+                report.overwritten += 1;
+            }
+            Some(_) => {
+                let target = available_path(notes_dir, &file_name, None);
+                write_atomic(&target, content.as_bytes())?;
+                note.id = uuid::Uuid::new_v4().to_string();
+                note.file_path = target.to_string_lossy().to_string();
+                index.notes.push(note);
+                report.duplicated += 1;
+            }
+            None => {
+                let target = available_path(notes_dir, &file_name, None);
+                write_atomic(&target, content.as_bytes())?;
+                note.file_path = target.to_string_lossy().to_string();
+                index.notes.push(note);
+                report.restored += 1;
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+// 아카이브의 그룹을 id 기준으로 병합
+// 같은 id는 Overwrite일 때만 이름/색상을 덮어쓰고, 이름(폴더)이 같은 다른 그룹은 로컬 그룹으로 합침
+// 아카이브 그룹 id -> 로컬 그룹 id 매핑 반환 (id가 바뀐 그룹만 포함)
+fn merge_groups(
+    groups: &mut Vec<NoteGroup>,
+    archived: Vec<NoteGroup>,
+    on_conflict: ConflictStrategy,
+) -> HashMap<String, String> {
+    let folder = |name: &str| sanitize_file_name(name).to_lowercase();
+    let mut remapped = HashMap::new();
+
+    for group in archived {
+        let same_name = groups
+            .iter()
+            .find(|g| g.id != group.id && folder(&g.name) == folder(&group.name))
+            .map(|g| g.id.clone());
+
+        match groups.iter().position(|g| g.id == group.id) {
+            Some(pos) => {
+                // 덮어쓸 이름이 다른 그룹과 겹치면 이름은 로컬 것을 유지
+                if on_conflict == ConflictStrategy::Overwrite {
+                    if same_name.is_none() {
+                        groups[pos].name = group.name;
+                    }
+                    groups[pos].color = group.color;
+                }
+            }
+            None => match same_name {
+                Some(local_id) => {
+                    remapped.insert(group.id, local_id);
+                }
+                None => groups.push(group),
+            },
+        }
+    }
+
+    remapped
+}
+
+// 로컬 이미지 파일 경로 (웹/Data URI는 None)
+fn local_image_path(src: &str, note_dir: Option<&Path>) -> Option<PathBuf> {
+    if src.starts_with("http://") || src.starts_with("https://") || src.starts_with("data:") {
+        return None;
+    }
+
+    let decoded = percent_encoding::percent_decode_str(src)
+        .decode_utf8_lossy()
+        .to_string();
+    let path = PathBuf::from(decoded);
+    let path = match note_dir {
+        Some(dir) if path.is_relative() => dir.join(path),
+        _ => path,
+    };
+    path.is_file().then_some(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NoteMetadata;
+    use std::io::Cursor;
+
+    fn group(id: &str, name: &str) -> NoteGroup {
+        NoteGroup {
+            id: id.to_string(),
+            name: name.to_string(),
+            color: None,
+        }
+    }
+
+    // 노트 하나("note-1", 그룹 "g1")가 든 아카이브와 같은 id의 로컬 노트가 있는 인덱스 준비
+    fn conflict_fixture(root: &Path) -> (Cursor<Vec<u8>>, NotesIndex) {
+        let source_dir = root.join("source");
+        fs::create_dir_all(&source_dir).unwrap();
+        let source_path = source_dir.join("memo.md");
+        fs::write(&source_path, "# Archived").unwrap();
+
+        let archived = NotesIndex {
+            notes: vec![NoteMetadata {
+                id: "note-1".to_string(),
+                title: "Archived".to_string(),
+                file_path: source_path.to_string_lossy().to_string(),
+                group_id: Some("g1".to_string()),
+                ..Default::default()
+            }],
+            groups: vec![group("g1", "Archived group"), group("g2", "Work")],
+            ..Default::default()
+        };
+        let mut buffer = Cursor::new(Vec::new());
+        write_archive(&archived, &mut buffer).unwrap();
+        buffer.set_position(0);
+
+        let local_path = root.join("local.md");
+        fs::write(&local_path, "# Local").unwrap();
+        let local = NotesIndex {
+            notes: vec![NoteMetadata {
+                id: "note-1".to_string(),
+                title: "Local".to_string(),
+                file_path: local_path.to_string_lossy().to_string(),
+                group_id: Some("g1".to_string()),
+                ..Default::default()
+            }],
+            groups: vec![group("g1", "Local group"), group("g3", "work")],
+            ..Default::default()
+        };
+        (buffer, local)
+    }
+
+    fn temp_root() -> PathBuf {
+        let root = std::env::temp_dir().join(format!("stickermd_backup_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(root.join("restore")).unwrap();
+        root
+    }
+
+    #[test]
+    fn test_merge_conflict_strategies() {
+        // KeepLocal: 로컬 노트와 그룹 이름 유지
+        let root = temp_root();
+        let (buffer, mut local) = conflict_fixture(&root);
+        let report = restore_archive(
+            buffer,
+            &mut local,
+            &root.join("restore"),
+            RestoreMode::Merge,
+            ConflictStrategy::KeepLocal,
+        )
+        .unwrap();
+        assert_eq!(report.skipped, 1);
+        assert_eq!(local.notes.len(), 1);
+        assert_eq!(
+            fs::read_to_string(&local.notes[0].file_path).unwrap(),
+            "# Local"
+        );
+        assert_eq!(local.groups[0].name, "Local group");
+        // 이름이 같은 그룹("Work" / "work")은 새로 만들지 않음
+        assert_eq!(local.groups.len(), 2);
+        fs::remove_dir_all(&root).unwrap();
+
+        // Overwrite: 로컬 파일 자리에 아카이브 내용 기록, 그룹 이름도 덮어씀
+        let root = temp_root();
+        let (buffer, mut local) = conflict_fixture(&root);
+        let local_path = local.notes[0].file_path.clone();
+        let report = restore_archive(
+            buffer,
+            &mut local,
+            &root.join("restore"),
+            RestoreMode::Merge,
+            ConflictStrategy::Overwrite,
+        )
+        .unwrap();
+        assert_eq!(report.overwritten, 1);
+        assert_eq!(local.notes.len(), 1);
+        assert_eq!(local.notes[0].title, "Archived");
+        assert_eq!(local.notes[0].file_path, local_path);
+        assert_eq!(fs::read_to_string(&local_path).unwrap(), "# Archived");
+        assert_eq!(local.groups[0].name, "Archived group");
+        assert_eq!(local.groups.len(), 2);
+        fs::remove_dir_all(&root).unwrap();
+
+        // KeepBoth: 새 id로 복제하고 그룹은 그대로 연결
+        let root = temp_root();
+        let (buffer, mut local) = conflict_fixture(&root);
+        let report = restore_archive(
+            buffer,
+            &mut local,
+            &root.join("restore"),
+            RestoreMode::Merge,
+            ConflictStrategy::KeepBoth,
+        )
+        .unwrap();
+        assert_eq!(report.duplicated, 1);
+        assert_eq!(local.notes.len(), 2);
+        assert_ne!(local.notes[1].id, "note-1");
+        assert_eq!(local.notes[1].group_id.as_deref(), Some("g1"));
+        assert_eq!(local.groups[0].name, "Local group");
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_replace_takes_archive_notes_and_groups() {
+        for strategy in [
+            ConflictStrategy::KeepLocal,
+            ConflictStrategy::Overwrite,
+            ConflictStrategy::KeepBoth,
+        ] {
+            let root = temp_root();
+            let (buffer, mut local) = conflict_fixture(&root);
+            let local_path = local.notes[0].file_path.clone();
+            let report = restore_archive(
+                buffer,
+                &mut local,
+                &root.join("restore"),
+                RestoreMode::Replace,
+                strategy,
+            )
+            .unwrap();
+
+            // 인덱스를 비운 뒤 복원하므로 충돌 없이 그대로 추가됨
+            assert_eq!(report.restored, 1);
+            assert_eq!(local.notes.len(), 1);
+            assert_eq!(local.notes[0].id, "note-1");
+            assert_ne!(local.notes[0].file_path, local_path);
+            assert_eq!(
+                fs::read_to_string(&local.notes[0].file_path).unwrap(),
+                "# Archived"
+            );
+            // 기존 파일은 삭제하지 않음
+            assert_eq!(fs::read_to_string(&local_path).unwrap(), "# Local");
+
+            let names: Vec<_> = local.groups.iter().map(|g| g.name.as_str()).collect();
+            assert_eq!(names, vec!["Archived group", "Work"]);
+            fs::remove_dir_all(&root).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_archive_roundtrip_with_images() {
+        let root = std::env::temp_dir().join(format!("stickermd_backup_{}", uuid::Uuid::new_v4()));
+        let source_dir = root.join("source");
+        let restore_dir = root.join("restore");
+        fs::create_dir_all(&source_dir).unwrap();
+        fs::create_dir_all(&restore_dir).unwrap();
+
+        let image_path = source_dir.join("pic.png");
+        fs::write(&image_path, [1u8, 2, 3]).unwrap();
+        let note_path = source_dir.join("memo.md");
+        fs::write(
+            &note_path,
+            format!("# Memo\n\n![image]({})", image_path.to_string_lossy()),
+        )
+        .unwrap();
+
+        let index = NotesIndex {
+            notes: vec![NoteMetadata {
+                id: "note-1".to_string(),
+                title: "Memo".to_string(),
+                file_path: note_path.to_string_lossy().to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let mut buffer = Cursor::new(Vec::new());
+        write_archive(&index, &mut buffer).unwrap();
+
+        // 같은 id가 이미 있으므로 KeepBoth는 새 id로 복제
+        let mut local = index.clone();
+        buffer.set_position(0);
+        let report = restore_archive(
+            &mut buffer,
+            &mut local,
+            &restore_dir,
+            RestoreMode::Merge,
+            ConflictStrategy::KeepBoth,
+        )
+        .unwrap();
+
+        assert_eq!(report.duplicated, 1);
+        assert_eq!(local.notes.len(), 2);
+
+        let restored = &local.notes[1];
+        assert_ne!(restored.id, "note-1");
+        let content = fs::read_to_string(&restored.file_path).unwrap();
+        let restored_image = restore_dir.join("images").join("pic.png");
+        assert!(content.contains(&*restored_image.to_string_lossy()));
+        assert_eq!(fs::read(&restored_image).unwrap(), vec![1u8, 2, 3]);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,180 @@
+// 데일리 노트 (notes/daily/YYYY-MM-DD.md)
+//
+// 폴더와 파일 이름 형식은 설정(notes.daily)을 따르며, 날짜는 현지 시간 기준이다.
+
+use crate::settings::{read_settings, DailySettings};
+use crate::templates::{fixed_note_path, format_time, render_template_body};
+use crate::{get_notes_dir, open_file_from_path, open_note_window, write_atomic};
+use chrono::{DateTime, Duration, Local, NaiveDate, TimeZone};
+use serde::Serialize;
+use std::path::PathBuf;
+
+// 이전/다음 데일리 노트를 찾을 때 살펴보는 최대 일수
+const SEARCH_DAYS: i64 = 3660;
+
+#[derive(Serialize)]
+pub struct DailyNote {
+    id: String,
+    // YYYY-MM-DD
+    date: String,
+    file_path: String,
+    // 이번에 새로 만든 경우
+    created: bool,
+}
+
+// 오늘(또는 지정한 날짜)의 데일리 노트 열기 커맨드, 없으면 생성
+#[tauri::command]
+pub async fn open_daily_note(
+    app: tauri::AppHandle,
+    date: Option<String>,
+) -> Result<DailyNote, String> {
+    let date = match date {
+        Some(date) => parse_date(&date)?,
+        None => Local::now().date_naive(),
+    };
+    open_for_date(app, date).await
+}
+
+// 이전 데일리 노트 열기 커맨드 (가장 가까운 이전 날짜의 기존 노트, 새로 만들지 않음)
+#[tauri::command]
+pub async fn open_previous_daily_note(
+    app: tauri::AppHandle,
+    date: String,
+) -> Result<DailyNote, String> {
+    let settings = read_settings()?.notes.daily;
+    let date = nearest_existing(&get_notes_dir()?, &settings, parse_date(&date)?, -1)
+        .ok_or("No earlier daily note")?;
+    open_for_date(app, date).await
+}
+
+// 다음 데일리 노트 열기 커맨드 (가장 가까운 다음 날짜의 기존 노트, 새로 만들지 않음)
+#[tauri::command]
+pub async fn open_next_daily_note(
+    app: tauri::AppHandle,
+    date: String,
+) -> Result<DailyNote, String> {
+    let settings = read_settings()?.notes.daily;
+    let date = nearest_existing(&get_notes_dir()?, &settings, parse_date(&date)?, 1)
+        .ok_or("No later daily note")?;
+    open_for_date(app, date).await
+}
+
+// from에서 direction(-1: 이전, 1: 다음) 방향으로 파일이 있는 가장 가까운 날짜
+fn nearest_existing(
+    notes_dir: &std::path::Path,
+    settings: &DailySettings,
+    from: NaiveDate,
+    direction: i64,
+) -> Option<NaiveDate> {
+    (1..=SEARCH_DAYS)
+        .filter_map(|n| from.checked_add_signed(Duration::days(n * direction)))
+        .find(|date| daily_note_path(notes_dir, settings, local_time_on(*date)).exists())
+}
+
+fn parse_date(date: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d")
+        .map_err(|e| format!("Invalid date {}: {}", date, e))
+}
+
+async fn open_for_date(app: tauri::AppHandle, date: NaiveDate) -> Result<DailyNote, String> {
+    let settings = read_settings()?.notes.daily;
+    let at = local_time_on(date);
+    let path = daily_note_path(&get_notes_dir()?, &settings, at);
+
+    let created = !path.exists();
+    if created {
+        let content = match &settings.template {
+            Some(template) => render_template_body(template, at)?,
+            None => format!("# {}\n\n", date.format("%Y-%m-%d")),
+        };
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        write_atomic(&path, content.as_bytes())?;
+    }
+
+    // 인덱스 등록(이미 등록된 경우 기존 id)은 파일 열기와 같은 방식으로 처리
+    let file_path = path.to_string_lossy().to_string();
+    let id = open_file_from_path(app.clone(), file_path.clone()).await?;
+    open_note_window(app, id.clone()).await?;
+
+    Ok(DailyNote {
+        id,
+        date: date.format("%Y-%m-%d").to_string(),
+        file_path,
+        created,
+    })
+}
+
+// 해당 날짜의 현재 시각 (템플릿의 {{time}}용, DST로 없는 시각이면 지금)
+fn local_time_on(date: NaiveDate) -> DateTime<Local> {
+    let now = Local::now();
+    Local
+        .from_local_datetime(&date.and_time(now.time()))
+        .earliest()
+        .unwrap_or(now)
+}
+
+fn daily_note_path(
+    notes_dir: &std::path::Path,
+    settings: &DailySettings,
+    at: DateTime<Local>,
+) -> PathBuf {
+    let file_name = format_time(at, &settings.file_format);
+    fixed_note_path(notes_dir, &format!("{}/{}", settings.folder, file_name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    #[test]
+    fn test_daily_note_path_uses_settings() {
+        let at = Local.with_ymd_and_hms(2024, 2, 29, 8, 0, 0).unwrap();
+        let notes_dir = Path::new("/notes");
+
+        assert_eq!(
+            daily_note_path(notes_dir, &DailySettings::default(), at),
+            Path::new("/notes/daily/2024-02-29.md")
+        );
+
+        let settings = DailySettings {
+            folder: "journal/2024".to_string(),
+            file_format: "%Y%m%d %a".to_string(),
+            template: None,
+        };
+        assert_eq!(
+            daily_note_path(notes_dir, &settings, at),
+            Path::new("/notes/journal/2024/20240229 Thu.md")
+        );
+    }
+
+    #[test]
+    fn test_nearest_existing_skips_missing_days() {
+        let dir = std::env::temp_dir().join(format!("stickermd_daily_{}", uuid::Uuid::new_v4()));
+        let settings = DailySettings::default();
+        std::fs::create_dir_all(dir.join("daily")).unwrap();
+        for name in ["2024-02-20.md", "2024-03-05.md"] {
+            std::fs::write(dir.join("daily").join(name), "").unwrap();
+        }
+        let date = |d: &str| parse_date(d).unwrap();
+
+        assert_eq!(
+            nearest_existing(&dir, &settings, date("2024-02-29"), -1),
+            Some(date("2024-02-20"))
+        );
+        assert_eq!(
+            nearest_existing(&dir, &settings, date("2024-02-29"), 1),
+            Some(date("2024-03-05"))
+        );
+        assert_eq!(
+            nearest_existing(&dir, &settings, date("2024-03-05"), 1),
+            None
+        );
+        // 찾기만 하고 빠진 날짜의 파일은 만들지 않음
+        assert_eq!(std::fs::read_dir(dir.join("daily")).unwrap().count(), 2);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
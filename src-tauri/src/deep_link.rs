@@ -0,0 +1,223 @@
+// stickermd:// 딥 링크
+//
+// stickermd://open/<id>                노트 열기
+// stickermd://new?text=...&color=...   새 노트를 만들고 열기 (color: #RRGGBB 또는 yellow, blue 등)
+// stickermd://search?q=...             메인 윈도우를 열고 검색 결과 전달
+//
+// 모든 링크는 deep-link 플러그인으로 받는다. 이미 실행 중일 때의 링크는 single-instance 플러그인이
+// on_open_url로 넘겨주고, Windows/Linux에서 링크로 처음 실행된 경우는 get_current로 읽는다.
+// 라이브러리가 잠겨 있으면 잠금을 해제할 때까지 보관했다가 처리한다.
+
+use crate::{read_index, write_index, NoteMetadata};
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use serde::Serialize;
+use std::sync::Mutex;
+use tauri::Emitter;
+
+// 라이브러리 잠금 해제를 기다리는 링크
+static PENDING: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+pub const SCHEME: &str = "stickermd";
+
+// 컨텍스트 메뉴의 색상과 같은 이름
+const NAMED_COLORS: [(&str, &str); 5] = [
+    ("yellow", "#FFF7D1"),
+    ("blue", "#E0F7FA"),
+    ("green", "#E8F5E9"),
+    ("pink", "#FCE4EC"),
+    ("purple", "#F3E5F5"),
+];
+
+#[derive(Debug, PartialEq)]
+enum DeepLink {
+    Open(String),
+    New { text: String, color: Option<String> },
+    Search(String),
+}
+
+#[derive(Serialize, Clone)]
+struct SearchPayload {
+    query: String,
+    notes: Vec<NoteMetadata>,
+}
+
+// 실행 인자가 딥 링크인지 확인
+pub fn is_deep_link(arg: &str) -> bool {
+    arg.get(..SCHEME.len() + 1)
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case(&format!("{}:", SCHEME)))
+}
+
+fn parse(url: &str) -> Result<DeepLink, String> {
+    if !is_deep_link(url) {
+        return Err(format!("Not a {} link: {}", SCHEME, url));
+    }
+    // "stickermd://open/x"와 "stickermd:open/x" 모두 허용
+    let rest = url[SCHEME.len() + 1..].trim_start_matches('/');
+    let (path, query) = rest.split_once('?').unwrap_or((rest, ""));
+    let query = crate::api::parse_query(query);
+    let mut segments = path.split('/').filter(|s| !s.is_empty());
+
+    match segments.next().map(str::to_lowercase).as_deref() {
+        Some("open") => {
+            let id = segments.next().ok_or("Missing note id")?;
+            let id = percent_encoding::percent_decode_str(id).decode_utf8_lossy();
+            Ok(DeepLink::Open(id.to_string()))
+        }
+        Some("new") => Ok(DeepLink::New {
+            text: query.get("text").cloned().unwrap_or_default(),
+            color: query.get("color").map(|c| parse_color(c)).transpose()?,
+        }),
+        Some("search") => Ok(DeepLink::Search(
+            query.get("q").cloned().unwrap_or_default(),
+        )),
+        _ => Err(format!("Unknown link: {}", url)),
+    }
+}
+
+// 색상 이름 또는 16진수 색상을 "#RRGGBB"로
+fn parse_color(value: &str) -> Result<String, String> {
+    let value = value.trim();
+    if let Some((_, hex)) = NAMED_COLORS
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(value))
+    {
+        return Ok(hex.to_string());
+    }
+
+    let hex = value.trim_start_matches('#');
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(format!("Invalid color: {}", value));
+    }
+    match hex.len() {
+        6 => Ok(format!("#{}", hex.to_uppercase())),
+        3 => Ok(format!(
+            "#{}",
+            hex.chars()
+                .flat_map(|c| [c, c])
+                .collect::<String>()
+                .to_uppercase()
+        )),
+        _ => Err(format!("Invalid color: {}", value)),
+    }
+}
+
+// 노트 링크 ("stickermd://open/<id>")
+fn note_link(id: &str) -> String {
+    format!(
+        "{}://open/{}",
+        SCHEME,
+        utf8_percent_encode(id, NON_ALPHANUMERIC)
+    )
+}
+
+// 노트 링크 복사 커맨드 (클립보드에는 프론트엔드가 복사)
+#[tauri::command]
+pub fn copy_note_link(id: String) -> Result<String, String> {
+    if !read_index()?.notes.iter().any(|n| n.id == id) {
+        return Err("Note not found".to_string());
+    }
+    Ok(note_link(&id))
+}
+
+// 딥 링크 처리 (오류는 로그로만 남김)
+pub fn handle(app: tauri::AppHandle, url: String) {
+    crate::log_to_file(&format!("Deep link: {}", url));
+    // 잠긴 라이브러리는 인덱스를 읽을 수 없으므로 잠금 해제 후 처리
+    if crate::vault::is_enabled() && !crate::vault::is_unlocked() {
+        PENDING.lock().unwrap_or_else(|e| e.into_inner()).push(url);
+        return;
+    }
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = open(&app, &url).await {
+            crate::log_to_file(&format!("Deep link failed ({}): {}", url, e));
+        }
+    });
+}
+
+// 잠겨 있는 동안 받은 링크 처리 (unlock_library에서 호출)
+pub fn handle_pending(app: &tauri::AppHandle) {
+    let pending = std::mem::take(&mut *PENDING.lock().unwrap_or_else(|e| e.into_inner()));
+    for url in pending {
+        handle(app.clone(), url);
+    }
+}
+
+async fn open(app: &tauri::AppHandle, url: &str) -> Result<(), String> {
+    match parse(url)? {
+        DeepLink::Open(id) => {
+            if !read_index()?.notes.iter().any(|n| n.id == id) {
+                return Err("Note not found".to_string());
+            }
+            crate::open_note_window(app.clone(), id).await
+        }
+        DeepLink::New { text, color } => {
+            let id = crate::generate_new_note_id();
+            crate::save_new_note(app.clone(), id.clone(), text)?;
+            if color.is_some() {
+                let _guard = crate::lock_index();
+                let mut index = read_index()?;
+                if let Some(note) = index.notes.iter_mut().find(|n| n.id == id) {
+                    note.color = color;
+                }
+                write_index(&index)?;
+            }
+            crate::open_note_window(app.clone(), id).await
+        }
+        DeepLink::Search(query) => {
+            let notes = crate::search_notes(query.clone(), Some(false))?;
+            crate::open_main_window(app.clone()).await?;
+            let _ = app.emit("deep-link-search", SearchPayload { query, notes });
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse("stickermd://open/abc-123").unwrap(),
+            DeepLink::Open("abc-123".to_string())
+        );
+        // Windows가 붙이는 끝의 '/'와 대소문자 무시
+        assert_eq!(
+            parse("StickerMD://OPEN/abc-123/").unwrap(),
+            DeepLink::Open("abc-123".to_string())
+        );
+        assert_eq!(
+            parse("stickermd://new?text=%23%20%EC%9E%A5%EB%B3%B4%EA%B8%B0%0A%EC%9A%B0%EC%9C%A0&color=pink")
+                .unwrap(),
+            DeepLink::New {
+                text: "# 장보기\n우유".to_string(),
+                color: Some("#FCE4EC".to_string()),
+            }
+        );
+        assert_eq!(
+            parse("stickermd:search?q=meeting+notes").unwrap(),
+            DeepLink::Search("meeting notes".to_string())
+        );
+
+        assert!(parse("stickermd://open").is_err());
+        assert!(parse("stickermd://delete/abc").is_err());
+        assert!(parse("stickermd://new?color=red").is_err());
+        assert!(parse("https://open/abc").is_err());
+    }
+
+    #[test]
+    fn test_parse_color() {
+        assert_eq!(parse_color("#fff7d1").unwrap(), "#FFF7D1");
+        assert_eq!(parse_color("abc").unwrap(), "#AABBCC");
+        assert_eq!(parse_color("Blue").unwrap(), "#E0F7FA");
+        assert!(parse_color("#12345").is_err());
+    }
+
+    #[test]
+    fn test_note_link_roundtrip() {
+        let link = note_link("a b/c");
+        assert_eq!(link, "stickermd://open/a%20b%2Fc");
+        assert_eq!(parse(&link).unwrap(), DeepLink::Open("a b/c".to_string()));
+    }
+}
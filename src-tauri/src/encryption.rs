@@ -0,0 +1,423 @@
+// 노트 암호화 (패스프레이즈)
+//
+// 암호화된 노트 파일 형식 (텍스트):
+// - 첫 줄: HEADER
+// - 둘째 줄: 봉투 JSON (KDF 매개변수, salt, nonce, 암호문; 바이너리 값은 base64)
+//
+// 키는 패스프레이즈에서 Argon2id로 유도하고 본문은 AES-256-GCM으로 암호화한다.
+// 잠금 해제한 노트의 키는 메모리에만 보관하며, 설정한 시간 동안 사용하지 않으면 자동으로 잠근다.
+
+use crate::{
+    extract_title, lock_index, read_file, read_index, refresh_content_stats, write_atomic,
+    write_index, NoteMetadata,
+};
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use argon2::{Algorithm, Argon2, Params, Version};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+use tauri::Emitter;
+
+const HEADER: &str = "<!-- stickermd-encrypted v1 -->";
+const KDF: &str = "argon2id";
+const CIPHER: &str = "aes-256-gcm";
+const SALT_LEN: usize = 16;
+const CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+// 잠긴 노트를 읽거나 저장하려 할 때 반환하는 오류 (프론트엔드에서 잠금 화면 표시)
+pub const LOCKED: &str = "NOTE_LOCKED";
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Envelope {
+    kdf: String,
+    memory_kib: u32,
+    iterations: u32,
+    parallelism: u32,
+    salt: String,
+    cipher: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+// 패스프레이즈에서 유도한 키와 유도에 사용한 매개변수
+#[derive(Clone)]
+pub struct DerivedKey {
+    pub key: [u8; 32],
+    pub salt: Vec<u8>,
+    pub memory_kib: u32,
+    pub iterations: u32,
+    pub parallelism: u32,
+}
+
+// 잠금 해제된 노트 (노트 id별)
+struct Session {
+    key: DerivedKey,
+    last_used: Instant,
+}
+
+static SESSIONS: Mutex<BTreeMap<String, Session>> = Mutex::new(BTreeMap::new());
+
+fn sessions() -> MutexGuard<'static, BTreeMap<String, Session>> {
+    SESSIONS.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+// 암호화된 노트 파일 내용인지 확인
+pub fn is_encrypted(content: &str) -> bool {
+    content.starts_with(HEADER)
+}
+
+pub fn derive_key(
+    passphrase: &str,
+    salt: Vec<u8>,
+    memory_kib: u32,
+    iterations: u32,
+    parallelism: u32,
+) -> Result<DerivedKey, String> {
+    let params = Params::new(memory_kib, iterations, parallelism, Some(32))
+        .map_err(|e| format!("Invalid key derivation parameters: {}", e))?;
+    let mut key = [0u8; 32];
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(passphrase.as_bytes(), &salt, &mut key)
+        .map_err(|e| e.to_string())?;
+
+    Ok(DerivedKey {
+        key,
+        salt,
+        memory_kib,
+        iterations,
+        parallelism,
+    })
+}
+
+// 새 salt로 키 유도 (기본 Argon2id 매개변수)
+pub fn new_key(passphrase: &str) -> Result<DerivedKey, String> {
+    if passphrase.is_empty() {
+        return Err("Passphrase must not be empty".to_string());
+    }
+    let mut salt = vec![0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    derive_key(
+        passphrase,
+        salt,
+        Params::DEFAULT_M_COST,
+        Params::DEFAULT_T_COST,
+        Params::DEFAULT_P_COST,
+    )
+}
+
+// 본문을 암호화하여 파일에 쓸 내용 생성 (저장할 때마다 새 nonce 사용)
+fn seal(key: &DerivedKey, plaintext: &str) -> Result<String, String> {
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key.key));
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext.as_bytes())
+        .map_err(|_| "Failed to encrypt note".to_string())?;
+
+    let envelope = Envelope {
+        kdf: KDF.to_string(),
+        memory_kib: key.memory_kib,
+        iterations: key.iterations,
+        parallelism: key.parallelism,
+        salt: STANDARD.encode(&key.salt),
+        cipher: CIPHER.to_string(),
+        nonce: STANDARD.encode(nonce),
+        ciphertext: STANDARD.encode(ciphertext),
+    };
+    let json = serde_json::to_string(&envelope).map_err(|e| e.to_string())?;
+    Ok(format!("{}\n{}\n", HEADER, json))
+}
+
+fn parse_envelope(content: &str) -> Result<Envelope, String> {
+    let json = content
+        .strip_prefix(HEADER)
+        .ok_or("Note is not encrypted")?;
+    let envelope: Envelope =
+        serde_json::from_str(json.trim()).map_err(|e| format!("Invalid encrypted note: {}", e))?;
+    if envelope.kdf != KDF || envelope.cipher != CIPHER {
+        return Err(format!(
+            "Unsupported encryption: {} / {}",
+            envelope.kdf, envelope.cipher
+        ));
+    }
+    Ok(envelope)
+}
+
+fn decode(value: &str) -> Result<Vec<u8>, String> {
+    STANDARD
+        .decode(value)
+        .map_err(|e| format!("Invalid encrypted note: {}", e))
+}
+
+// 이미 유도한 키로 복호화 (봉투의 salt가 키와 다르면 실패)
+fn open_with_key(key: &DerivedKey, envelope: &Envelope) -> Result<String, String> {
+    if decode(&envelope.salt)? != key.salt {
+        return Err(LOCKED.to_string());
+    }
+    let nonce = decode(&envelope.nonce)?;
+    if nonce.len() != 12 {
+        return Err("Invalid encrypted note: bad nonce".to_string());
+    }
+    let ciphertext = decode(&envelope.ciphertext)?;
+
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key.key));
+    let plaintext = cipher
+        .decrypt(Nonce::from_slice(&nonce), ciphertext.as_ref())
+        .map_err(|_| "Wrong passphrase or corrupted note".to_string())?;
+    String::from_utf8(plaintext).map_err(|_| "Note is not valid UTF-8".to_string())
+}
+
+// 패스프레이즈로 복호화, 본문과 유도한 키 반환
+fn open(passphrase: &str, content: &str) -> Result<(String, DerivedKey), String> {
+    let envelope = parse_envelope(content)?;
+    let key = derive_key(
+        passphrase,
+        decode(&envelope.salt)?,
+        envelope.memory_kib,
+        envelope.iterations,
+        envelope.parallelism,
+    )?;
+    let plaintext = open_with_key(&key, &envelope)?;
+    Ok((plaintext, key))
+}
+
+// 암호화된 노트의 인덱스 정보에서 본문 유래 정보 제거 (해시는 암호문 기준)
+pub fn hide_content_stats(note: &mut NoteMetadata, sealed: &str) {
+    note.links.clear();
+    note.word_count = 0;
+    note.char_count = 0;
+    note.content_hash = Some(crate::content_hash(sealed));
+}
+
+// 암호화된 노트의 목록 제목: 본문의 첫 줄이 드러나지 않도록 파일 이름 사용
+pub fn locked_title(file_path: &str) -> String {
+    Path::new(file_path)
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| "Untitled Note".to_string())
+}
+
+// 잠금 해제된 노트의 본문 읽기 (잠겨 있으면 LOCKED 오류)
+pub fn read_unlocked(note: &NoteMetadata) -> Result<String, String> {
+    let bytes = read_file(Path::new(&note.file_path))?;
+    let envelope = parse_envelope(&String::from_utf8_lossy(&bytes))?;
+
+    let mut sessions = sessions();
+    let session = sessions.get_mut(&note.id).ok_or(LOCKED)?;
+    let result = open_with_key(&session.key, &envelope);
+    if result.is_ok() {
+        session.last_used = Instant::now();
+    } else {
+        // 외부에서 다른 패스프레이즈로 바뀐 파일 등: 다시 잠금 해제하도록 세션 제거
+        sessions.remove(&note.id);
+    }
+    result
+}
+
+// 잠금 해제된 노트의 키로 본문 암호화 (잠겨 있으면 LOCKED 오류)
+pub fn seal_for_note(id: &str, plaintext: &str) -> Result<String, String> {
+    let mut sessions = sessions();
+    let session = sessions.get_mut(id).ok_or(LOCKED)?;
+    session.last_used = Instant::now();
+    seal(&session.key, plaintext)
+}
+
+// 노트 암호화 커맨드 (제목은 파일 이름으로 바꿔 본문을 인덱스에 남기지 않음, 암호화 직후에는 잠금 해제 상태)
+#[tauri::command]
+pub fn encrypt_note(
+    app: tauri::AppHandle,
+    id: String,
+    passphrase: String,
+) -> Result<NoteMetadata, String> {
+    // 파일과 인덱스의 암호화 여부가 어긋나지 않도록 파일 쓰기부터 인덱스 저장까지 잠금 유지
+    let guard = lock_index();
+    let mut index = read_index()?;
+    let note = index
+        .notes
+        .iter_mut()
+        .find(|n| n.id == id)
+        .ok_or("Note not found")?;
+    if note.encrypted {
+        return Err("Note is already encrypted".to_string());
+    }
+
+    let path = Path::new(&note.file_path).to_path_buf();
+    let content = read_file(&path)?;
+    let plaintext =
+        String::from_utf8(content.clone()).map_err(|_| "Note is not valid UTF-8".to_string())?;
+
+    let key = new_key(&passphrase)?;
+    let sealed = seal(&key, &plaintext)?;
+    write_atomic(&path, sealed.as_bytes())?;
+
+    note.encrypted = true;
+    note.title = locked_title(&note.file_path);
+    hide_content_stats(note, &sealed);
+    let updated = note.clone();
+
+    if let Err(e) = write_index(&index) {
+        // 인덱스 저장에 실패하면 파일도 원래 내용으로 되돌림
+        let _ = write_atomic(&path, &content);
+        return Err(e);
+    }
+
+    sessions().insert(
+        id,
+        Session {
+            key,
+            last_used: Instant::now(),
+        },
+    );
+    drop(guard);
+
+    let _ = app.emit("refresh-notes-list", ());
+    Ok(updated)
+}
+
+// 노트 암호화 해제 커맨드 (평문으로 다시 저장)
+#[tauri::command]
+pub fn decrypt_note(
+    app: tauri::AppHandle,
+    id: String,
+    passphrase: String,
+) -> Result<NoteMetadata, String> {
+    let guard = lock_index();
+    let mut index = read_index()?;
+    let note = index
+        .notes
+        .iter_mut()
+        .find(|n| n.id == id)
+        .ok_or("Note not found")?;
+    if !note.encrypted {
+        return Err("Note is not encrypted".to_string());
+    }
+
+    let path = Path::new(&note.file_path).to_path_buf();
+    let sealed = read_file(&path)?;
+    let (plaintext, _) = open(&passphrase, &String::from_utf8_lossy(&sealed))?;
+    write_atomic(&path, plaintext.as_bytes())?;
+
+    note.encrypted = false;
+    note.title = extract_title(&plaintext);
+    refresh_content_stats(note, &plaintext);
+    let updated = note.clone();
+
+    if let Err(e) = write_index(&index) {
+        let _ = write_atomic(&path, &sealed);
+        return Err(e);
+    }
+    sessions().remove(&id);
+    drop(guard);
+
+    let _ = app.emit("note-file-changed", id);
+    let _ = app.emit("refresh-notes-list", ());
+    Ok(updated)
+}
+
+// 노트 잠금 해제 커맨드: 본문 반환
+#[tauri::command]
+pub fn unlock_note(id: String, passphrase: String) -> Result<String, String> {
+    let index = read_index()?;
+    let note = index
+        .notes
+        .iter()
+        .find(|n| n.id == id)
+        .ok_or("Note not found")?;
+    if !note.encrypted {
+        return Err("Note is not encrypted".to_string());
+    }
+
+    let bytes = read_file(Path::new(&note.file_path))?;
+    let (plaintext, key) = open(&passphrase, &String::from_utf8_lossy(&bytes))?;
+    sessions().insert(
+        id,
+        Session {
+            key,
+            last_used: Instant::now(),
+        },
+    );
+    drop(guard);
+    Ok(plaintext)
+}
+
+// 노트 잠금 커맨드
+#[tauri::command]
+pub fn lock_note(app: tauri::AppHandle, id: String) {
+    if sessions().remove(&id).is_some() {
+        let _ = app.emit("note-locked", id);
+    }
+}
+
+// 자동 잠금 스케줄러 시작
+pub fn start_auto_lock(app: tauri::AppHandle) {
+    std::thread::spawn(move || loop {
+        std::thread::sleep(CHECK_INTERVAL);
+
+        let minutes = crate::settings::read_settings()
+            .map(|s| s.security.auto_lock_minutes)
+            .unwrap_or_default();
+        for id in expire_sessions(minutes) {
+            let _ = app.emit("note-locked", id);
+        }
+    });
+}
+
+// minutes분 이상 사용하지 않은 세션 제거, 잠근 노트 id 반환 (0이면 자동 잠금 안 함)
+fn expire_sessions(minutes: u32) -> Vec<String> {
+    if minutes == 0 {
+        return vec![];
+    }
+    let timeout = Duration::from_secs(minutes as u64 * 60);
+
+    let mut sessions = sessions();
+    let expired: Vec<String> = sessions
+        .iter()
+        .filter(|(_, s)| s.last_used.elapsed() >= timeout)
+        .map(|(id, _)| id.clone())
+        .collect();
+    for id in &expired {
+        sessions.remove(id);
+    }
+    expired
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seal_and_open_roundtrip() {
+        let key = new_key("비밀번호 123").unwrap();
+        let sealed = seal(&key, "# 계정\n암호: hunter2").unwrap();
+
+        assert!(is_encrypted(&sealed));
+        assert!(!sealed.contains("hunter2"));
+        // 같은 키라도 저장할 때마다 nonce가 달라짐
+        assert_ne!(sealed, seal(&key, "# 계정\n암호: hunter2").unwrap());
+
+        let (plaintext, reopened) = open("비밀번호 123", &sealed).unwrap();
+        assert_eq!(plaintext, "# 계정\n암호: hunter2");
+        assert_eq!(reopened.key, key.key);
+        assert!(open("wrong", &sealed).is_err());
+        assert!(new_key("").is_err());
+    }
+
+    #[test]
+    fn test_tampered_note_is_rejected() {
+        let key = new_key("pass").unwrap();
+        let sealed = seal(&key, "secret").unwrap();
+
+        let mut envelope = parse_envelope(&sealed).unwrap();
+        let mut ciphertext = decode(&envelope.ciphertext).unwrap();
+        ciphertext[0] ^= 1;
+        envelope.ciphertext = STANDARD.encode(ciphertext);
+        assert!(open_with_key(&key, &envelope).is_err());
+
+        assert!(parse_envelope("# plain note").is_err());
+    }
+}
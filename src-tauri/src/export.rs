@@ -0,0 +1,402 @@
+// 노트를 독립 실행형 HTML 문서로 내보내기
+//
+// 마크다운 렌더링은 프론트엔드(react-markdown + remark-gfm + rehype-raw)와 같은
+// CommonMark + GFM 규칙을 따르며, CLI에서도 동일하게 동작하도록 Rust에서 처리한다.
+
+use crate::{extract_title, read_file, read_index, sanitize_file_name, unique_name};
+use pulldown_cmark::{html, CowStr, Event, Options, Parser, Tag};
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const DEFAULT_COLOR: &str = "#FFF7D1";
+
+// 파일 경로를 URL 경로로 쓸 때 인코딩할 문자
+const PATH_ENCODE_SET: &percent_encoding::AsciiSet = &percent_encoding::CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?');
+
+#[derive(Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    // 노트 색상을 배경으로 사용하는 화면용 HTML
+    Html,
+    // 흰 배경, 인쇄/PDF 저장용 여백이 적용된 HTML
+    Print,
+}
+
+#[derive(Deserialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ImageMode {
+    // 이미지를 data URI로 HTML에 포함
+    #[default]
+    Inline,
+    // 이미지를 "<파일명>_files" 폴더로 복사하고 상대 경로로 참조
+    Bundle,
+}
+
+// 노트 내보내기 커맨드 (output_path가 없으면 저장 다이얼로그 표시)
+#[tauri::command]
+pub async fn export_note(
+    app: tauri::AppHandle,
+    id: String,
+    format: ExportFormat,
+    images: Option<ImageMode>,
+    output_path: Option<String>,
+) -> Result<String, String> {
+    use tauri_plugin_dialog::{DialogExt, FilePath};
+
+    let index = read_index()?;
+    let note = index
+        .notes
+        .iter()
+        .find(|n| n.id == id)
+        .ok_or("Note not found")?;
+    if note.encrypted {
+        return Err("Encrypted notes cannot be exported".to_string());
+    }
+
+    let output = match output_path {
+        Some(path) => PathBuf::from(path),
+        None => {
+            let file_path = app
+                .dialog()
+                .file()
+                .add_filter("HTML", &["html"])
+                .set_file_name(format!("{}.html", sanitize_file_name(&note.title)))
+                .blocking_save_file();
+
+            match file_path {
+                Some(FilePath::Path(path)) => path,
+                _ => return Err("No file selected".to_string()),
+            }
+        }
+    };
+
+    let content_bytes = read_file(Path::new(&note.file_path))?;
+    let content = String::from_utf8_lossy(&content_bytes).to_string();
+
+    export_markdown(
+        &content,
+        Path::new(&note.file_path).parent(),
+        note.color.as_deref().unwrap_or(DEFAULT_COLOR),
+        &output,
+        format,
+        images.unwrap_or_default(),
+    )?;
+
+    Ok(output.to_string_lossy().to_string())
+}
+
+// 마크다운을 HTML 파일로 저장 (커맨드/CLI 공용)
+pub fn export_markdown(
+    markdown: &str,
+    source_dir: Option<&Path>,
+    color: &str,
+    output: &Path,
+    format: ExportFormat,
+    images: ImageMode,
+) -> Result<(), String> {
+    let stem = output
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| "note".to_string());
+    let assets_name = format!("{}_files", stem);
+    let assets_dir = output.with_file_name(&assets_name);
+    // 원본 이미지 경로 -> 복사된 파일명 (같은 이미지는 한 번만 복사)
+    let mut bundled: HashMap<PathBuf, String> = HashMap::new();
+    let mut used_names = HashSet::new();
+
+    let body = markdown_to_html(markdown, |src| {
+        let path = resolve_local_image(src, source_dir)?;
+        match images {
+            ImageMode::Inline => {
+                use base64::Engine;
+                let bytes = read_file(&path).ok()?;
+                let mime = mime_guess::from_path(&path).first_or_octet_stream();
+                Some(format!(
+                    "data:{};base64,{}",
+                    mime,
+                    base64::engine::general_purpose::STANDARD.encode(bytes)
+                ))
+            }
+            ImageMode::Bundle => {
+                let name = match bundled.get(&path) {
+                    Some(name) => name.clone(),
+                    None => {
+                        // 폴더가 다른 같은 이름의 이미지는 번호를 붙여 구분
+                        let file_name = path.file_name()?.to_string_lossy().to_string();
+                        let name = unique_name(&mut used_names, &file_name);
+                        fs::create_dir_all(&assets_dir).ok()?;
+                        fs::write(assets_dir.join(&name), read_file(&path).ok()?).ok()?;
+                        bundled.insert(path, name.clone());
+                        name
+                    }
+                };
+                Some(format!(
+                    "{}/{}",
+                    percent_encoding::utf8_percent_encode(&assets_name, PATH_ENCODE_SET),
+                    percent_encoding::utf8_percent_encode(&name, PATH_ENCODE_SET)
+                ))
+            }
+        }
+    });
+
+    let document = render_document(&extract_title(markdown), &body, color, format);
+    fs::write(output, document).map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+// 마크다운 -> HTML 본문 (rewrite_image가 Some을 반환하면 이미지 src 교체)
+pub fn markdown_to_html<F>(markdown: &str, mut rewrite_image: F) -> String
+where
+    F: FnMut(&str) -> Option<String>,
+{
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_GFM;
+
+    let parser = Parser::new_ext(markdown, options).map(|event| match event {
+        Event::Start(Tag::Image {
+            link_type,
+            dest_url,
+            title,
+            id,
+        }) => {
+            let dest_url = match rewrite_image(&dest_url) {
+                Some(new_url) => CowStr::from(new_url),
+                None => dest_url,
+            };
+            Event::Start(Tag::Image {
+                link_type,
+                dest_url,
+                title,
+                id,
+            })
+        }
+        other => other,
+    });
+
+    let mut body = String::new();
+    html::push_html(&mut body, parser);
+    body
+}
+
+// 이미지 src가 로컬 파일을 가리키면 실제 경로 반환
+fn resolve_local_image(src: &str, source_dir: Option<&Path>) -> Option<PathBuf> {
+    if src.starts_with("http://") || src.starts_with("https://") || src.starts_with("data:") {
+        return None;
+    }
+
+    let decoded = percent_encoding::percent_decode_str(src)
+        .decode_utf8_lossy()
+        .to_string();
+    let path = PathBuf::from(decoded);
+    let path = match source_dir {
+        Some(dir) if path.is_relative() => dir.join(path),
+        _ => path,
+    };
+
+    path.is_file().then_some(path)
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// 본문을 스타일이 포함된 완전한 HTML 문서로 감싸기
+fn render_document(title: &str, body: &str, color: &str, format: ExportFormat) -> String {
+    // 색상 값은 CSS에 그대로 들어가므로 #RRGGBB 형식만 허용
+    let is_hex_color = matches!(color.len(), 4 | 7)
+        && color.starts_with('#')
+        && color[1..].chars().all(|c| c.is_ascii_hexdigit());
+    let color = if is_hex_color { color } else { DEFAULT_COLOR };
+
+    let theme = match format {
+        ExportFormat::Html => format!(
+            "body {{ background: {color}; }}\n\
+             main {{ max-width: 760px; margin: 40px auto; padding: 32px 40px; \
+             background: {color}; border-radius: 8px; box-shadow: 0 4px 16px rgba(0, 0, 0, 0.12); }}",
+        ),
+        ExportFormat::Print => format!(
+            "@page {{ margin: 2cm; }}\n\
+             body {{ background: #FFFFFF; }}\n\
+             main {{ max-width: none; margin: 0; padding: 0 0 0 16px; border-left: 6px solid {color}; }}\n\
+             a[href^=\"http\"]::after {{ content: \" (\" attr(href) \")\"; color: #6B7280; font-size: 0.85em; }}\n\
+             pre, blockquote, table, img {{ page-break-inside: avoid; }}",
+        ),
+    };
+
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="ko">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<meta name="generator" content="StickerMD">
+<title>{title}</title>
+<style>
+{base}
+{theme}
+</style>
+</head>
+<body>
+<main>
+{body}</main>
+</body>
+</html>
+"#,
+        title = escape_html(title),
+        base = BASE_CSS,
+        theme = theme,
+        body = body,
+    )
+}
+
+const BASE_CSS: &str = r#"* { box-sizing: border-box; }
+body { margin: 0; color: #111827; font-family: "Pretendard Variable", Inter, system-ui, Avenir, Helvetica, Arial, sans-serif; font-size: 16px; line-height: 1.6; }
+h1 { font-size: 1.75em; font-weight: 800; }
+h2 { font-size: 1.5em; font-weight: 700; }
+h3 { font-size: 1.25em; font-weight: 600; }
+a { color: #2563EB; }
+blockquote { margin: 0; padding-left: 1em; border-left: 3px solid #D1D5DB; color: #6B7280; font-style: italic; }
+code { font-family: 'D2Coding', 'Consolas', 'Monaco', 'Andale Mono', 'Ubuntu Mono', monospace; background: rgba(0, 0, 0, 0.05); border-radius: 3px; padding: 0 2px; }
+pre { background: #282C34; color: #ABB2BF; padding: 12px 16px; border-radius: 6px; overflow-x: auto; }
+pre code { background: none; padding: 0; }
+del { color: #6B7280; }
+hr { border: none; border-top: 1px solid #D1D5DB; }
+img { max-width: 100%; }
+table { border-collapse: collapse; }
+th, td { border: 1px solid #D1D5DB; padding: 4px 8px; }
+li:has(> input[type="checkbox"]) { list-style: none; margin-left: -1.3em; }
+@media print { body { background: #FFFFFF; } main { box-shadow: none; margin: 0; } }"#;
+
+// CLI 내보내기: stickermd --export <input.md> <output.html> [--print] [--bundle-images]
+// 내보내기 인자가 아니면 None을 반환하여 일반 실행을 계속함
+pub fn run_cli(args: &[String]) -> Option<i32> {
+    if args.get(1).map(String::as_str) != Some("--export") {
+        return None;
+    }
+
+    let positional: Vec<&String> = args
+        .iter()
+        .skip(2)
+        .filter(|a| !a.starts_with("--"))
+        .collect();
+    let (input, output) = match positional.as_slice() {
+        [input, output] => (PathBuf::from(input), PathBuf::from(output)),
+        _ => {
+            eprintln!(
+                "Usage: stickermd --export <input.md> <output.html> [--print] [--bundle-images]"
+            );
+            return Some(2);
+        }
+    };
+
+    let format = if args.iter().any(|a| a == "--print") {
+        ExportFormat::Print
+    } else {
+        ExportFormat::Html
+    };
+    let images = if args.iter().any(|a| a == "--bundle-images") {
+        ImageMode::Bundle
+    } else {
+        ImageMode::Inline
+    };
+
+    let result = read_file(&input).and_then(|bytes| {
+        let content = String::from_utf8_lossy(&bytes).to_string();
+
+        // 인덱스에 등록된 노트라면 저장된 색상 사용 (상대 경로/심볼릭 링크도 같은 파일로 비교)
+        let input_path = fs::canonicalize(&input).unwrap_or_else(|_| input.clone());
+        let color = read_index()
+            .ok()
+            .and_then(|index| {
+                index
+                    .notes
+                    .into_iter()
+                    .find(|n| fs::canonicalize(&n.file_path).is_ok_and(|path| path == input_path))
+                    .and_then(|n| n.color)
+            })
+            .unwrap_or_else(|| DEFAULT_COLOR.to_string());
+
+        export_markdown(&content, input.parent(), &color, &output, format, images)
+    });
+
+    match result {
+        Ok(()) => {
+            println!("Exported {} -> {}", input.display(), output.display());
+            Some(0)
+        }
+        Err(e) => {
+            eprintln!("Export failed: {}", e);
+            Some(1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markdown_to_html_gfm() {
+        let markdown = "# 제목\n\n- [x] done\n- [ ] todo\n\n~~old~~\n\n| a | b |\n|---|---|\n| 1 | 2 |\n\n![img](pic.png)";
+        let html = markdown_to_html(markdown, |src| Some(format!("assets/{}", src)));
+
+        assert!(html.contains("<h1>제목</h1>"));
+        assert!(html.contains(r#"<input disabled="" type="checkbox" checked=""/>"#));
+        assert!(html.contains("<del>old</del>"));
+        assert!(html.contains("<table>"));
+        assert!(html.contains(r#"src="assets/pic.png""#));
+    }
+
+    #[test]
+    fn test_render_document_rejects_invalid_color() {
+        let html = render_document("<T>", "<p>x</p>", "red;}</style>", ExportFormat::Print);
+        assert!(html.contains("<title>&lt;T&gt;</title>"));
+        assert!(html.contains(DEFAULT_COLOR));
+        assert!(!html.contains("red;}"));
+    }
+
+    #[test]
+    fn test_bundle_images_dedupes_by_source_path() {
+        let root = std::env::temp_dir().join(format!("stickermd_export_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(root.join("a")).unwrap();
+        fs::create_dir_all(root.join("b")).unwrap();
+        fs::write(root.join("a/pic.png"), [1u8]).unwrap();
+        fs::write(root.join("b/pic.png"), [2u8]).unwrap();
+
+        let output = root.join("out.html");
+        let markdown = "![](a/pic.png) ![](b/pic.png) ![](a/pic.png)";
+        export_markdown(
+            markdown,
+            Some(&root),
+            DEFAULT_COLOR,
+            &output,
+            ExportFormat::Html,
+            ImageMode::Bundle,
+        )
+        .unwrap();
+
+        let assets = root.join("out_files");
+        assert_eq!(fs::read(assets.join("pic.png")).unwrap(), vec![1u8]);
+        assert_eq!(fs::read(assets.join("pic_1.png")).unwrap(), vec![2u8]);
+        assert_eq!(fs::read_dir(&assets).unwrap().count(), 2);
+        let html = fs::read_to_string(&output).unwrap();
+        assert_eq!(html.matches(r#"src="out_files/pic.png""#).count(), 2);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,682 @@
+// 저장소 루트를 git 저장소로 관리 (설정의 git.enabled)
+//
+// - 노트 저장은 commit_delay_seconds 동안 모아서 하나의 커밋으로 기록
+// - 노트 삭제는 바로 커밋
+// - 원격 저장소와 pull/push, 병합 충돌이 난 노트는 상대편 내용을 충돌 노트로 추가
+//
+// git 명령줄 도구를 사용하므로 git이 설치되어 있어야 함
+
+use crate::settings::read_settings;
+use crate::{
+    available_path, get_storage_dir, read_file, refresh_content_stats, write_atomic,
+    write_atomic_raw, NoteMetadata, NotesIndex,
+};
+use serde::Serialize;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::Emitter;
+
+const BRANCH: &str = "main";
+const REMOTE_NAME: &str = "origin";
+// 기기별 설정과 상태, 백업은 저장소에 포함하지 않음
+const GITIGNORE: &str = "settings.json\nsync_state.json\nhook_log.json\nbackups/\n.*.tmp\n";
+const CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+// 커밋, pull, push가 동시에 실행되지 않도록 잠금
+static GIT_LOCK: Mutex<()> = Mutex::new(());
+// 아직 커밋하지 않은 저장 기록
+static PENDING: Mutex<Option<Pending>> = Mutex::new(None);
+
+struct Pending {
+    titles: BTreeSet<String>,
+    last_change: Instant,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NoteRevision {
+    commit: String,
+    author: String,
+    date: String,
+    message: String,
+    // 해당 커밋에서의 저장소 기준 경로 (이름이 바뀐 노트)
+    path: String,
+}
+
+#[derive(Serialize, Default, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PullReport {
+    updated: bool,
+    conflicts: usize,
+    #[serde(skip)]
+    changed_paths: Vec<String>,
+}
+
+fn run(root: &Path, args: &[&str]) -> Result<Vec<u8>, String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(root)
+        .args(args)
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .output()
+        .map_err(|e| format!("Failed to run git: {}", e))?;
+
+    if output.status.success() {
+        Ok(output.stdout)
+    } else {
+        Err(format!(
+            "git {} failed: {}",
+            args.first().unwrap_or(&""),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))
+    }
+}
+
+fn git(root: &Path, args: &[&str]) -> Result<String, String> {
+    run(root, args).map(|out| String::from_utf8_lossy(&out).trim().to_string())
+}
+
+// 저장소가 없으면 생성
+fn ensure_repo(root: &Path) -> Result<(), String> {
+    if root.join(".git").exists() {
+        return Ok(());
+    }
+    fs::create_dir_all(root).map_err(|e| e.to_string())?;
+    git(root, &["init", "--quiet"])?;
+    git(
+        root,
+        &["symbolic-ref", "HEAD", &format!("refs/heads/{}", BRANCH)],
+    )?;
+
+    // 사용자 정보가 설정되지 않은 환경에서도 커밋할 수 있도록 저장소에만 기본값 설정
+    if git(root, &["config", "user.name"]).is_err() {
+        git(root, &["config", "user.name", "StickerMD"])?;
+    }
+    if git(root, &["config", "user.email"]).is_err() {
+        git(root, &["config", "user.email", "stickermd@localhost"])?;
+    }
+
+    let gitignore = root.join(".gitignore");
+    if !gitignore.exists() {
+        fs::write(gitignore, GITIGNORE).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+fn has_commits(root: &Path) -> bool {
+    git(root, &["rev-parse", "--verify", "--quiet", "HEAD"]).is_ok()
+}
+
+// 바뀐 파일을 모두 커밋, 바뀐 것이 없으면 false
+fn commit_all(root: &Path, message: &str) -> Result<bool, String> {
+    ensure_repo(root)?;
+    git(root, &["add", "-A"])?;
+    if git(root, &["status", "--porcelain"])?.is_empty() {
+        return Ok(false);
+    }
+    git(root, &["commit", "--quiet", "-m", message])?;
+    Ok(true)
+}
+
+fn commit_message(titles: &BTreeSet<String>) -> String {
+    match titles.len() {
+        0 => "Update notes".to_string(),
+        1 => format!("Update {}", titles.iter().next().unwrap()),
+        n => {
+            let list: Vec<String> = titles.iter().map(|t| format!("- {}", t)).collect();
+            format!("Update {} notes\n\n{}", n, list.join("\n"))
+        }
+    }
+}
+
+fn is_enabled() -> bool {
+    read_settings().map(|s| s.git.enabled).unwrap_or(false)
+}
+
+// 노트 저장 기록 (일정 시간 동안 더 저장되지 않으면 커밋)
+pub fn schedule_commit(title: &str) {
+    if !is_enabled() {
+        return;
+    }
+    let mut pending = PENDING.lock().unwrap_or_else(|e| e.into_inner());
+    let pending = pending.get_or_insert_with(|| Pending {
+        titles: BTreeSet::new(),
+        last_change: Instant::now(),
+    });
+    pending.titles.insert(title.to_string());
+    pending.last_change = Instant::now();
+}
+
+// 기록된 저장을 바로 커밋
+fn flush_pending(root: &Path) -> Result<(), String> {
+    let pending = PENDING.lock().unwrap_or_else(|e| e.into_inner()).take();
+    if let Some(pending) = pending {
+        commit_all(root, &commit_message(&pending.titles))?;
+    }
+    Ok(())
+}
+
+// 노트 삭제 커밋 (실패해도 삭제 자체는 성공으로 처리)
+pub fn record_deletion(title: &str) {
+    if !is_enabled() {
+        return;
+    }
+    let _guard = GIT_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let result = get_storage_dir().and_then(|root| {
+        flush_pending(&root)?;
+        commit_all(&root, &format!("Delete {}", title))
+    });
+    if let Err(e) = result {
+        println!("Git: failed to commit deletion: {}", e);
+    }
+}
+
+// 저장 기록을 확인하여 커밋하는 스케줄러 시작
+pub fn start_scheduler() {
+    std::thread::spawn(|| loop {
+        std::thread::sleep(CHECK_INTERVAL);
+
+        let idle = PENDING
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .as_ref()
+            .map(|p| p.last_change.elapsed());
+        let Some(idle) = idle else {
+            continue;
+        };
+        let Ok(settings) = read_settings() else {
+            continue;
+        };
+        if !settings.git.enabled {
+            PENDING.lock().unwrap_or_else(|e| e.into_inner()).take();
+            continue;
+        }
+        if idle < Duration::from_secs(settings.git.commit_delay_seconds as u64) {
+            continue;
+        }
+
+        let _guard = GIT_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        if let Err(e) = get_storage_dir().and_then(|root| flush_pending(&root)) {
+            println!("Git: failed to commit: {}", e);
+        }
+    });
+}
+
+// 노트 파일의 저장소 기준 경로 ("notes/a.md")
+fn relative_path(root: &Path, file_path: &str) -> Result<String, String> {
+    let relative = Path::new(file_path)
+        .strip_prefix(root)
+        .map_err(|_| "Note is not stored in the library".to_string())?;
+    Ok(relative.to_string_lossy().replace('\\', "/"))
+}
+
+// 노트 파일의 커밋 기록 (최신순, 이름 변경 추적)
+fn note_history(root: &Path, relative: &str) -> Result<Vec<NoteRevision>, String> {
+    if !root.join(".git").exists() || !has_commits(root) {
+        return Ok(Vec::new());
+    }
+    let log = git(
+        root,
+        &[
+            "log",
+            "--follow",
+            "--name-only",
+            "--format=%x1e%H%x1f%an%x1f%aI%x1f%s",
+            "--",
+            relative,
+        ],
+    )?;
+
+    Ok(log
+        .split('\u{1e}')
+        .filter_map(|entry| {
+            let mut lines = entry.lines().filter(|l| !l.is_empty());
+            let mut fields = lines.next()?.split('\u{1f}');
+            Some(NoteRevision {
+                commit: fields.next()?.to_string(),
+                author: fields.next()?.to_string(),
+                date: fields.next()?.to_string(),
+                message: fields.next().unwrap_or_default().to_string(),
+                path: lines.next().unwrap_or(relative).to_string(),
+            })
+        })
+        .collect())
+}
+
+// 원격 저장소 주소 설정
+fn configure_remote(root: &Path, url: &str) -> Result<(), String> {
+    match git(root, &["remote", "get-url", REMOTE_NAME]) {
+        Ok(current) if current == url => Ok(()),
+        Ok(_) => git(root, &["remote", "set-url", REMOTE_NAME, url]).map(|_| ()),
+        Err(_) => git(root, &["remote", "add", REMOTE_NAME, url]).map(|_| ()),
+    }
+}
+
+// 원격 저장소의 변경을 병합, 충돌한 노트는 상대편 내용을 충돌 노트로 추가
+fn pull(root: &Path, url: &str) -> Result<PullReport, String> {
+    commit_all(root, "Update notes")?;
+    configure_remote(root, url)?;
+    git(root, &["fetch", "--quiet", REMOTE_NAME])?;
+
+    let upstream = format!("refs/remotes/{}/{}", REMOTE_NAME, BRANCH);
+    if git(root, &["rev-parse", "--verify", "--quiet", &upstream]).is_err() {
+        // 원격 저장소가 비어 있음
+        return Ok(PullReport::default());
+    }
+    let before = git(root, &["rev-parse", "HEAD"]).ok();
+
+    let mut report = PullReport::default();
+    let merged = git(
+        root,
+        &[
+            "merge",
+            "--quiet",
+            "--no-edit",
+            "--allow-unrelated-histories",
+            &upstream,
+        ],
+    );
+    if let Err(e) = merged {
+        let conflicted: Vec<String> = git(root, &["diff", "--name-only", "--diff-filter=U"])?
+            .lines()
+            .map(str::to_string)
+            .collect();
+        if conflicted.is_empty() {
+            let _ = git(root, &["merge", "--abort"]);
+            return Err(e);
+        }
+
+        report.conflicts = resolve_conflicts(root, &conflicted)?;
+        git(root, &["add", "-A"])?;
+        git(root, &["commit", "--quiet", "--no-edit"])?;
+    }
+
+    let after = git(root, &["rev-parse", "HEAD"])?;
+    report.updated = before.as_deref() != Some(after.as_str());
+    if report.updated {
+        let diff = match &before {
+            Some(before) => git(root, &["diff", "--name-only", before, &after])?,
+            None => git(root, &["ls-files"])?,
+        };
+        report.changed_paths = diff.lines().map(str::to_string).collect();
+
+        // 다른 기기의 인덱스에 기록된 경로를 이 기기 기준으로 변경
+        let index_path = root.join("index.json");
+        if let Ok(bytes) = read_file(&index_path) {
+            let mut index: NotesIndex =
+                serde_json::from_slice(&bytes).map_err(|e| e.to_string())?;
+            if rebase_paths(&mut index, root) {
+                write_index_at(&index_path, &index)?;
+                commit_all(root, "Update note paths")?;
+            }
+        }
+    }
+    Ok(report)
+}
+
+// 충돌 파일 정리, 추가한 충돌 노트 수 반환
+// - 노트: 이쪽 내용을 유지하고 상대편 내용은 새 충돌 노트로
+// - index.json: 노트 id 기준 합집합
+// - 그 밖의 파일: 이쪽 내용 유지
+fn resolve_conflicts(root: &Path, conflicted: &[String]) -> Result<usize, String> {
+    let stage = |n: u8, path: &str| run(root, &["show", &format!(":{}:{}", n, path)]).ok();
+
+    let mut index_sides = None;
+    let mut copies = Vec::new();
+    for path in conflicted {
+        let (ours, theirs) = (stage(2, path), stage(3, path));
+        let file = root.join(path);
+        if path == "index.json" {
+            index_sides = Some((ours, theirs));
+            continue;
+        }
+
+        match (ours, theirs) {
+            (Some(ours), Some(theirs)) if path.starts_with("notes/") => {
+                write_atomic_raw(&file, &ours)?;
+                let copy = conflict_path(&file);
+                write_atomic_raw(&copy, &theirs)?;
+                copies.push((path.clone(), copy, theirs));
+            }
+            // 한쪽에서 삭제된 파일은 남아 있는 내용 유지
+            (Some(bytes), _) | (None, Some(bytes)) => write_atomic_raw(&file, &bytes)?,
+            (None, None) => {}
+        }
+    }
+
+    let index_path = root.join("index.json");
+    let mut index = match index_sides {
+        Some((ours, theirs)) => merge_indexes(ours.as_deref(), theirs.as_deref())?,
+        None => read_file(&index_path)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .unwrap_or_default(),
+    };
+    rebase_paths(&mut index, root);
+
+    for (original, copy, bytes) in &copies {
+        let Some(source) = index
+            .notes
+            .iter()
+            .find(|n| relative_path(root, &n.file_path).ok().as_ref() == Some(original))
+            .cloned()
+        else {
+            continue;
+        };
+        let content = crate::vault::decode(bytes.clone())
+            .map(|b| String::from_utf8_lossy(&b).to_string())
+            .unwrap_or_default();
+
+        let mut note = NoteMetadata {
+            id: uuid::Uuid::new_v4().to_string(),
+            title: format!(
+                "{} (conflict {})",
+                source.title,
+                chrono::Local::now().format("%Y-%m-%d")
+            ),
+            file_path: copy.to_string_lossy().to_string(),
+            pinned: false,
+            ..source.clone()
+        };
+        if note.encrypted {
+            crate::encryption::hide_content_stats(&mut note, &content);
+        } else {
+            refresh_content_stats(&mut note, &content);
+        }
+        index.notes.push(note);
+    }
+    write_index_at(&index_path, &index)?;
+    Ok(copies.len())
+}
+
+// 충돌 노트 파일 경로 ("a.md" -> "a (conflict 2024-01-01).md")
+fn conflict_path(file: &Path) -> PathBuf {
+    let dir = file.parent().unwrap_or(Path::new("."));
+    let stem = file
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| "note".to_string());
+    let name = format!(
+        "{} (conflict {}).md",
+        stem,
+        chrono::Local::now().format("%Y-%m-%d")
+    );
+    available_path(dir, &crate::sanitize_file_name(&name), None)
+}
+
+// 양쪽 인덱스를 노트/그룹 id 기준으로 합침 (같은 id는 이쪽 기준)
+fn merge_indexes(ours: Option<&[u8]>, theirs: Option<&[u8]>) -> Result<NotesIndex, String> {
+    let parse = |bytes: Option<&[u8]>| -> Result<NotesIndex, String> {
+        match bytes {
+            Some(bytes) => {
+                let bytes = crate::vault::decode(bytes.to_vec())?;
+                serde_json::from_slice(&bytes).map_err(|e| e.to_string())
+            }
+            None => Ok(NotesIndex::default()),
+        }
+    };
+    let mut index = parse(ours)?;
+    let theirs = parse(theirs)?;
+
+    for note in theirs.notes {
+        if !index.notes.iter().any(|n| n.id == note.id) {
+            index.notes.push(note);
+        }
+    }
+    for group in theirs.groups {
+        if !index.groups.iter().any(|g| g.id == group.id) {
+            index.groups.push(group);
+        }
+    }
+    Ok(index)
+}
+
+// 다른 기기의 저장소 경로를 이 저장소의 notes 폴더 기준으로 변경, 바뀐 것이 있으면 true
+fn rebase_paths(index: &mut NotesIndex, root: &Path) -> bool {
+    let notes_dir = root.join("notes");
+    let mut changed = false;
+    for note in index.notes.iter_mut() {
+        if Path::new(&note.file_path).starts_with(root) {
+            continue;
+        }
+        let normalized = note.file_path.replace('\\', "/");
+        let found = normalized
+            .match_indices("/notes/")
+            .map(|(pos, marker)| notes_dir.join(&normalized[pos + marker.len()..]))
+            .find(|candidate| candidate.exists());
+        if let Some(path) = found {
+            note.file_path = path.to_string_lossy().to_string();
+            changed = true;
+        }
+    }
+    changed
+}
+
+fn write_index_at(path: &Path, index: &NotesIndex) -> Result<(), String> {
+    let content = serde_json::to_string_pretty(index).map_err(|e| e.to_string())?;
+    write_atomic(path, content.as_bytes())
+}
+
+// 이쪽 커밋을 원격 저장소로 보냄
+fn push(root: &Path, url: &str) -> Result<(), String> {
+    commit_all(root, "Update notes")?;
+    configure_remote(root, url)?;
+    let target = format!("HEAD:refs/heads/{}", BRANCH);
+    git(root, &["push", "--quiet", REMOTE_NAME, &target]).map_err(|e| {
+        if e.contains("rejected") {
+            "Remote has changes that are not pulled yet".to_string()
+        } else {
+            e
+        }
+    })?;
+    Ok(())
+}
+
+fn remote_url() -> Result<String, String> {
+    let settings = read_settings()?.git;
+    if !settings.enabled {
+        return Err("Git repository mode is not enabled".to_string());
+    }
+    settings
+        .remote
+        .ok_or_else(|| "Git remote is not set".to_string())
+}
+
+// 노트의 커밋 기록 커맨드
+#[tauri::command]
+pub fn get_note_history(id: String) -> Result<Vec<NoteRevision>, String> {
+    let note = crate::read_index()?
+        .notes
+        .into_iter()
+        .find(|n| n.id == id)
+        .ok_or("Note not found")?;
+    let root = get_storage_dir()?;
+    let relative = relative_path(&root, &note.file_path)?;
+
+    let _guard = GIT_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    note_history(&root, &relative)
+}
+
+// 특정 커밋 시점의 노트 내용 커맨드
+#[tauri::command]
+pub fn get_note_revision(id: String, commit: String) -> Result<String, String> {
+    let note = crate::read_index()?
+        .notes
+        .into_iter()
+        .find(|n| n.id == id)
+        .ok_or("Note not found")?;
+    if note.encrypted {
+        return Err("History of encrypted notes cannot be shown".to_string());
+    }
+    let root = get_storage_dir()?;
+    let relative = relative_path(&root, &note.file_path)?;
+
+    let _guard = GIT_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let revision = note_history(&root, &relative)?
+        .into_iter()
+        .find(|r| r.commit == commit)
+        .ok_or("Revision not found")?;
+    let bytes = run(
+        &root,
+        &["show", &format!("{}:{}", revision.commit, revision.path)],
+    )?;
+    let bytes = crate::vault::decode(bytes)?;
+    Ok(String::from_utf8_lossy(&bytes).to_string())
+}
+
+// 원격 저장소에서 가져오기 커맨드
+#[tauri::command]
+pub fn git_pull(app: tauri::AppHandle) -> Result<PullReport, String> {
+    let url = remote_url()?;
+    let root = get_storage_dir()?;
+
+    let _guard = GIT_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    flush_pending(&root)?;
+    // 병합과 index.json 재작성 중에 다른 저장이 인덱스를 덮어쓰지 않도록 잠금 (GIT_LOCK 다음 순서)
+    let index_guard = crate::lock_index();
+    let report = pull(&root, &url)?;
+    drop(index_guard);
+
+    if report.updated {
+        let index = crate::read_index()?;
+        for path in &report.changed_paths {
+            let changed = index
+                .notes
+                .iter()
+                .find(|n| relative_path(&root, &n.file_path).ok().as_ref() == Some(path));
+            if let Some(note) = changed {
+                let _ = app.emit("note-file-changed", &note.id);
+            }
+        }
+        let _ = app.emit("refresh-notes-list", ());
+    }
+    Ok(report)
+}
+
+// 원격 저장소로 보내기 커맨드
+#[tauri::command]
+pub fn git_push() -> Result<(), String> {
+    let url = remote_url()?;
+    let root = get_storage_dir()?;
+
+    let _guard = GIT_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    flush_pending(&root)?;
+    push(&root, &url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extract_title;
+
+    fn write_note(root: &Path, id: &str, content: &str) {
+        let notes_dir = root.join("notes");
+        fs::create_dir_all(&notes_dir).unwrap();
+        let path = notes_dir.join(format!("{}.md", id));
+        fs::write(&path, content).unwrap();
+
+        let index_path = root.join("index.json");
+        let mut index: NotesIndex = fs::read(&index_path)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .unwrap_or_default();
+        match index.notes.iter_mut().find(|n| n.id == id) {
+            Some(note) => refresh_content_stats(note, content),
+            None => index.notes.push(NoteMetadata {
+                id: id.to_string(),
+                title: extract_title(content),
+                file_path: path.to_string_lossy().to_string(),
+                ..Default::default()
+            }),
+        }
+        write_index_at(&index_path, &index).unwrap();
+    }
+
+    fn read_index_at(root: &Path) -> NotesIndex {
+        serde_json::from_slice(&fs::read(root.join("index.json")).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_history_and_pull_push() {
+        let dir = std::env::temp_dir().join(format!("stickermd_git_{}", uuid::Uuid::new_v4()));
+        let remote = dir.join("remote.git");
+        fs::create_dir_all(&remote).unwrap();
+        git(&remote, &["init", "--quiet", "--bare"]).unwrap();
+        let url = remote.to_string_lossy().to_string();
+        let (a, b) = (dir.join("a"), dir.join("b"));
+
+        write_note(&a, "n1", "# 장보기\n우유");
+        assert!(commit_all(&a, "Update 장보기").unwrap());
+        write_note(&a, "n1", "# 장보기\n우유\n계란");
+        assert!(commit_all(&a, "Update 장보기").unwrap());
+        assert!(!commit_all(&a, "Update 장보기").unwrap());
+
+        let history = note_history(&a, "notes/n1.md").unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].message, "Update 장보기");
+        let first = run(&a, &["show", &format!("{}:notes/n1.md", history[1].commit)]).unwrap();
+        assert_eq!(first, "# 장보기\n우유".as_bytes());
+
+        // 비어 있는 원격 저장소에서 가져오기
+        assert_eq!(pull(&a, &url).unwrap(), PullReport::default());
+        push(&a, &url).unwrap();
+
+        // 다른 기기에서 가져오면 경로가 그 기기 기준으로 바뀜
+        write_note(&b, "n2", "# 할 일");
+        let report = pull(&b, &url).unwrap();
+        assert!(report.updated);
+        assert_eq!(report.conflicts, 0);
+        let index = read_index_at(&b);
+        assert_eq!(index.notes.len(), 2);
+        let n1 = index.notes.iter().find(|n| n.id == "n1").unwrap();
+        assert_eq!(Path::new(&n1.file_path), b.join("notes").join("n1.md"));
+        push(&b, &url).unwrap();
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_pull_turns_conflicts_into_notes() {
+        let dir = std::env::temp_dir().join(format!("stickermd_git_{}", uuid::Uuid::new_v4()));
+        let remote = dir.join("remote.git");
+        fs::create_dir_all(&remote).unwrap();
+        git(&remote, &["init", "--quiet", "--bare"]).unwrap();
+        let url = remote.to_string_lossy().to_string();
+        let (a, b) = (dir.join("a"), dir.join("b"));
+
+        write_note(&a, "n1", "# 메모\n원본");
+        push(&a, &url).unwrap();
+        pull(&b, &url).unwrap();
+
+        write_note(&a, "n1", "# 메모\nA에서 수정");
+        push(&a, &url).unwrap();
+        write_note(&b, "n1", "# 메모\nB에서 수정");
+        assert!(push(&b, &url).is_err());
+
+        let report = pull(&b, &url).unwrap();
+        assert_eq!(report.conflicts, 1);
+        assert_eq!(
+            fs::read_to_string(b.join("notes").join("n1.md")).unwrap(),
+            "# 메모\nB에서 수정"
+        );
+        let index = read_index_at(&b);
+        let copy = index.notes.iter().find(|n| n.id != "n1").unwrap();
+        assert!(copy.title.starts_with("메모 (conflict"));
+        assert_eq!(
+            fs::read_to_string(&copy.file_path).unwrap(),
+            "# 메모\nA에서 수정"
+        );
+        assert!(git(&b, &["status", "--porcelain"]).unwrap().is_empty());
+
+        // 병합한 결과는 다시 보낼 수 있음
+        push(&b, &url).unwrap();
+        assert_eq!(pull(&a, &url).unwrap().conflicts, 0);
+        assert_eq!(read_index_at(&a).notes.len(), 2);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,457 @@
+// 노트북(그룹)으로 노트 정리
+//
+// 설정의 groupFolders가 켜져 있으면 노트 폴더 안에 있는 노트 파일을
+// 그룹 이름의 하위 폴더로 옮긴다. 노트 폴더 밖의 파일은 옮기지 않는다.
+
+use crate::settings::read_settings;
+use crate::{
+    available_path, get_notes_dir, lock_index, open_note_window, order_by_ids, read_index,
+    sanitize_file_name, write_index, NoteMetadata, NotesIndex,
+};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::{Emitter, Manager};
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct NoteGroup {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub color: Option<String>,
+}
+
+// 그룹 생성 커맨드
+#[tauri::command]
+pub fn create_group(
+    app: tauri::AppHandle,
+    name: String,
+    color: Option<String>,
+) -> Result<NoteGroup, String> {
+    let _guard = lock_index();
+    let mut index = read_index()?;
+    let name = validate_name(&index, &name, None)?;
+
+    let group = NoteGroup {
+        id: uuid::Uuid::new_v4().to_string(),
+        name,
+        color,
+    };
+    index.groups.push(group.clone());
+    write_index(&index)?;
+
+    let _ = app.emit("refresh-notes-list", ());
+    Ok(group)
+}
+
+// 그룹 이름 변경 커맨드
+#[tauri::command]
+pub fn rename_group(app: tauri::AppHandle, id: String, name: String) -> Result<(), String> {
+    // 파일 이동부터 인덱스 저장까지 잠금 유지 (동시 저장이 이전 경로로 덮어쓰지 않도록)
+    let _guard = lock_index();
+    let mut index = read_index()?;
+    let moved = rename_in_index(&mut index, &id, &name, group_folders_root()?.as_deref())?;
+
+    write_index(&index)?;
+    notify_moved(&app, &moved);
+    Ok(())
+}
+
+// 그룹 이름 변경, notes_dir가 있으면(그룹 폴더 사용) 노트 파일도 새 폴더로 이동
+// 파일을 옮긴 노트 id 목록 반환 (인덱스 저장은 호출자가 담당)
+fn rename_in_index(
+    index: &mut NotesIndex,
+    id: &str,
+    name: &str,
+    notes_dir: Option<&Path>,
+) -> Result<Vec<String>, String> {
+    let name = validate_name(index, name, Some(id))?;
+    // 이름을 바꾸기 전의 그룹 폴더 목록 (이전 폴더의 노트도 옮기도록)
+    let folders = notes_dir
+        .map(|dir| group_dirs(index, dir))
+        .unwrap_or_default();
+
+    let group = index
+        .groups
+        .iter_mut()
+        .find(|g| g.id == id)
+        .ok_or("Group not found")?;
+    let old_folder = sanitize_file_name(&group.name);
+    group.name = name.clone();
+
+    let mut moved = Vec::new();
+    if let Some(notes_dir) = notes_dir {
+        let target_dir = notes_dir.join(sanitize_file_name(&name));
+        for note in index.notes.iter_mut() {
+            if note.group_id.as_deref() == Some(id)
+                && relocate(note, notes_dir, &folders, &target_dir)?
+            {
+                moved.push(note.id.clone());
+            }
+        }
+        // 비어 있는 이전 폴더 정리 (다른 파일이 남아 있으면 실패하므로 무시)
+        let _ = fs::remove_dir(notes_dir.join(old_folder));
+    }
+    Ok(moved)
+}
+
+// 그룹 색상 변경 커맨드
+#[tauri::command]
+pub fn set_group_color(
+    app: tauri::AppHandle,
+    id: String,
+    color: Option<String>,
+) -> Result<(), String> {
+    let _guard = lock_index();
+    let mut index = read_index()?;
+    let group = index
+        .groups
+        .iter_mut()
+        .find(|g| g.id == id)
+        .ok_or("Group not found")?;
+    group.color = color;
+    write_index(&index)?;
+
+    let _ = app.emit("refresh-notes-list", ());
+    Ok(())
+}
+
+// 그룹 삭제 커맨드 (노트는 삭제하지 않고 그룹 없음으로 이동)
+#[tauri::command]
+pub fn delete_group(app: tauri::AppHandle, id: String) -> Result<(), String> {
+    let _guard = lock_index();
+    let mut index = read_index()?;
+    let pos = index
+        .groups
+        .iter()
+        .position(|g| g.id == id)
+        .ok_or("Group not found")?;
+
+    let note_ids: Vec<String> = index
+        .notes
+        .iter()
+        .filter(|n| n.group_id.as_deref() == Some(id.as_str()))
+        .map(|n| n.id.clone())
+        .collect();
+    let moved = assign_group(
+        &mut index,
+        &note_ids,
+        None,
+        group_folders_root()?.as_deref(),
+    )?;
+
+    let group = index.groups.remove(pos);
+    if read_settings()?.notes.group_folders {
+        let _ = fs::remove_dir(get_notes_dir()?.join(sanitize_file_name(&group.name)));
+    }
+
+    write_index(&index)?;
+    notify_moved(&app, &moved);
+    Ok(())
+}
+
+// 그룹 순서 변경 커맨드 (ids에 없는 그룹은 뒤에 기존 순서대로 유지)
+#[tauri::command]
+pub fn reorder_groups(app: tauri::AppHandle, ids: Vec<String>) -> Result<(), String> {
+    let _guard = lock_index();
+    let mut index = read_index()?;
+    index.groups = order_by_ids(std::mem::take(&mut index.groups), &ids, |g| &g.id);
+    write_index(&index)?;
+
+    let _ = app.emit("refresh-notes-list", ());
+    Ok(())
+}
+
+// 노트를 그룹으로 이동 커맨드 (group_id가 None이면 그룹 해제)
+#[tauri::command]
+pub fn move_notes_to_group(
+    app: tauri::AppHandle,
+    note_ids: Vec<String>,
+    group_id: Option<String>,
+) -> Result<(), String> {
+    let _guard = lock_index();
+    let mut index = read_index()?;
+    if let Some(group_id) = &group_id {
+        if !index.groups.iter().any(|g| &g.id == group_id) {
+            return Err("Group not found".to_string());
+        }
+    }
+
+    let moved = assign_group(
+        &mut index,
+        &note_ids,
+        group_id,
+        group_folders_root()?.as_deref(),
+    )?;
+    write_index(&index)?;
+    notify_moved(&app, &moved);
+    Ok(())
+}
+
+// 그룹에 속한 모든 노트 윈도우 표시/숨기기 커맨드
+#[tauri::command]
+pub async fn set_group_windows_visible(
+    app: tauri::AppHandle,
+    group_id: String,
+    visible: bool,
+) -> Result<(), String> {
+    let index = read_index()?;
+    let note_ids: Vec<String> = index
+        .notes
+        .iter()
+        .filter(|n| n.group_id.as_deref() == Some(group_id.as_str()))
+        // 보관된 노트의 윈도우는 다시 열지 않음
+        .filter(|n| !n.archived)
+        .map(|n| n.id.clone())
+        .collect();
+
+    for note_id in note_ids {
+        if visible {
+            open_note_window(app.clone(), note_id).await?;
+        } else if let Some(window) = app.get_webview_window(&format!("note_{}", note_id)) {
+            // 닫지 않고 숨겨서 편집 중인 내용 유지
+            window.hide().map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(())
+}
+
+// 그룹 이름 검사 (비어 있거나 다른 그룹과 폴더 이름이 겹치면 오류), except는 이름을 바꾸는 그룹 id
+fn validate_name(index: &NotesIndex, name: &str, except: Option<&str>) -> Result<String, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Group name cannot be empty".to_string());
+    }
+
+    // 그룹 폴더가 겹치지 않도록 폴더 이름 기준으로 대소문자 없이 비교
+    let folder = sanitize_file_name(name).to_lowercase();
+    let duplicate = index.groups.iter().any(|g| {
+        Some(g.id.as_str()) != except && sanitize_file_name(&g.name).to_lowercase() == folder
+    });
+    if duplicate {
+        return Err(format!("A group named \"{}\" already exists", name));
+    }
+    Ok(name.to_string())
+}
+
+// 그룹 폴더 사용 시 노트 폴더, 사용하지 않으면 None
+fn group_folders_root() -> Result<Option<PathBuf>, String> {
+    Ok(if read_settings()?.notes.group_folders {
+        Some(get_notes_dir()?)
+    } else {
+        None
+    })
+}
+
+// 현재 그룹들의 폴더 경로
+fn group_dirs(index: &NotesIndex, notes_dir: &Path) -> Vec<PathBuf> {
+    index
+        .groups
+        .iter()
+        .map(|g| notes_dir.join(sanitize_file_name(&g.name)))
+        .collect()
+}
+
+// 노트의 그룹 변경, notes_dir가 있으면(그룹 폴더 사용) 파일도 그룹 폴더로 이동
+// 파일을 옮긴 노트 id 목록 반환
+fn assign_group(
+    index: &mut NotesIndex,
+    note_ids: &[String],
+    group_id: Option<String>,
+    notes_dir: Option<&Path>,
+) -> Result<Vec<String>, String> {
+    let folder_target = match notes_dir {
+        Some(notes_dir) => {
+            let target_dir = match &group_id {
+                Some(id) => {
+                    let group = index
+                        .groups
+                        .iter()
+                        .find(|g| &g.id == id)
+                        .ok_or("Group not found")?;
+                    notes_dir.join(sanitize_file_name(&group.name))
+                }
+                None => notes_dir.to_path_buf(),
+            };
+            Some((notes_dir, group_dirs(index, notes_dir), target_dir))
+        }
+        None => None,
+    };
+
+    let mut moved = Vec::new();
+    for note in index.notes.iter_mut().filter(|n| note_ids.contains(&n.id)) {
+        note.group_id = group_id.clone();
+        if let Some((notes_dir, folders, target_dir)) = &folder_target {
+            if relocate(note, notes_dir, folders, target_dir)? {
+                moved.push(note.id.clone());
+            }
+        }
+    }
+    Ok(moved)
+}
+
+// 노트 폴더 또는 그룹 폴더(group_dirs)에 바로 들어 있는 노트 파일을 target_dir로 이동
+// 데일리 노트 폴더 등 그 밖의 하위 폴더에 있는 파일은 옮기지 않음
+fn relocate(
+    note: &mut NoteMetadata,
+    notes_dir: &Path,
+    group_dirs: &[PathBuf],
+    target_dir: &Path,
+) -> Result<bool, String> {
+    let current = PathBuf::from(&note.file_path);
+    let Some(parent) = current.parent() else {
+        return Ok(false);
+    };
+
+    let movable = parent == notes_dir || group_dirs.iter().any(|dir| dir == parent);
+    if !movable || parent == target_dir || !current.exists() {
+        return Ok(false);
+    }
+
+    let file_name = current
+        .file_name()
+        .ok_or("Invalid file path")?
+        .to_string_lossy()
+        .to_string();
+    fs::create_dir_all(target_dir).map_err(|e| e.to_string())?;
+    let target = available_path(target_dir, &file_name, None);
+    fs::rename(&current, &target).map_err(|e| e.to_string())?;
+
+    note.file_path = target.to_string_lossy().to_string();
+    Ok(true)
+}
+
+// 파일이 이동된 노트의 윈도우가 새 경로를 사용하도록 알림
+fn notify_moved(app: &tauri::AppHandle, moved: &[String]) {
+    for note_id in moved {
+        let _ = app.emit("note-file-changed", note_id);
+    }
+    let _ = app.emit("refresh-notes-list", ());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_notes_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("stickermd_groups_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn note_at(id: &str, path: &Path, group_id: Option<&str>) -> NoteMetadata {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, format!("# {}", id)).unwrap();
+        NoteMetadata {
+            id: id.to_string(),
+            file_path: path.to_string_lossy().to_string(),
+            group_id: group_id.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    fn group(id: &str, name: &str) -> NoteGroup {
+        NoteGroup {
+            id: id.to_string(),
+            name: name.to_string(),
+            color: None,
+        }
+    }
+
+    #[test]
+    fn test_relocate_only_moves_notes_in_group_folders() {
+        let notes_dir = temp_notes_dir();
+        let work = notes_dir.join("Work");
+        let home = notes_dir.join("Home");
+        let groups = vec![work.clone(), home.clone()];
+
+        let mut loose = note_at("loose", &notes_dir.join("loose.md"), None);
+        let mut grouped = note_at("grouped", &home.join("grouped.md"), None);
+        let mut daily = note_at("daily", &notes_dir.join("daily/2024-01-01.md"), None);
+
+        assert!(relocate(&mut loose, &notes_dir, &groups, &work).unwrap());
+        assert!(relocate(&mut grouped, &notes_dir, &groups, &work).unwrap());
+        assert!(!relocate(&mut daily, &notes_dir, &groups, &work).unwrap());
+
+        assert_eq!(Path::new(&loose.file_path), work.join("loose.md"));
+        assert_eq!(Path::new(&grouped.file_path), work.join("grouped.md"));
+        assert!(notes_dir.join("daily/2024-01-01.md").exists());
+
+        fs::remove_dir_all(&notes_dir).unwrap();
+    }
+
+    #[test]
+    fn test_rename_group_moves_files_and_rejects_duplicates() {
+        let notes_dir = temp_notes_dir();
+        let mut index = NotesIndex {
+            notes: vec![note_at("n1", &notes_dir.join("Work/n1.md"), Some("g1"))],
+            groups: vec![group("g1", "Work"), group("g2", "Home")],
+            ..Default::default()
+        };
+
+        // 다른 그룹과 같은 폴더 이름(대소문자 무시)은 거부
+        assert!(rename_in_index(&mut index, "g1", " home ", Some(&notes_dir)).is_err());
+        assert_eq!(index.groups[0].name, "Work");
+
+        let moved = rename_in_index(&mut index, "g1", "Projects", Some(&notes_dir)).unwrap();
+        assert_eq!(moved, vec!["n1".to_string()]);
+        assert_eq!(index.groups[0].name, "Projects");
+        assert_eq!(
+            Path::new(&index.notes[0].file_path),
+            notes_dir.join("Projects/n1.md")
+        );
+        // 비어 있는 이전 폴더는 정리
+        assert!(!notes_dir.join("Work").exists());
+
+        fs::remove_dir_all(&notes_dir).unwrap();
+    }
+
+    #[test]
+    fn test_move_notes_to_group_and_back() {
+        let notes_dir = temp_notes_dir();
+        let mut index = NotesIndex {
+            notes: vec![
+                note_at("n1", &notes_dir.join("n1.md"), None),
+                note_at("n2", &notes_dir.join("n2.md"), None),
+            ],
+            groups: vec![group("g1", "Work")],
+            ..Default::default()
+        };
+        let ids = vec!["n1".to_string()];
+
+        let moved = assign_group(&mut index, &ids, Some("g1".to_string()), Some(&notes_dir));
+        assert_eq!(moved.unwrap(), ids);
+        assert_eq!(index.notes[0].group_id.as_deref(), Some("g1"));
+        assert_eq!(
+            Path::new(&index.notes[0].file_path),
+            notes_dir.join("Work/n1.md")
+        );
+        assert_eq!(index.notes[1].group_id, None);
+
+        // 그룹 해제 시 노트 폴더로 되돌림
+        assign_group(&mut index, &ids, None, Some(&notes_dir)).unwrap();
+        assert_eq!(index.notes[0].group_id, None);
+        assert_eq!(
+            Path::new(&index.notes[0].file_path),
+            notes_dir.join("n1.md")
+        );
+
+        // 그룹 폴더를 쓰지 않으면 파일은 그대로
+        let moved = assign_group(&mut index, &ids, Some("g1".to_string()), None).unwrap();
+        assert!(moved.is_empty());
+        assert_eq!(
+            Path::new(&index.notes[0].file_path),
+            notes_dir.join("n1.md")
+        );
+
+        assert!(assign_group(
+            &mut index,
+            &ids,
+            Some("missing".to_string()),
+            Some(&notes_dir)
+        )
+        .is_err());
+
+        fs::remove_dir_all(&notes_dir).unwrap();
+    }
+}
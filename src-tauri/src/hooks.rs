@@ -0,0 +1,325 @@
+// 노트 이벤트 훅 (설정의 hooks)
+//
+// 노트 생성/저장/삭제, 리마인더 알림 시 사용자가 설정한 실행 파일을 실행한다.
+// 표준 입력으로 JSON({"event", "note", "content", "firedAt"})을 전달하고,
+// 제한 시간이 지나면 종료시킨다. 실행 결과는 저장소 루트의 hook_log.json에 최근 기록만 보관한다.
+// 암호화된 노트는 본문을 전달하지 않는다 (content: null).
+
+use crate::settings::{read_settings, HookSettings};
+use crate::{get_storage_dir, read_file, write_atomic, NoteMetadata};
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+const LOG_FILE: &str = "hook_log.json";
+// 보관할 실행 기록 수
+const MAX_LOG_ENTRIES: usize = 200;
+// 기록할 출력 최대 길이 (바이트)
+const MAX_OUTPUT: usize = 4096;
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+// 실행 기록 파일을 동시에 고쳐 쓰지 않도록 잠금
+static LOG_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum HookEvent {
+    NoteCreated,
+    #[default]
+    NoteSaved,
+    NoteDeleted,
+    ReminderFired,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HookPayload<'a> {
+    event: HookEvent,
+    note: &'a NoteMetadata,
+    content: Option<&'a str>,
+    fired_at: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct HookResult {
+    hook: String,
+    event: HookEvent,
+    note_id: String,
+    started_at: String,
+    duration_ms: u64,
+    // 정상 종료 시 종료 코드 (시간 초과, 실행 실패, 시그널 종료는 None)
+    exit_code: Option<i32>,
+    timed_out: bool,
+    error: Option<String>,
+    stdout: String,
+    stderr: String,
+}
+
+// 이벤트에 해당하는 훅을 백그라운드에서 순서대로 실행
+// content가 None이면 노트 파일에서 읽음 (암호화된 노트는 전달하지 않음)
+pub fn fire(event: HookEvent, note: &NoteMetadata, content: Option<String>) {
+    let hooks: Vec<HookSettings> = read_settings()
+        .map(|s| s.hooks)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|h| h.enabled && h.event == event && !h.command.trim().is_empty())
+        .collect();
+    if hooks.is_empty() {
+        return;
+    }
+
+    let note = note.clone();
+    std::thread::spawn(move || {
+        let content = if note.encrypted {
+            None
+        } else {
+            content.or_else(|| {
+                read_file(Path::new(&note.file_path))
+                    .ok()
+                    .map(|bytes| String::from_utf8_lossy(&bytes).to_string())
+            })
+        };
+        let payload = HookPayload {
+            event,
+            note: &note,
+            content: content.as_deref(),
+            fired_at: chrono::Utc::now().to_rfc3339(),
+        };
+        let input = serde_json::to_vec(&payload).unwrap_or_default();
+        let work_dir = get_storage_dir().ok();
+
+        for hook in &hooks {
+            let result = run_hook(hook, event, &note.id, &input, work_dir.as_deref());
+            if let Err(e) = append_log(result) {
+                println!("Failed to write hook log: {}", e);
+            }
+        }
+    });
+}
+
+// 훅 하나 실행 (제한 시간이 지나면 종료)
+fn run_hook(
+    hook: &HookSettings,
+    event: HookEvent,
+    note_id: &str,
+    input: &[u8],
+    work_dir: Option<&Path>,
+) -> HookResult {
+    let started_at = chrono::Utc::now().to_rfc3339();
+    let start = Instant::now();
+    let mut result = HookResult {
+        hook: hook.name.clone(),
+        event,
+        note_id: note_id.to_string(),
+        started_at,
+        duration_ms: 0,
+        exit_code: None,
+        timed_out: false,
+        error: None,
+        stdout: String::new(),
+        stderr: String::new(),
+    };
+
+    let mut command = Command::new(hook.command.trim());
+    command
+        .args(&hook.args)
+        .env("STICKERMD_EVENT", event_name(event))
+        .env("STICKERMD_NOTE_ID", note_id)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    if let Some(dir) = work_dir.filter(|d| d.is_dir()) {
+        command.current_dir(dir);
+    }
+
+    let mut child = match command.spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            result.error = Some(format!("Failed to start: {}", e));
+            return result;
+        }
+    };
+
+    // 출력이 많아도 막히지 않도록 입출력은 별도 스레드에서 처리
+    let stdin = child.stdin.take().map(|mut stdin| {
+        let input = input.to_vec();
+        std::thread::spawn(move || {
+            // 입력을 읽지 않고 끝나는 훅은 BrokenPipe가 나므로 무시
+            let _ = stdin.write_all(&input);
+        })
+    });
+    let stdout = capture(child.stdout.take());
+    let stderr = capture(child.stderr.take());
+
+    let timeout = Duration::from_secs(hook.timeout_seconds.max(1) as u64);
+    loop {
+        match child.try_wait() {
+            Ok(Some(status)) => {
+                result.exit_code = status.code();
+                break;
+            }
+            Ok(None) if start.elapsed() >= timeout => {
+                let _ = child.kill();
+                let _ = child.wait();
+                result.timed_out = true;
+                result.error = Some(format!("Timed out after {}s", timeout.as_secs()));
+                break;
+            }
+            Ok(None) => std::thread::sleep(POLL_INTERVAL),
+            Err(e) => {
+                let _ = child.kill();
+                result.error = Some(e.to_string());
+                break;
+            }
+        }
+    }
+
+    // 시간이 초과된 경우 훅이 만든 하위 프로세스가 출력을 계속 열어 둘 수 있으므로 기다리지 않음
+    if !result.timed_out {
+        if let Some(handle) = stdin {
+            let _ = handle.join();
+        }
+        let collect = |handle: Option<JoinHandle<Vec<u8>>>| {
+            let bytes = handle.and_then(|h| h.join().ok()).unwrap_or_default();
+            truncate_output(&String::from_utf8_lossy(&bytes))
+        };
+        result.stdout = collect(stdout);
+        result.stderr = collect(stderr);
+    }
+    result.duration_ms = start.elapsed().as_millis() as u64;
+    result
+}
+
+fn capture<R: Read + Send + 'static>(pipe: Option<R>) -> Option<JoinHandle<Vec<u8>>> {
+    pipe.map(|mut pipe| {
+        std::thread::spawn(move || {
+            let mut bytes = Vec::new();
+            let _ = pipe.read_to_end(&mut bytes);
+            bytes
+        })
+    })
+}
+
+fn event_name(event: HookEvent) -> String {
+    serde_json::to_value(event)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+fn truncate_output(output: &str) -> String {
+    let output = output.trim_end();
+    if output.len() <= MAX_OUTPUT {
+        return output.to_string();
+    }
+    let mut end = MAX_OUTPUT;
+    while !output.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}…", &output[..end])
+}
+
+fn get_log_path() -> Result<PathBuf, String> {
+    Ok(get_storage_dir()?.join(LOG_FILE))
+}
+
+fn read_log() -> Result<Vec<HookResult>, String> {
+    Ok(read_file(&get_log_path()?)
+        .ok()
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+        .unwrap_or_default())
+}
+
+fn append_log(result: HookResult) -> Result<(), String> {
+    let _guard = LOG_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut log = read_log()?;
+    log.push(result);
+    if log.len() > MAX_LOG_ENTRIES {
+        log.drain(..log.len() - MAX_LOG_ENTRIES);
+    }
+    let content = serde_json::to_string_pretty(&log).map_err(|e| e.to_string())?;
+    write_atomic(&get_log_path()?, content.as_bytes())
+}
+
+// 훅 실행 기록 조회 커맨드 (최신순)
+#[tauri::command]
+pub fn get_hook_log() -> Result<Vec<HookResult>, String> {
+    let _guard = LOG_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut log = read_log()?;
+    log.reverse();
+    Ok(log)
+}
+
+// 훅 실행 기록 삭제 커맨드
+#[tauri::command]
+pub fn clear_hook_log() -> Result<(), String> {
+    let _guard = LOG_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let path = get_log_path()?;
+    if path.exists() {
+        std::fs::remove_file(path).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    fn shell_hook(script: &str, timeout_seconds: u32) -> HookSettings {
+        HookSettings {
+            name: "test".to_string(),
+            event: HookEvent::NoteSaved,
+            command: "sh".to_string(),
+            args: vec!["-c".to_string(), script.to_string()],
+            enabled: true,
+            timeout_seconds,
+        }
+    }
+
+    #[test]
+    fn test_run_hook_passes_payload_on_stdin() {
+        let note = NoteMetadata {
+            id: "n1".to_string(),
+            title: "장보기".to_string(),
+            ..Default::default()
+        };
+        let payload = HookPayload {
+            event: HookEvent::NoteSaved,
+            note: &note,
+            content: Some("# 장보기"),
+            fired_at: "2024-01-01T00:00:00Z".to_string(),
+        };
+        let input = serde_json::to_vec(&payload).unwrap();
+
+        let hook = shell_hook("cat; echo \"$STICKERMD_EVENT\" >&2; exit 3", 10);
+        let result = run_hook(&hook, HookEvent::NoteSaved, "n1", &input, None);
+        assert_eq!(result.exit_code, Some(3));
+        assert!(!result.timed_out);
+        assert_eq!(result.stderr, "note_saved");
+
+        let echoed: serde_json::Value = serde_json::from_str(&result.stdout).unwrap();
+        assert_eq!(echoed["event"], "note_saved");
+        assert_eq!(echoed["note"]["id"], "n1");
+        assert_eq!(echoed["content"], "# 장보기");
+    }
+
+    #[test]
+    fn test_run_hook_times_out() {
+        let hook = shell_hook("sleep 10", 1);
+        let start = Instant::now();
+        let result = run_hook(&hook, HookEvent::NoteSaved, "n1", b"{}", None);
+        assert!(result.timed_out);
+        assert_eq!(result.exit_code, None);
+        assert!(start.elapsed() < Duration::from_secs(5));
+
+        let mut missing = shell_hook("", 1);
+        missing.command = "stickermd-hook-that-does-not-exist".to_string();
+        let result = run_hook(&missing, HookEvent::NoteSaved, "n1", b"{}", None);
+        assert!(result.error.unwrap().starts_with("Failed to start"));
+    }
+}
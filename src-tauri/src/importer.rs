@@ -0,0 +1,672 @@
+// 다른 스티커 메모 앱의 데이터를 StickerMD 노트로 가져오기
+//
+// 지원 형식:
+// - Windows Sticky Notes (plum.sqlite)
+// - Google Keep (Takeout의 Keep 폴더, JSON/HTML)
+// - Apple Notes (HTML/Markdown/텍스트로 내보낸 폴더)
+// - Simplenote (notes.json)
+
+use crate::{
+    content_hash, extract_title, get_notes_dir, lock_index, read_index, save_image, write_atomic,
+    write_index, NoteMetadata,
+};
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::Emitter;
+
+#[derive(Deserialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportSource {
+    StickyNotes,
+    GoogleKeep,
+    AppleNotes,
+    Simplenote,
+}
+
+impl ImportSource {
+    fn name(self) -> &'static str {
+        match self {
+            ImportSource::StickyNotes => "sticky_notes",
+            ImportSource::GoogleKeep => "google_keep",
+            ImportSource::AppleNotes => "apple_notes",
+            ImportSource::Simplenote => "simplenote",
+        }
+    }
+}
+
+#[derive(Serialize, Default)]
+pub struct ImportReport {
+    imported: Vec<String>,
+    skipped: usize,
+    errors: Vec<String>,
+}
+
+// 파싱된 외부 노트 (아직 저장되지 않은 상태)
+#[derive(Default)]
+struct ImportedNote {
+    title: Option<String>,
+    body: String,
+    created_at: Option<DateTime<Utc>>,
+    updated_at: Option<DateTime<Utc>>,
+    color: Option<String>,
+    pinned: bool,
+    // 상대 경로 이미지를 찾을 기준 디렉토리
+    base_dir: Option<PathBuf>,
+    // 작성/수정 시각을 파일 시스템에서 읽음 (폴더를 복사하면 바뀌므로 식별자에 쓰지 않음)
+    times_from_file: bool,
+}
+
+impl ImportedNote {
+    fn is_empty(&self) -> bool {
+        self.body.trim().is_empty() && self.title.as_deref().unwrap_or("").trim().is_empty()
+    }
+
+    // 원본 노트 식별자: 형식 + 제목/본문/작성 시각의 해시 (가져올 폴더가 달라도 같은 값)
+    // 파일 시스템에서 읽은 시각은 복사할 때 바뀌므로 제외
+    fn import_key(&self, source: ImportSource) -> String {
+        let created_at = self
+            .created_at
+            .filter(|_| !self.times_from_file)
+            .map(|t| t.to_rfc3339())
+            .unwrap_or_default();
+        let hash = content_hash(&format!(
+            "{}\0{}\0{}",
+            self.title.as_deref().unwrap_or(""),
+            self.body,
+            created_at
+        ));
+        format!("{}:{}", source.name(), hash)
+    }
+}
+
+// 가져오기 커맨드: 외부 데이터를 마크다운 노트로 변환하여 인덱스에 등록
+#[tauri::command]
+pub fn import_notes(
+    app: tauri::AppHandle,
+    source: ImportSource,
+    path: String,
+) -> Result<ImportReport, String> {
+    let path = PathBuf::from(&path);
+    if !path.exists() {
+        return Err(format!("Import source not found: {}", path.display()));
+    }
+
+    let notes = match source {
+        ImportSource::StickyNotes => parse_sticky_notes(&path)?,
+        ImportSource::GoogleKeep => parse_google_keep(&path)?,
+        ImportSource::AppleNotes => parse_apple_notes(&path)?,
+        ImportSource::Simplenote => parse_simplenote(&path)?,
+    };
+
+    let notes_dir = get_notes_dir()?;
+    let _guard = lock_index();
+    let mut index = read_index()?;
+    let mut report = ImportReport::default();
+    // 이미 가져온 노트는 다시 가져오지 않음
+    let mut imported_keys: HashSet<String> = index
+        .notes
+        .iter()
+        .filter_map(|n| n.import_key.clone())
+        .collect();
+
+    for note in notes {
+        if note.is_empty() {
+            report.skipped += 1;
+            continue;
+        }
+        let key = note.import_key(source);
+        if !imported_keys.insert(key.clone()) {
+            report.skipped += 1;
+            continue;
+        }
+
+        match write_imported_note(note, &notes_dir) {
+            Ok(mut metadata) => {
+                metadata.import_key = Some(key);
+                report.imported.push(metadata.id.clone());
+                index.notes.push(metadata);
+            }
+            Err(e) => report.errors.push(e),
+        }
+    }
+
+    write_index(&index)?;
+
+    // 목록 갱신 이벤트 발행
+    let _ = app.emit("refresh-notes-list", ());
+
+    Ok(report)
+}
+
+// 파싱된 노트를 마크다운 파일로 저장하고 메타데이터 생성
+fn write_imported_note(note: ImportedNote, notes_dir: &Path) -> Result<NoteMetadata, String> {
+    let id = uuid::Uuid::new_v4().to_string();
+
+    let body = match &note.base_dir {
+        Some(dir) => embed_images(&note.body, dir),
+        None => note.body.clone(),
+    };
+
+    let content = match note.title.as_deref().map(str::trim) {
+        Some(title) if !title.is_empty() => format!("# {}\n\n{}", title, body.trim_start()),
+        _ => body,
+    };
+
+    let file_path = notes_dir.join(format!("{}.md", id));
+    write_atomic(&file_path, content.as_bytes())?;
+
+    let now = Utc::now();
+    let created_at = note.created_at.unwrap_or(now);
+    let updated_at = note.updated_at.unwrap_or(created_at);
+
+    Ok(NoteMetadata {
+        id,
+        title: extract_title(&content),
+        file_path: file_path.to_string_lossy().to_string(),
+        created_at: created_at.to_rfc3339(),
+        updated_at: updated_at.to_rfc3339(),
+        width: Some(400.0),
+        height: Some(400.0),
+        color: note.color,
+        pinned: note.pinned,
+        ..Default::default()
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Windows Sticky Notes (plum.sqlite)
+// ---------------------------------------------------------------------------
+
+fn parse_sticky_notes(path: &Path) -> Result<Vec<ImportedNote>, String> {
+    use rusqlite::{Connection, OpenFlags};
+
+    let conn = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+        .map_err(|e| e.to_string())?;
+
+    let mut stmt = conn
+        .prepare("SELECT Text, Theme, CreatedAt, UpdatedAt FROM Note WHERE DeletedAt IS NULL")
+        .map_err(|e| e.to_string())?;
+
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, Option<String>>(0)?,
+                row.get::<_, Option<String>>(1)?,
+                row.get::<_, Option<i64>>(2)?,
+                row.get::<_, Option<i64>>(3)?,
+            ))
+        })
+        .map_err(|e| e.to_string())?;
+
+    let mut notes = Vec::new();
+    for row in rows {
+        let (text, theme, created, updated) = row.map_err(|e| e.to_string())?;
+        notes.push(ImportedNote {
+            body: sticky_text_to_markdown(text.as_deref().unwrap_or("")),
+            created_at: created.and_then(dotnet_ticks_to_utc),
+            updated_at: updated.and_then(dotnet_ticks_to_utc),
+            color: theme.as_deref().and_then(palette_color),
+            ..Default::default()
+        });
+    }
+
+    Ok(notes)
+}
+
+// Sticky Notes는 각 문단 앞에 "\id=<guid> " 접두어를 붙여 저장함
+fn sticky_text_to_markdown(text: &str) -> String {
+    text.lines()
+        .map(|line| {
+            if let Some(rest) = line.strip_prefix("\\id=") {
+                rest.split_once(' ').map(|(_, t)| t).unwrap_or("")
+            } else {
+                line
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// .NET 틱 (0001-01-01부터 100ns 단위) -> UTC
+fn dotnet_ticks_to_utc(ticks: i64) -> Option<DateTime<Utc>> {
+    const TICKS_PER_SECOND: i64 = 10_000_000;
+    const UNIX_EPOCH_SECONDS: i64 = 62_135_596_800;
+
+    let secs = ticks / TICKS_PER_SECOND - UNIX_EPOCH_SECONDS;
+    let nanos = (ticks % TICKS_PER_SECOND) * 100;
+    Utc.timestamp_opt(secs, nanos as u32).single()
+}
+
+// ---------------------------------------------------------------------------
+// Google Keep (Takeout)
+// ---------------------------------------------------------------------------
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+struct KeepNote {
+    title: String,
+    text_content: String,
+    list_content: Vec<KeepListItem>,
+    color: String,
+    is_pinned: bool,
+    is_trashed: bool,
+    created_timestamp_usec: Option<i64>,
+    user_edited_timestamp_usec: Option<i64>,
+    attachments: Vec<KeepAttachment>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+struct KeepListItem {
+    text: String,
+    is_checked: bool,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+struct KeepAttachment {
+    file_path: String,
+}
+
+fn parse_google_keep(path: &Path) -> Result<Vec<ImportedNote>, String> {
+    let files: Vec<PathBuf> = if path.is_dir() {
+        let mut files: Vec<PathBuf> = fs::read_dir(path)
+            .map_err(|e| e.to_string())?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .collect();
+        files.sort();
+        files
+    } else {
+        vec![path.to_path_buf()]
+    };
+
+    let mut notes = Vec::new();
+    for file in &files {
+        let base_dir = file.parent().map(Path::to_path_buf);
+        match extension_of(file).as_str() {
+            "json" => {
+                let content = fs::read_to_string(file).map_err(|e| e.to_string())?;
+                // Takeout 폴더에는 노트 외의 JSON도 섞여 있을 수 있으므로 파싱 실패는 건너뜀
+                if let Ok(keep) = serde_json::from_str::<KeepNote>(&content) {
+                    if let Some(mut note) = keep_note_to_imported(keep) {
+                        note.base_dir = base_dir;
+                        notes.push(note);
+                    }
+                }
+            }
+            // JSON 사본이 없는 오래된 Takeout은 HTML만 포함
+            "html" if !file.with_extension("json").exists() => {
+                let html = fs::read_to_string(file).map_err(|e| e.to_string())?;
+                notes.push(ImportedNote {
+                    body: html2md::parse_html(&html),
+                    updated_at: file_modified_at(file),
+                    base_dir,
+                    ..Default::default()
+                });
+            }
+            _ => {}
+        }
+    }
+
+    Ok(notes)
+}
+
+fn keep_note_to_imported(keep: KeepNote) -> Option<ImportedNote> {
+    if keep.is_trashed {
+        return None;
+    }
+
+    let mut body = keep.text_content;
+    if !keep.list_content.is_empty() {
+        let items: Vec<String> = keep
+            .list_content
+            .iter()
+            .map(|item| {
+                format!(
+                    "- [{}] {}",
+                    if item.is_checked { "x" } else { " " },
+                    item.text
+                )
+            })
+            .collect();
+        if !body.is_empty() {
+            body.push_str("\n\n");
+        }
+        body.push_str(&items.join("\n"));
+    }
+
+    // 첨부 이미지는 본문 끝에 추가 (이후 embed_images에서 복사됨)
+    for attachment in &keep.attachments {
+        if !attachment.file_path.is_empty() {
+            body.push_str(&format!("\n\n![image]({})", attachment.file_path));
+        }
+    }
+
+    let usec_to_utc = |usec: i64| Utc.timestamp_micros(usec).single();
+
+    Some(ImportedNote {
+        title: Some(keep.title).filter(|t| !t.trim().is_empty()),
+        body,
+        created_at: keep.created_timestamp_usec.and_then(usec_to_utc),
+        updated_at: keep.user_edited_timestamp_usec.and_then(usec_to_utc),
+        color: palette_color(&keep.color),
+        pinned: keep.is_pinned,
+        base_dir: None,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Apple Notes (내보내기 폴더)
+// ---------------------------------------------------------------------------
+
+fn parse_apple_notes(path: &Path) -> Result<Vec<ImportedNote>, String> {
+    let mut files = Vec::new();
+    collect_files(path, &mut files)?;
+    files.sort();
+
+    let mut notes = Vec::new();
+    for file in &files {
+        let body = match extension_of(file).as_str() {
+            "html" | "htm" => {
+                html2md::parse_html(&fs::read_to_string(file).map_err(|e| e.to_string())?)
+            }
+            "md" | "markdown" | "txt" => {
+                String::from_utf8_lossy(&fs::read(file).map_err(|e| e.to_string())?).to_string()
+            }
+            _ => continue,
+        };
+
+        let metadata = fs::metadata(file).ok();
+        notes.push(ImportedNote {
+            body,
+            created_at: metadata
+                .as_ref()
+                .and_then(|m| m.created().ok())
+                .map(DateTime::<Utc>::from),
+            updated_at: file_modified_at(file),
+            base_dir: file.parent().map(Path::to_path_buf),
+            times_from_file: true,
+            ..Default::default()
+        });
+    }
+
+    Ok(notes)
+}
+
+fn collect_files(path: &Path, files: &mut Vec<PathBuf>) -> Result<(), String> {
+    if path.is_file() {
+        files.push(path.to_path_buf());
+        return Ok(());
+    }
+
+    for entry in fs::read_dir(path).map_err(|e| e.to_string())? {
+        let entry_path = entry.map_err(|e| e.to_string())?.path();
+        if entry_path.is_dir() {
+            collect_files(&entry_path, files)?;
+        } else {
+            files.push(entry_path);
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Simplenote (notes.json)
+// ---------------------------------------------------------------------------
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SimplenoteExport {
+    #[serde(default)]
+    active_notes: Vec<SimplenoteNote>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SimplenoteNote {
+    content: String,
+    creation_date: Option<String>,
+    last_modified: Option<String>,
+    #[serde(default)]
+    pinned: bool,
+}
+
+fn parse_simplenote(path: &Path) -> Result<Vec<ImportedNote>, String> {
+    let content = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    parse_simplenote_json(&content)
+}
+
+fn parse_simplenote_json(content: &str) -> Result<Vec<ImportedNote>, String> {
+    let export: SimplenoteExport = serde_json::from_str(content).map_err(|e| e.to_string())?;
+
+    let parse_date = |s: &Option<String>| {
+        s.as_deref()
+            .and_then(|d| DateTime::parse_from_rfc3339(d).ok())
+            .map(|d| d.with_timezone(&Utc))
+    };
+
+    Ok(export
+        .active_notes
+        .into_iter()
+        .map(|note| ImportedNote {
+            created_at: parse_date(&note.creation_date),
+            updated_at: parse_date(&note.last_modified),
+            body: note.content,
+            pinned: note.pinned,
+            ..Default::default()
+        })
+        .collect())
+}
+
+// ---------------------------------------------------------------------------
+// 공용 헬퍼
+// ---------------------------------------------------------------------------
+
+// 외부 앱의 색상 이름을 StickerMD 팔레트로 매핑
+fn palette_color(name: &str) -> Option<String> {
+    let color = match name.to_ascii_uppercase().as_str() {
+        "YELLOW" | "ORANGE" | "BROWN" => "#FFF7D1",
+        "BLUE" | "TEAL" | "CERULEAN" | "DARK_BLUE" => "#E0F7FA",
+        "GREEN" => "#E8F5E9",
+        "PINK" | "RED" => "#FCE4EC",
+        "PURPLE" => "#F3E5F5",
+        _ => return None,
+    };
+    Some(color.to_string())
+}
+
+fn extension_of(path: &Path) -> String {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase())
+        .unwrap_or_default()
+}
+
+fn file_modified_at(path: &Path) -> Option<DateTime<Utc>> {
+    fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .map(DateTime::<Utc>::from)
+}
+
+// 마크다운 이미지(![alt](src))의 로컬/Data URI 이미지를 save_image로 복사하고 경로 교체
+fn embed_images(markdown: &str, base_dir: &Path) -> String {
+    use base64::Engine;
+
+    rewrite_image_links(markdown, |src| {
+        if src.starts_with("http://") || src.starts_with("https://") {
+            return None;
+        }
+
+        if let Some(data) = src.strip_prefix("data:") {
+            let (meta, payload) = data.split_once(',')?;
+            let ext = meta
+                .strip_prefix("image/")?
+                .split(';')
+                .next()?
+                .replace("svg+xml", "svg");
+            let bytes = base64::engine::general_purpose::STANDARD
+                .decode(payload)
+                .ok()?;
+            return save_image(format!("imported.{}", ext), bytes).ok();
+        }
+
+        let decoded = percent_encoding::percent_decode_str(src)
+            .decode_utf8_lossy()
+            .to_string();
+        let image_path = base_dir.join(decoded);
+        let bytes = fs::read(&image_path).ok()?;
+        let name = image_path.file_name()?.to_string_lossy().to_string();
+        save_image(name, bytes).ok()
+    })
+}
+
+// 마크다운 이미지 링크의 src를 치환 (replace가 None을 반환하면 원본 유지)
+pub(crate) fn rewrite_image_links<F>(markdown: &str, mut replace: F) -> String
+where
+    F: FnMut(&str) -> Option<String>,
+{
+    let mut result = String::with_capacity(markdown.len());
+    let mut rest = markdown;
+
+    while let Some(start) = rest.find("![") {
+        let Some(mid) = rest[start..].find("](").map(|i| start + i + 2) else {
+            break;
+        };
+        let Some(end) = rest[mid..].find(')').map(|i| mid + i) else {
+            break;
+        };
+
+        // src 뒤의 "title" 부분은 그대로 유지
+        let target = &rest[mid..end];
+        let (src, suffix) = match target.find(char::is_whitespace) {
+            Some(i) => target.split_at(i),
+            None => (target, ""),
+        };
+
+        result.push_str(&rest[..mid]);
+        match replace(src) {
+            Some(new_src) => result.push_str(&new_src),
+            None => result.push_str(src),
+        }
+        result.push_str(suffix);
+        result.push(')');
+
+        rest = &rest[end + 1..];
+    }
+
+    result.push_str(rest);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keep_checklist_and_color() {
+        let json = r##"{
+            "title": "장보기",
+            "textContent": "",
+            "listContent": [
+                {"text": "우유", "isChecked": true},
+                {"text": "계란", "isChecked": false}
+            ],
+            "color": "GREEN",
+            "isPinned": true,
+            "isTrashed": false,
+            "createdTimestampUsec": 1700000000000000,
+            "userEditedTimestampUsec": 1700000100000000
+        }"##;
+
+        let keep: KeepNote = serde_json::from_str(json).unwrap();
+        let note = keep_note_to_imported(keep).unwrap();
+
+        assert_eq!(note.title.as_deref(), Some("장보기"));
+        assert_eq!(note.body, "- [x] 우유\n- [ ] 계란");
+        assert_eq!(note.color.as_deref(), Some("#E8F5E9"));
+        assert!(note.pinned);
+        assert_eq!(note.created_at.unwrap().timestamp(), 1_700_000_000);
+        assert_eq!(note.updated_at.unwrap().timestamp(), 1_700_000_100);
+    }
+
+    #[test]
+    fn test_sticky_notes_text_and_ticks() {
+        let text = "\\id=0a1b2c first line\n\\id=3d4e5f second line";
+        assert_eq!(sticky_text_to_markdown(text), "first line\nsecond line");
+
+        // 2020-01-01T00:00:00Z
+        let ticks = 637_134_336_000_000_000;
+        assert_eq!(
+            dotnet_ticks_to_utc(ticks).unwrap().to_rfc3339(),
+            "2020-01-01T00:00:00+00:00"
+        );
+    }
+
+    #[test]
+    fn test_simplenote_and_image_rewrite() {
+        let json = r#"{"activeNotes": [{
+            "id": "abc",
+            "content": "Title\nbody",
+            "creationDate": "2021-05-01T10:00:00.000Z",
+            "lastModified": "2021-05-02T10:00:00.000Z",
+            "pinned": true
+        }], "trashedNotes": []}"#;
+
+        let notes = parse_simplenote_json(json).unwrap();
+        assert_eq!(notes.len(), 1);
+        assert!(notes[0].pinned);
+        assert_eq!(notes[0].body, "Title\nbody");
+
+        let markdown = "![a](img.png \"t\") and ![b](https://x/y.png)";
+        let rewritten = rewrite_image_links(markdown, |src| {
+            (!src.starts_with("http")).then(|| format!("/saved/{}", src))
+        });
+        assert_eq!(
+            rewritten,
+            "![a](/saved/img.png \"t\") and ![b](https://x/y.png)"
+        );
+    }
+
+    #[test]
+    fn test_import_key_ignores_location_but_not_content() {
+        let note = ImportedNote {
+            title: Some("Title".to_string()),
+            body: "body".to_string(),
+            base_dir: Some(PathBuf::from("/a")),
+            ..Default::default()
+        };
+        let moved = ImportedNote {
+            title: Some("Title".to_string()),
+            body: "body".to_string(),
+            base_dir: Some(PathBuf::from("/b")),
+            ..Default::default()
+        };
+        let edited = ImportedNote {
+            title: Some("Title".to_string()),
+            body: "body 2".to_string(),
+            ..Default::default()
+        };
+
+        let key = note.import_key(ImportSource::Simplenote);
+        assert!(key.starts_with("simplenote:"));
+        assert_eq!(key, moved.import_key(ImportSource::Simplenote));
+        assert_ne!(key, edited.import_key(ImportSource::Simplenote));
+        assert_ne!(key, note.import_key(ImportSource::GoogleKeep));
+
+        // 파일 시스템 시각은 복사한 폴더마다 다르므로 식별자에 영향 없음
+        let exported = |created: i64| ImportedNote {
+            body: "body".to_string(),
+            created_at: Utc.timestamp_opt(created, 0).single(),
+            times_from_file: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            exported(1_700_000_000).import_key(ImportSource::AppleNotes),
+            exported(1_800_000_000).import_key(ImportSource::AppleNotes)
+        );
+    }
+}
@@ -5,7 +5,30 @@ use tauri::menu::ContextMenu;
 use tauri::Emitter; // Emitter 트레이트 추가
 use tauri::Manager; // ContextMenu 트레이트 추가
 
-#[derive(Serialize, Deserialize, Clone)]
+mod api;
+mod arrange;
+mod auto_backup;
+mod backup;
+mod daily;
+mod deep_link;
+mod encryption;
+mod export;
+mod git;
+mod groups;
+mod hooks;
+mod importer;
+mod links;
+mod placement;
+mod reminders;
+mod settings;
+mod stats;
+mod sync;
+mod tasks;
+mod templates;
+mod vault;
+mod webdav;
+
+#[derive(Serialize, Deserialize, Clone, Default)]
 struct NoteMetadata {
     id: String,
     title: String,
@@ -14,25 +37,101 @@ struct NoteMetadata {
     updated_at: String,
     width: Option<f64>,
     height: Option<f64>,
+    // 윈도우 위치 (가상 데스크톱의 물리 좌표), 없으면 계단식으로 배치
+    #[serde(default)]
+    x: Option<i32>,
+    #[serde(default)]
+    y: Option<i32>,
+    // 배경색 (예: "#FFF7D1"), 없으면 기본 노란색
+    #[serde(default)]
+    color: Option<String>,
+    #[serde(default)]
+    pinned: bool,
+    // 알림 예정 시각 (RFC 3339)
+    #[serde(default)]
+    reminder_at: Option<String>,
+    #[serde(default)]
+    reminder_recurrence: Option<reminders::Recurrence>,
+    // 반복 리마인더의 처음 시각 (월/연 반복은 말일 조정이 쌓이지 않도록 이 시각 기준으로 계산)
+    #[serde(default)]
+    reminder_anchor: Option<String>,
+    // 다시 알림(snooze) 시각, reminder_at보다 우선
+    #[serde(default)]
+    snoozed_until: Option<String>,
+    // 알림이 표시되었고 사용자 확인(dismiss/snooze)을 기다리는 상태
+    #[serde(default)]
+    reminder_fired: bool,
+    // 본문의 위키 링크 대상 ([[...]] 안의 제목 또는 id)
+    #[serde(default)]
+    links: Vec<String>,
+    // 소속 노트북(그룹) id
+    #[serde(default)]
+    group_id: Option<String>,
+    #[serde(default)]
+    word_count: usize,
+    #[serde(default)]
+    char_count: usize,
+    // 본문의 SHA-256 (외부에서 파일이 수정되었는지 확인용)
+    #[serde(default)]
+    content_hash: Option<String>,
+    // 보관된 노트는 기본 목록에서 숨김 (검색에는 포함)
+    #[serde(default)]
+    archived: bool,
+    #[serde(default)]
+    archived_at: Option<String>,
+    // 패스프레이즈로 암호화된 노트 (본문 유래 정보는 인덱스에 저장하지 않음)
+    #[serde(default)]
+    encrypted: bool,
+    // 다른 앱에서 가져온 노트의 원본 식별자 (같은 노트를 다시 가져오지 않도록 확인)
+    #[serde(default)]
+    import_key: Option<String>,
 }
 
+// 메인 윈도우 크기 (논리 크기)와 위치 (물리 좌표)
 #[derive(Serialize, Deserialize, Clone)]
 struct WindowSize {
     width: f64,
     height: f64,
+    #[serde(default)]
+    x: Option<i32>,
+    #[serde(default)]
+    y: Option<i32>,
 }
 
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone, Default)]
 #[serde(rename_all = "camelCase")]
 struct NotesIndex {
     notes: Vec<NoteMetadata>,
     main_window: Option<WindowSize>,
+    // 노트북(그룹) 목록, 순서가 곧 표시 순서
+    #[serde(default)]
+    groups: Vec<groups::NoteGroup>,
+}
+
+// 메모 목록 정렬 기준 (고정된 메모는 항상 맨 위)
+#[derive(Deserialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+enum NoteSort {
+    // 최근 수정 순
+    #[default]
+    Updated,
+    // 최근 생성 순
+    Created,
+    // 제목 가나다/알파벳 순
+    Title,
+    // 사용자가 드래그로 정한 순서 (인덱스 저장 순서)
+    Manual,
+}
+
+// 저장소 루트 경로 가져오기 (~/Documents/StickerMD)
+fn get_storage_dir() -> Result<PathBuf, String> {
+    let home = dirs::home_dir().ok_or("홈 디렉토리를 찾을 수 없습니다")?;
+    Ok(home.join("Documents").join("StickerMD"))
 }
 
 // 메모 디렉토리 경로 가져오기
 fn get_notes_dir() -> Result<PathBuf, String> {
-    let home = dirs::home_dir().ok_or("홈 디렉토리를 찾을 수 없습니다")?;
-    let notes_dir = home.join("Documents").join("StickerMD").join("notes");
+    let notes_dir = get_storage_dir()?.join("notes");
 
     // 디렉토리가 없으면 생성
     if !notes_dir.exists() {
@@ -44,8 +143,7 @@ fn get_notes_dir() -> Result<PathBuf, String> {
 
 // index.json 경로 가져오기
 fn get_index_path() -> Result<PathBuf, String> {
-    let home = dirs::home_dir().ok_or("홈 디렉토리를 찾을 수 없습니다")?;
-    let sticker_dir = home.join("Documents").join("StickerMD");
+    let sticker_dir = get_storage_dir()?;
 
     if !sticker_dir.exists() {
         fs::create_dir_all(&sticker_dir).map_err(|e| e.to_string())?;
@@ -60,13 +158,11 @@ fn read_index() -> Result<NotesIndex, String> {
 
     if !index_path.exists() {
         // index.json이 없으면 빈 인덱스 반환
-        return Ok(NotesIndex {
-            notes: vec![],
-            main_window: None,
-        });
+        return Ok(NotesIndex::default());
     }
 
-    let content = fs::read_to_string(&index_path).map_err(|e| e.to_string())?;
+    // 라이브러리가 잠겨 있으면 오류 (빈 인덱스로 덮어쓰지 않도록)
+    let content = String::from_utf8_lossy(&read_file(&index_path)?).to_string();
 
     // 파싱 실패 시 빈 인덱스 반환 (오래된 데이터 형식 처리)
     match serde_json::from_str(&content) {
@@ -79,10 +175,7 @@ fn read_index() -> Result<NotesIndex, String> {
             // 오래된 파일을 백업하고 새로 시작
             let backup_path = index_path.with_extension("json.backup");
             let _ = fs::rename(&index_path, backup_path);
-            Ok(NotesIndex {
-                notes: vec![],
-                main_window: None,
-            })
+            Ok(NotesIndex::default())
         }
     }
 }
@@ -91,8 +184,15 @@ fn read_index() -> Result<NotesIndex, String> {
 fn write_index(index: &NotesIndex) -> Result<(), String> {
     let index_path = get_index_path()?;
     let content = serde_json::to_string_pretty(index).map_err(|e| e.to_string())?;
-    fs::write(&index_path, content).map_err(|e| e.to_string())?;
-    Ok(())
+    write_atomic(&index_path, content.as_bytes())
+}
+
+// index.json 읽기-수정-쓰기를 직렬화하는 잠금 (동기화, 다시 알림, API 스레드와 커맨드가 서로 덮어쓰지 않도록)
+static INDEX_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+// 인덱스 잠금 (잠금을 잡은 스레드가 패닉해도 계속 사용). 잡은 채로 잠그는 함수를 다시 부르지 않음
+fn lock_index() -> std::sync::MutexGuard<'static, ()> {
+    INDEX_LOCK.lock().unwrap_or_else(|e| e.into_inner())
 }
 
 // 메모 제목 추출 (마크다운 첫 줄)
@@ -113,6 +213,190 @@ fn extract_title(content: &str) -> String {
     }
 }
 
+// 본문 SHA-256 해시 (16진수 문자열)
+fn content_hash(content: &str) -> String {
+    use sha2::{Digest, Sha256};
+    format!("{:x}", Sha256::digest(content.as_bytes()))
+}
+
+// 본문에서 계산되는 메타데이터(링크, 단어/글자 수, 해시) 갱신
+fn refresh_content_stats(note: &mut NoteMetadata, content: &str) {
+    note.links = links::parse_wiki_links(content);
+    note.word_count = content.split_whitespace().count();
+    note.char_count = content.chars().count();
+    note.content_hash = Some(content_hash(content));
+}
+
+// 노트 내용이 바뀌었을 때 제목/통계/수정 시각 갱신
+// 제목이 바뀌면 다른 노트의 링크도 바꾸고, 내용이 바뀐 노트의 id 목록 반환 (인덱스 저장은 호출자가 담당)
+fn sync_note_content(
+    index: &mut NotesIndex,
+    id: &str,
+    content: &str,
+    updated_at: String,
+) -> Result<Vec<String>, String> {
+    let note = index
+        .notes
+        .iter_mut()
+        .find(|n| n.id == id)
+        .ok_or("Note not found")?;
+
+    let old_title = std::mem::replace(&mut note.title, extract_title(content));
+    let new_title = note.title.clone();
+    refresh_content_stats(note, content);
+    note.updated_at = updated_at;
+
+    Ok(if old_title != new_title {
+        links::rewrite_links_for_rename(index, id, &old_title, &new_title)
+    } else {
+        vec![]
+    })
+}
+
+// 본문의 #태그 추출 (코드 블록 제외, 중복 제거, 소문자 정규화)
+fn extract_tags(content: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    let mut in_code_block = false;
+
+    for line in content.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+
+        let mut prev = ' ';
+        for (i, c) in line.char_indices() {
+            if c == '#' && prev.is_whitespace() {
+                let tag: String = line[i + 1..]
+                    .chars()
+                    .take_while(|c| c.is_alphanumeric() || *c == '_' || *c == '-' || *c == '/')
+                    .collect();
+                let tag = tag.to_lowercase();
+                // 숫자만으로 된 것(#1 등)은 태그로 보지 않음
+                if !tag.is_empty()
+                    && !tag.chars().all(|c| c.is_ascii_digit())
+                    && !tags.contains(&tag)
+                {
+                    tags.push(tag);
+                }
+            }
+            prev = c;
+        }
+    }
+
+    tags
+}
+
+// 파일 읽기 (라이브러리 암호화가 켜져 있으면 복호화)
+fn read_file(path: &std::path::Path) -> Result<Vec<u8>, String> {
+    vault::decode(fs::read(path).map_err(|e| e.to_string())?)
+}
+
+// 임시 파일에 쓴 뒤 교체하여 쓰기 도중 파일이 손상되지 않도록 저장
+// 저장소 루트 안의 파일은 라이브러리 암호화가 켜져 있으면 암호화하여 저장
+fn write_atomic(path: &std::path::Path, content: &[u8]) -> Result<(), String> {
+    write_atomic_raw(path, &vault::encode(path, content)?)
+}
+
+fn write_atomic_raw(path: &std::path::Path, content: &[u8]) -> Result<(), String> {
+    let file_name = path
+        .file_name()
+        .ok_or("Invalid file path")?
+        .to_string_lossy()
+        .to_string();
+    let tmp_path = path.with_file_name(format!(".{}.tmp", file_name));
+
+    fs::write(&tmp_path, content).map_err(|e| e.to_string())?;
+    fs::rename(&tmp_path, path).map_err(|e| {
+        let _ = fs::remove_file(&tmp_path);
+        e.to_string()
+    })
+}
+
+// 중복되지 않는 이름 생성 ("a.md" -> "a_1.md" -> "a_2.md")
+fn unique_name(used: &mut std::collections::HashSet<String>, name: &str) -> String {
+    let (stem, ext) = match name.rsplit_once('.') {
+        Some((stem, ext)) if !stem.is_empty() => (stem, format!(".{}", ext)),
+        _ => (name, String::new()),
+    };
+
+    let mut candidate = name.to_string();
+    let mut n = 1;
+    while !used.insert(candidate.clone()) {
+        candidate = format!("{}_{}{}", stem, n, ext);
+        n += 1;
+    }
+    candidate
+}
+
+// dir 안에서 사용 가능한 파일 경로 (내용이 같은 기존 파일은 그대로 재사용)
+fn available_path(dir: &std::path::Path, name: &str, content: Option<&[u8]>) -> PathBuf {
+    let mut used = std::collections::HashSet::new();
+    loop {
+        let candidate = dir.join(unique_name(&mut used, name));
+        if !candidate.exists() {
+            return candidate;
+        }
+        if let Some(bytes) = content {
+            if read_file(&candidate).map(|b| b == bytes).unwrap_or(false) {
+                return candidate;
+            }
+        }
+    }
+}
+
+// 제목으로 파일 이름 만들기 (한글 등 유니코드 문자 유지, 공백/기호는 '-'로)
+fn slugify_title(title: &str) -> String {
+    let mut slug = String::new();
+    for c in title.chars().flat_map(char::to_lowercase) {
+        if c.is_alphanumeric() {
+            slug.push(c);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+        if slug.chars().count() >= 60 {
+            break;
+        }
+    }
+    let slug = slug.trim_end_matches('-');
+
+    // Windows 예약 이름은 파일 이름으로 쓸 수 없음
+    const RESERVED: &[&str] = &[
+        "con", "prn", "aux", "nul", "com1", "com2", "com3", "com4", "com5", "com6", "com7", "com8",
+        "com9", "lpt1", "lpt2", "lpt3", "lpt4", "lpt5", "lpt6", "lpt7", "lpt8", "lpt9",
+    ];
+    if slug.is_empty() || slug == "untitled-note" {
+        "untitled".to_string()
+    } else if RESERVED.contains(&slug) {
+        format!("{}-note", slug)
+    } else {
+        slug.to_string()
+    }
+}
+
+// 파일/폴더 이름에 쓸 수 없는 문자 제거 (한글 등 유니코드는 유지)
+fn sanitize_file_name(name: &str) -> String {
+    let cleaned: String = name
+        .chars()
+        .map(|c| match c {
+            '<' | '>' | ':' | '"' | '/' | '\\' | '|' | '?' | '*' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect();
+    // Windows에서는 끝의 공백/마침표가 허용되지 않음
+    let cleaned = cleaned.trim().trim_end_matches('.').trim();
+
+    if cleaned.is_empty() {
+        "Untitled".to_string()
+    } else {
+        cleaned.to_string()
+    }
+}
+
 #[tauri::command]
 fn greet(name: &str) -> String {
     format!("Hello, {}! You've been greeted from Rust!", name)
@@ -120,8 +404,76 @@ fn greet(name: &str) -> String {
 
 // 메모 목록 조회
 #[tauri::command]
-fn get_notes_list() -> Result<NotesIndex, String> {
-    read_index()
+fn get_notes_list(
+    sort: Option<NoteSort>,
+    include_archived: Option<bool>,
+) -> Result<NotesIndex, String> {
+    let mut index = read_index()?;
+    if !include_archived.unwrap_or(false) {
+        index.notes.retain(|n| !n.archived);
+    }
+    sort_notes(&mut index.notes, sort.unwrap_or_default());
+    Ok(index)
+}
+
+// 메모 고정/해제 커맨드
+#[tauri::command]
+fn set_note_pinned(
+    app: tauri::AppHandle,
+    id: String,
+    pinned: bool,
+) -> Result<NoteMetadata, String> {
+    let _guard = lock_index();
+    let mut index = read_index()?;
+    let note = index
+        .notes
+        .iter_mut()
+        .find(|n| n.id == id)
+        .ok_or("Note not found")?;
+    note.pinned = pinned;
+    let updated = note.clone();
+    write_index(&index)?;
+
+    let _ = app.emit("refresh-notes-list", ());
+    Ok(updated)
+}
+
+// 메모 순서 변경 커맨드 (Manual 정렬에 사용, ids에 없는 메모는 뒤에 기존 순서대로 유지)
+#[tauri::command]
+fn reorder_notes(app: tauri::AppHandle, ids: Vec<String>) -> Result<(), String> {
+    let _guard = lock_index();
+    let mut index = read_index()?;
+    index.notes = order_by_ids(std::mem::take(&mut index.notes), &ids, |n| &n.id);
+    write_index(&index)?;
+
+    let _ = app.emit("refresh-notes-list", ());
+    Ok(())
+}
+
+// 정렬 기준에 따라 정렬한 뒤 고정된 메모를 맨 위로 (안정 정렬이므로 각 구간 내 순서 유지)
+fn sort_notes(notes: &mut [NoteMetadata], sort: NoteSort) {
+    let parse = |value: &str| chrono::DateTime::parse_from_rfc3339(value).ok();
+
+    match sort {
+        // 시각을 해석할 수 없는 메모는 맨 뒤로
+        NoteSort::Updated => notes.sort_by_key(|n| std::cmp::Reverse(parse(&n.updated_at))),
+        NoteSort::Created => notes.sort_by_key(|n| std::cmp::Reverse(parse(&n.created_at))),
+        NoteSort::Title => notes.sort_by_cached_key(|n| n.title.to_lowercase()),
+        NoteSort::Manual => {}
+    }
+    notes.sort_by_key(|n| !n.pinned);
+}
+
+// ids 순서대로 재배열, ids에 없는 항목은 뒤에 기존 순서대로 유지
+fn order_by_ids<T>(mut items: Vec<T>, ids: &[String], id_of: impl Fn(&T) -> &String) -> Vec<T> {
+    let mut ordered = Vec::with_capacity(items.len());
+    for id in ids {
+        if let Some(pos) = items.iter().position(|item| id_of(item) == id) {
+            ordered.push(items.remove(pos));
+        }
+    }
+    ordered.extend(items);
+    ordered
 }
 
 // UUID 생성 커맨드
@@ -144,7 +496,15 @@ fn register_note(
     let now: chrono::DateTime<chrono::Utc> = SystemTime::now().into();
     let now_str = now.to_rfc3339();
 
-    let metadata = NoteMetadata {
+    // 저장된 파일이 있으면 제목, 링크, 통계는 파일 내용 기준으로 계산
+    // 암호화된 파일은 본문을 해석하지 않음
+    let content = read_file(std::path::Path::new(&file_path))
+        .ok()
+        .map(|bytes| String::from_utf8_lossy(&bytes).to_string())
+        .filter(|c| !encryption::is_encrypted(c));
+    let title = content.as_deref().map(extract_title).unwrap_or(title);
+
+    let mut metadata = NoteMetadata {
         id,
         title,
         file_path,
@@ -152,23 +512,52 @@ fn register_note(
         updated_at: now_str.clone(),
         width: Some(400.0),
         height: Some(400.0),
+        ..Default::default()
     };
+    if let Some(content) = &content {
+        refresh_content_stats(&mut metadata, content);
+    }
 
     // 인덱스에 추가
+    let guard = lock_index();
     let mut index = read_index()?;
+    let mut old_title = None;
+    let created = !index.notes.iter().any(|n| n.id == metadata.id);
     // 이미 존재하는지 확인 (업데이트)
     if let Some(existing) = index.notes.iter_mut().find(|n| n.id == metadata.id) {
+        old_title = Some(existing.title.clone());
         existing.title = metadata.title.clone();
         existing.file_path = metadata.file_path.clone();
         existing.updated_at = now_str;
+        existing.links = metadata.links.clone();
+        existing.word_count = metadata.word_count;
+        existing.char_count = metadata.char_count;
+        existing.content_hash = metadata.content_hash.clone();
     } else {
         index.notes.push(metadata.clone());
     }
 
+    // 제목이 바뀌었으면 다른 노트의 [[이전 제목]] 링크 갱신
+    let rewritten = match old_title {
+        Some(old) if old != metadata.title => {
+            links::rewrite_links_for_rename(&mut index, &metadata.id, &old, &metadata.title)
+        }
+        _ => vec![],
+    };
+
     write_index(&index)?;
+    drop(guard);
+
+    // 링크가 바뀐 노트의 열린 윈도우가 내용을 다시 불러오도록 알림
+    for note_id in rewritten {
+        let _ = app.emit("note-file-changed", note_id);
+    }
 
     // 이벤트 발행: 노트 목록이 변경되었음을 알림
     let _ = app.emit("refresh-notes-list", ());
+    if created {
+        hooks::fire(hooks::HookEvent::NoteCreated, &metadata, content);
+    }
 
     Ok(metadata)
 }
@@ -221,9 +610,35 @@ fn show_context_menu(app: tauri::AppHandle, window: tauri::Window) -> Result<(),
         .build()
         .map_err(|e| e.to_string())?;
 
+    let copy_link = MenuItemBuilder::new("Copy Link to Note")
+        .id("copy_link")
+        .build(&app)
+        .map_err(|e| e.to_string())?;
+
+    // 열려 있는 노트 윈도우 전체 정렬 (on_menu_event에서 처리)
+    let mut arrange = SubmenuBuilder::new(&app, "Arrange Notes");
+    for (id, label) in [
+        ("arrange_tile", "Tile"),
+        ("arrange_cascade", "Cascade"),
+        ("arrange_stack_left", "Stack on Left Edge"),
+        ("arrange_stack_right", "Stack on Right Edge"),
+        ("arrange_align_left", "Align Left Edges"),
+        ("arrange_align_top", "Align Top Edges"),
+    ] {
+        arrange = arrange.item(
+            &MenuItemBuilder::new(label)
+                .id(id)
+                .build(&app)
+                .map_err(|e| e.to_string())?,
+        );
+    }
+    let arrange = arrange.build().map_err(|e| e.to_string())?;
+
     let menu = MenuBuilder::new(&app)
         .item(&toggle_top)
         .item(&colors)
+        .item(&copy_link)
+        .item(&arrange)
         .build()
         .map_err(|e| e.to_string())?;
 
@@ -234,6 +649,15 @@ fn show_context_menu(app: tauri::AppHandle, window: tauri::Window) -> Result<(),
 // 메모 윈도우 열기
 #[tauri::command]
 async fn open_note_window(app: tauri::AppHandle, note_id: String) -> Result<(), String> {
+    open_note_window_at(app, note_id, None).await
+}
+
+// 노트 윈도우 열기, 새로 만드는 윈도우는 cursor(UTF-16 오프셋)에 커서를 두도록 URL로 전달
+async fn open_note_window_at(
+    app: tauri::AppHandle,
+    note_id: String,
+    cursor: Option<usize>,
+) -> Result<(), String> {
     println!("Opening note window for ID: {}", note_id);
 
     // 저장된 크기 불러오기
@@ -245,6 +669,7 @@ async fn open_note_window(app: tauri::AppHandle, note_id: String) -> Result<(),
     } else {
         (400.0, 400.0)
     };
+    let saved_position = note.and_then(|n| n.x.zip(n.y));
 
     println!("Restoring window size: {}x{}", width, height);
 
@@ -253,26 +678,40 @@ async fn open_note_window(app: tauri::AppHandle, note_id: String) -> Result<(),
     // 이미 열려있는지 확인
     if let Some(window) = app.get_webview_window(&label) {
         println!("Window {} already exists, focusing...", label);
+        // 그룹 숨기기로 감춰진 윈도우일 수 있으므로 먼저 표시
+        let _ = window.show();
         let _ = window.set_focus();
         return Ok(());
     }
 
     println!("Creating new window: {}", label);
 
+    let url = match cursor {
+        Some(cursor) => format!("index.html?cursor={}", cursor),
+        None => "index.html".to_string(),
+    };
     let builder =
-        tauri::WebviewWindowBuilder::new(&app, &label, tauri::WebviewUrl::App("index.html".into()))
-            .title(&format!("Note"))
+        tauri::WebviewWindowBuilder::new(&app, &label, tauri::WebviewUrl::App(url.into()))
+            .title("Note")
             .inner_size(width, height)
             .min_inner_size(300.0, 100.0)
             .decorations(false) // 테두리 없음
             .transparent(false) // 투명도 없음 (일단)
+            .visible(false) // 위치를 잡은 뒤 표시
             .disable_drag_drop_handler(); // HTML5 Drag&Drop 사용을 위해 Tauri 핸들러 비활성화
 
     println!("Builder created, building window...");
 
+    // 새 윈도우를 만들기 전에 위치 결정 (계단식 배치가 새 윈도우 자신을 피하지 않도록)
+    let position = placement::note_position(&app, saved_position, (width, height));
+
     match builder.build() {
-        Ok(_) => {
+        Ok(window) => {
             println!("Window {} created successfully", label);
+            if let Err(e) = placement::apply(&window, position, (width, height)) {
+                println!("Failed to place window {}: {}", label, e);
+            }
+            window.show().map_err(|e| e.to_string())?;
             Ok(())
         }
         Err(e) => {
@@ -285,22 +724,268 @@ async fn open_note_window(app: tauri::AppHandle, note_id: String) -> Result<(),
 // 파일 저장 커맨드
 #[tauri::command]
 fn save_note(path: String, content: String) -> Result<String, String> {
-    fs::write(&path, &content).map_err(|e| e.to_string())?;
+    // 인덱스를 읽지 못해도 인덱스 밖의 파일 저장은 진행 (암호화 여부 확인은 최선 노력)
+    let index = read_index().ok();
+    let content = write_note_file(index.as_ref(), &path, content)?;
+
+    // 수정 시각과 링크 그래프 갱신 (실패해도 저장 자체는 성공으로 처리)
+    // git 커밋 메시지에는 save_note_by_id와 같이 노트 제목 사용 (인덱스 밖의 파일은 파일 이름)
+    let mut commit_title = std::path::Path::new(&path)
+        .file_stem()
+        .map(|name| name.to_string_lossy().to_string());
+    match touch_note_for_path(&path, &content) {
+        // 암호화된 노트는 훅에 내용을 넘기지 않음
+        Ok(Some(note)) => {
+            commit_title = Some(note.title.clone());
+            let content = (!note.encrypted).then_some(content);
+            hooks::fire(hooks::HookEvent::NoteSaved, &note, content)
+        }
+        Ok(None) => {}
+        Err(e) => println!("Failed to update index for {}: {}", path, e),
+    }
+    if let Some(title) = commit_title {
+        git::schedule_commit(&title);
+    }
     Ok(format!("Saved to {}", path))
 }
 
+// 파일 쓰기: 인덱스에서 암호화된 노트이면 잠금 해제된 키로 암호화, 실제로 쓴 내용 반환
+fn write_note_file(
+    index: Option<&NotesIndex>,
+    path: &str,
+    content: String,
+) -> Result<String, String> {
+    let encrypted_id = index
+        .and_then(|index| {
+            index
+                .notes
+                .iter()
+                .find(|n| n.file_path == path && n.encrypted)
+        })
+        .map(|n| n.id.clone());
+    let content = match encrypted_id {
+        Some(id) => encryption::seal_for_note(&id, &content)?,
+        None => content,
+    };
+    write_atomic(std::path::Path::new(path), content.as_bytes())?;
+    Ok(content)
+}
+
+// 저장된 파일에 해당하는 노트의 updated_at과 링크 목록 갱신 (인덱스에 등록된 노트만)
+fn touch_note_for_path(path: &str, content: &str) -> Result<Option<NoteMetadata>, String> {
+    let _guard = lock_index();
+    let mut index = read_index()?;
+    let Some(note) = index.notes.iter_mut().find(|n| n.file_path == path) else {
+        return Ok(None);
+    };
+
+    note.updated_at = chrono::Utc::now().to_rfc3339();
+    if note.encrypted {
+        encryption::hide_content_stats(note, content);
+    } else {
+        refresh_content_stats(note, content);
+    }
+    let updated = note.clone();
+    write_index(&index)?;
+    Ok(Some(updated))
+}
+
+// 경로로 연 파일의 인덱스 정보: save_note_by_id와 같이 제목과 본문 통계(링크, 단어/글자 수, 해시)를 채움
+fn note_for_file(id: String, file_path: String, content: &str, now: String) -> NoteMetadata {
+    let encrypted = encryption::is_encrypted(content);
+    // 암호화된 파일은 본문 대신 파일 이름을 제목으로 사용
+    let title = if encrypted {
+        encryption::locked_title(&file_path)
+    } else {
+        extract_title(content)
+    };
+
+    let mut note = NoteMetadata {
+        id,
+        title,
+        file_path,
+        created_at: now.clone(),
+        updated_at: now,
+        width: Some(400.0),
+        height: Some(400.0),
+        encrypted,
+        ..Default::default()
+    };
+    if encrypted {
+        encryption::hide_content_stats(&mut note, content);
+    } else {
+        refresh_content_stats(&mut note, content);
+    }
+    note
+}
+
+// id로 노트 저장 커맨드: 파일 쓰기와 함께 제목, 수정 시각, 통계를 인덱스에 반영
+#[tauri::command]
+fn save_note_by_id(
+    app: tauri::AppHandle,
+    id: String,
+    content: String,
+) -> Result<NoteMetadata, String> {
+    // API 서버 스레드에서도 호출되므로 인덱스를 쓸 때까지 잠금 유지
+    let guard = lock_index();
+    let mut index = read_index()?;
+    let (path, old_title, encrypted) = index
+        .notes
+        .iter()
+        .find(|n| n.id == id)
+        .map(|n| (n.file_path.clone(), n.title.clone(), n.encrypted))
+        .ok_or("Note not found")?;
+
+    // 암호화된 노트는 잠금 해제된 키로 암호화하여 저장
+    let sealed = if encrypted {
+        Some(encryption::seal_for_note(&id, &content)?)
+    } else {
+        None
+    };
+    write_atomic(
+        std::path::Path::new(&path),
+        sealed.as_deref().unwrap_or(&content).as_bytes(),
+    )?;
+
+    let updated_at = chrono::Utc::now().to_rfc3339();
+    // 암호화된 노트는 평문에서 제목/링크를 뽑지 않음 (제목은 암호화할 때의 것을 유지)
+    let mut rewritten = match &sealed {
+        Some(_) => vec![],
+        None => sync_note_content(&mut index, &id, &content, updated_at.clone())?,
+    };
+    let note = index
+        .notes
+        .iter_mut()
+        .find(|n| n.id == id)
+        .ok_or("Note not found")?;
+    if let Some(sealed) = &sealed {
+        note.updated_at = updated_at;
+        encryption::hide_content_stats(note, sealed);
+    }
+
+    // 설정에 따라 바뀐 제목으로 파일 이름 변경
+    let renamed =
+        if note.title != old_title && settings::read_settings()?.notes.rename_on_title_change {
+            rename_to_title(note, &get_notes_dir()?)?
+        } else {
+            None
+        };
+    let updated = note.clone();
+
+    if let Err(e) = write_index(&index) {
+        // 인덱스 저장에 실패하면 파일 이름도 되돌려 인덱스와 맞춤
+        if let Some(old_path) = renamed {
+            let _ = fs::rename(&updated.file_path, old_path);
+        }
+        return Err(e);
+    }
+    drop(guard);
+
+    if renamed.is_some() {
+        rewritten.push(id);
+    }
+    for note_id in rewritten {
+        let _ = app.emit("note-file-changed", note_id);
+    }
+    let _ = app.emit("refresh-notes-list", ());
+    git::schedule_commit(&updated.title);
+    hooks::fire(
+        hooks::HookEvent::NoteSaved,
+        &updated,
+        sealed.is_none().then_some(content),
+    );
+
+    Ok(updated)
+}
+
+// 노트 폴더 안의 파일을 제목 기반 이름으로 변경, 변경 전 경로 반환 (인덱스 저장은 호출자가 담당)
+fn rename_to_title(
+    note: &mut NoteMetadata,
+    notes_dir: &std::path::Path,
+) -> Result<Option<PathBuf>, String> {
+    let current = PathBuf::from(&note.file_path);
+    // 사용자가 직접 고른 노트 폴더 밖의 파일은 이름을 바꾸지 않음
+    if !current.starts_with(notes_dir) {
+        return Ok(None);
+    }
+    let (Some(dir), Some(stem)) = (current.parent(), current.file_stem()) else {
+        return Ok(None);
+    };
+
+    // 이미 제목에 맞는 이름("slug.md", "slug_1.md")이면 그대로 둠
+    let slug = slugify_title(&note.title);
+    let stem = stem.to_string_lossy();
+    let matches_slug = stem == slug
+        || stem
+            .strip_prefix(&slug)
+            .and_then(|rest| rest.strip_prefix('_'))
+            .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()));
+    if matches_slug {
+        return Ok(None);
+    }
+
+    let target = available_path(dir, &format!("{}.md", slug), None);
+    fs::rename(&current, &target).map_err(|e| e.to_string())?;
+    note.file_path = target.to_string_lossy().to_string();
+    Ok(Some(current))
+}
+
+// 새 노트를 노트 폴더에 제목 기반 파일 이름으로 저장하고 인덱스에 등록하는 커맨드
+#[tauri::command]
+fn save_new_note(
+    app: tauri::AppHandle,
+    id: String,
+    content: String,
+) -> Result<NoteMetadata, String> {
+    let (path, title) = write_new_note(&get_notes_dir()?, &content)?;
+    register_note(app, id, title, path.to_string_lossy().to_string())
+}
+
+// 새 노트 파일을 dir에 제목 기반 이름으로 저장 (같은 이름이 있으면 "_1" 등을 붙임), 경로와 제목 반환
+fn write_new_note(dir: &std::path::Path, content: &str) -> Result<(PathBuf, String), String> {
+    let title = extract_title(content);
+    let path = available_path(dir, &format!("{}.md", slugify_title(&title)), None);
+    write_atomic(&path, content.as_bytes())?;
+    Ok((path, title))
+}
+
 // 노트 내용 읽기 커맨드
 #[tauri::command]
-fn load_note_content(id: String) -> Result<String, String> {
-    let index = read_index()?;
+fn load_note_content(app: tauri::AppHandle, id: String) -> Result<String, String> {
+    // 바뀐 파일 정보를 인덱스에 쓸 수 있으므로 자동 저장/백그라운드 스레드와 같은 잠금 사용
+    let guard = lock_index();
+    let mut index = read_index()?;
     let note = index
         .notes
         .iter()
         .find(|n| n.id == id)
         .ok_or("Note not found")?;
 
-    let content_bytes = fs::read(&note.file_path).map_err(|e| e.to_string())?;
+    // 암호화된 노트는 잠금 해제 전까지 LOCKED 오류
+    if note.encrypted {
+        return encryption::read_unlocked(note);
+    }
+
+    let content_bytes = read_file(std::path::Path::new(&note.file_path))?;
     let content = String::from_utf8_lossy(&content_bytes).to_string();
+
+    // 외부 편집기 등으로 파일이 바뀌었으면 인덱스 정보도 갱신 (수정 시각은 파일 기준)
+    if note.content_hash.as_deref() != Some(content_hash(&content).as_str()) {
+        let updated_at = fs::metadata(&note.file_path)
+            .and_then(|m| m.modified())
+            .map(|t| chrono::DateTime::<chrono::Utc>::from(t).to_rfc3339())
+            .unwrap_or_else(|_| chrono::Utc::now().to_rfc3339());
+
+        let rewritten = sync_note_content(&mut index, &id, &content, updated_at)?;
+        write_index(&index)?;
+        drop(guard);
+
+        for note_id in rewritten {
+            let _ = app.emit("note-file-changed", note_id);
+        }
+        let _ = app.emit("refresh-notes-list", ());
+    }
+
     Ok(content)
 }
 
@@ -314,13 +999,13 @@ async fn save_note_with_dialog(app: tauri::AppHandle, content: String) -> Result
         .dialog()
         .file()
         .add_filter("Markdown", &["md"])
-        .set_file_name("note.md")
+        .set_file_name(format!("{}.md", slugify_title(&extract_title(&content))))
         .blocking_save_file();
 
     match file_path {
         Some(FilePath::Path(path)) => {
             // 파일 저장
-            fs::write(&path, &content).map_err(|e| e.to_string())?;
+            write_atomic(&path, content.as_bytes())?;
             Ok(path.to_string_lossy().to_string())
         }
         _ => Err("No file selected".to_string()),
@@ -384,10 +1069,10 @@ async fn open_main_window(app: tauri::AppHandle) -> Result<(), String> {
 
     // 저장된 크기 불러오기
     let index = read_index()?;
-    let (width, height) = if let Some(size) = index.main_window {
-        (size.width, size.height)
+    let (width, height, saved_position) = if let Some(size) = index.main_window {
+        (size.width, size.height, size.x.zip(size.y))
     } else {
-        (600.0, 800.0)
+        (600.0, 800.0, None)
     };
 
     log_to_file(&format!(
@@ -405,13 +1090,21 @@ async fn open_main_window(app: tauri::AppHandle) -> Result<(), String> {
     .min_inner_size(400.0, 200.0)
     .decorations(false)
     .transparent(false)
-    .resizable(true);
+    .resizable(true)
+    .visible(false);
 
     match builder.build() {
         Ok(window) => {
             log_to_file("open_main_window: Window built successfully");
-            if let Err(e) = window.center() {
-                log_to_file(&format!("open_main_window: Failed to center - {}", e));
+            // 저장된 위치가 없을 때만 가운데 배치
+            match placement::restore(&window, saved_position, (width, height)) {
+                Ok(true) => {}
+                Ok(false) => {
+                    if let Err(e) = window.center() {
+                        log_to_file(&format!("open_main_window: Failed to center - {}", e));
+                    }
+                }
+                Err(e) => log_to_file(&format!("open_main_window: Failed to place - {}", e)),
             }
 
             if let Err(e) = window.show() {
@@ -452,10 +1145,7 @@ fn read_image_binary(file_path: String) -> Result<Vec<u8>, String> {
         return Err(format!("Forbidden file extension: {}", extension));
     }
 
-    match fs::read(&path) {
-        Ok(data) => Ok(data),
-        Err(e) => Err(format!("Failed to read file: {}", e)),
-    }
+    read_file(&path).map_err(|e| format!("Failed to read file: {}", e))
 }
 
 // 이미지 파일 저장 (Drag & Drop용)
@@ -483,14 +1173,20 @@ fn save_image(name: String, data: Vec<u8>) -> Result<String, String> {
     );
     let file_path = images_dir.join(&new_name);
 
-    fs::write(&file_path, data).map_err(|e| e.to_string())?;
+    write_atomic(&file_path, &data)?;
 
     Ok(file_path.to_string_lossy().to_string())
 }
 
-// 윈도우 상태 저장 커맨드
+// 윈도우 상태 저장 커맨드 (호출한 윈도우의 현재 위치와 크기)
 #[tauri::command]
-fn save_window_state(id: Option<String>, width: f64, height: f64) -> Result<(), String> {
+fn save_window_state(window: tauri::WebviewWindow, id: Option<String>) -> Result<(), String> {
+    // 최소화된 윈도우는 위치가 화면 밖(-32000 등)으로 보고되므로 저장하지 않음
+    if window.is_minimized().unwrap_or(false) {
+        return Ok(());
+    }
+    let ((x, y), (width, height)) = placement::geometry(&window)?;
+    let _guard = lock_index();
     let mut index = read_index()?;
 
     if let Some(note_id) = id {
@@ -498,10 +1194,17 @@ fn save_window_state(id: Option<String>, width: f64, height: f64) -> Result<(),
         if let Some(note) = index.notes.iter_mut().find(|n| n.id == note_id) {
             note.width = Some(width);
             note.height = Some(height);
+            note.x = Some(x);
+            note.y = Some(y);
         }
     } else {
         // 메인 윈도우
-        index.main_window = Some(WindowSize { width, height });
+        index.main_window = Some(WindowSize {
+            width,
+            height,
+            x: Some(x),
+            y: Some(y),
+        });
     }
 
     write_index(&index)?;
@@ -525,10 +1228,13 @@ async fn open_file_from_path(app: tauri::AppHandle, path: String) -> Result<Stri
     use std::time::SystemTime;
 
     let path_str = path.clone();
+    // 등록 여부 확인부터 인덱스 저장까지 잠금 유지 (윈도우 조작 전에 해제)
+    let guard = lock_index();
     let mut index = read_index()?;
 
     // 1. 이미 등록된 파일인지 확인
     if let Some(existing_note) = index.notes.iter().find(|n| n.file_path == path_str) {
+        drop(guard);
         log_to_file(&format!("File already registered: {}", path_str));
 
         // 윈도우가 열려있는지 확인하고 포커스
@@ -551,25 +1257,15 @@ async fn open_file_from_path(app: tauri::AppHandle, path: String) -> Result<Stri
     let new_id = uuid::Uuid::new_v4().to_string();
 
     // 파일 내용 읽어서 제목 추출 (lossy utf8 처리)
-    let content_bytes = fs::read(&path).map_err(|e| e.to_string())?;
+    let content_bytes = read_file(std::path::Path::new(&path))?;
     let content = String::from_utf8_lossy(&content_bytes).to_string();
-    let title = extract_title(&content);
 
     let now: chrono::DateTime<chrono::Utc> = SystemTime::now().into();
-    let now_str = now.to_rfc3339();
-
-    let new_note = NoteMetadata {
-        id: new_id.clone(),
-        title,
-        file_path: path_str,
-        created_at: now_str.clone(),
-        updated_at: now_str,
-        width: Some(400.0),
-        height: Some(400.0),
-    };
+    let new_note = note_for_file(new_id.clone(), path_str, &content, now.to_rfc3339());
 
     index.notes.push(new_note);
     write_index(&index)?;
+    drop(guard);
 
     // 목록 갱신 이벤트 발행
     let _ = app.emit("refresh-notes-list", ());
@@ -602,12 +1298,18 @@ async fn open_file_with_dialog(app: tauri::AppHandle) -> Result<String, String>
 // 노트 삭제 커맨드
 #[tauri::command]
 fn delete_note(app: tauri::AppHandle, id: String) -> Result<(), String> {
+    let guard = lock_index();
     let mut index = read_index()?;
 
     // 해당 노트 찾기
     if let Some(pos) = index.notes.iter().position(|n| n.id == id) {
         let note = &index.notes[pos];
         let file_path = PathBuf::from(&note.file_path);
+        let title = note.title.clone();
+        // 삭제 훅에 전달할 내용 (파일을 지우기 전에 읽음)
+        let content = read_file(&file_path)
+            .ok()
+            .map(|bytes| String::from_utf8_lossy(&bytes).to_string());
 
         // 파일 삭제
         if file_path.exists() {
@@ -615,8 +1317,11 @@ fn delete_note(app: tauri::AppHandle, id: String) -> Result<(), String> {
         }
 
         // 인덱스에서 제거
-        index.notes.remove(pos);
+        let removed = index.notes.remove(pos);
         write_index(&index)?;
+        drop(guard);
+        git::record_deletion(&title);
+        hooks::fire(hooks::HookEvent::NoteDeleted, &removed, content);
 
         // 열린 윈도우 닫기
         let label = format!("note_{}", id);
@@ -633,9 +1338,80 @@ fn delete_note(app: tauri::AppHandle, id: String) -> Result<(), String> {
     }
 }
 
+// 노트 보관 커맨드 (파일과 인덱스는 유지, 열린 윈도우는 닫음)
+#[tauri::command]
+fn archive_note(app: tauri::AppHandle, id: String) -> Result<NoteMetadata, String> {
+    let updated = set_archived(&id, true)?;
+
+    let label = format!("note_{}", id);
+    if let Some(window) = app.get_webview_window(&label) {
+        let _ = window.close();
+    }
+
+    let _ = app.emit("refresh-notes-list", ());
+    Ok(updated)
+}
+
+// 노트 보관 해제 커맨드
+#[tauri::command]
+fn unarchive_note(app: tauri::AppHandle, id: String) -> Result<NoteMetadata, String> {
+    let updated = set_archived(&id, false)?;
+    let _ = app.emit("refresh-notes-list", ());
+    Ok(updated)
+}
+
+fn set_archived(id: &str, archived: bool) -> Result<NoteMetadata, String> {
+    let _guard = lock_index();
+    let mut index = read_index()?;
+    let note = index
+        .notes
+        .iter_mut()
+        .find(|n| n.id == id)
+        .ok_or("Note not found")?;
+
+    note.archived = archived;
+    note.archived_at = archived.then(|| chrono::Utc::now().to_rfc3339());
+    let updated = note.clone();
+    write_index(&index)?;
+    Ok(updated)
+}
+
+// 노트 검색 커맨드: 제목과 본문에서 대소문자 구분 없이 검색 (기본적으로 보관된 노트 포함)
+// 암호화된 노트는 제목만 검색
+#[tauri::command]
+fn search_notes(
+    query: String,
+    include_archived: Option<bool>,
+) -> Result<Vec<NoteMetadata>, String> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Ok(vec![]);
+    }
+    let include_archived = include_archived.unwrap_or(true);
+
+    let index = read_index()?;
+    Ok(index
+        .notes
+        .into_iter()
+        .filter(|n| include_archived || !n.archived)
+        .filter(|n| {
+            n.title.to_lowercase().contains(&query)
+                || (!n.encrypted
+                    && read_file(std::path::Path::new(&n.file_path))
+                        .map(|bytes| {
+                            String::from_utf8_lossy(&bytes)
+                                .to_lowercase()
+                                .contains(&query)
+                        })
+                        .unwrap_or(false))
+        })
+        .collect())
+}
+
 // 노트 목록에서만 제거하는 커맨드
 #[tauri::command]
 fn remove_note_from_index(app: tauri::AppHandle, id: String) -> Result<(), String> {
+    let guard = lock_index();
     let mut index = read_index()?;
 
     // 해당 노트 찾기
@@ -643,6 +1419,7 @@ fn remove_note_from_index(app: tauri::AppHandle, id: String) -> Result<(), Strin
         // 인덱스에서 제거 (파일 삭제 안 함)
         index.notes.remove(pos);
         write_index(&index)?;
+        drop(guard);
 
         // 열린 윈도우 닫기
         let label = format!("note_{}", id);
@@ -659,12 +1436,45 @@ fn remove_note_from_index(app: tauri::AppHandle, id: String) -> Result<(), Strin
     }
 }
 
+// 인덱스를 읽는 백그라운드 작업 시작 (한 번만)
+fn start_background_tasks(app: &tauri::AppHandle) {
+    static STARTED: std::sync::Once = std::sync::Once::new();
+
+    STARTED.call_once(|| {
+        // 자동 백업 스케줄러 시작
+        auto_backup::start_scheduler();
+
+        // 리마인더 스케줄러 시작 (앱이 꺼져 있던 동안 놓친 알림도 첫 확인 시 표시)
+        reminders::start_scheduler(app.clone());
+
+        // 잠금 해제한 노트 자동 잠금
+        encryption::start_auto_lock(app.clone());
+
+        // 폴더 동기화 스케줄러 시작
+        sync::start_scheduler(app.clone());
+
+        // git 저장소 모드의 저장 커밋
+        git::start_scheduler();
+
+        // 자동화용 로컬 HTTP API
+        api::start_server(app.clone());
+    });
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    // CLI 내보내기 모드는 윈도우 없이 처리 후 종료
+    let cli_args: Vec<String> = std::env::args().collect();
+    if let Some(code) = export::run_cli(&cli_args) {
+        std::process::exit(code);
+    }
+
     tauri::Builder::default()
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_dialog::init())
+        .plugin(tauri_plugin_notification::init())
+        // single-instance를 먼저 등록해야 두 번째 실행의 딥 링크가 on_open_url로 전달됨
         .plugin(tauri_plugin_single_instance::init(|app, args, _cwd| {
             log_to_file(&format!("Single instance callback: {:?}", args));
 
@@ -696,6 +1506,11 @@ pub fn run() {
             if args.len() > 1 {
                 // println!("Single instance args: {:?}", args);
                 for arg in args.iter().skip(1) {
+                    // 딥 링크는 deep-link 플러그인의 on_open_url에서 처리
+                    if deep_link::is_deep_link(arg) {
+                        break;
+                    }
+
                     let path = std::path::Path::new(arg);
                     if path.exists() && path.is_file() {
                         if let Some(ext) = path.extension() {
@@ -713,9 +1528,12 @@ pub fn run() {
                 }
             }
         }))
+        .plugin(tauri_plugin_deep_link::init())
         .invoke_handler(tauri::generate_handler![
             greet,
             get_notes_list,
+            set_note_pinned,
+            reorder_notes,
             generate_new_note_id,
             register_note,
             show_context_menu,
@@ -723,6 +1541,8 @@ pub fn run() {
             open_main_window,
             save_note,
             load_note_content,
+            save_note_by_id,
+            save_new_note,
             save_note_with_dialog,
             set_always_on_top,
             close_window,
@@ -733,23 +1553,115 @@ pub fn run() {
             save_window_state,
             delete_note,
             remove_note_from_index,
+            archive_note,
+            unarchive_note,
+            search_notes,
             open_file_with_dialog,
-            open_file_from_path
+            open_file_from_path,
+            importer::import_notes,
+            export::export_note,
+            backup::export_all,
+            backup::import_backup,
+            auto_backup::list_backups,
+            auto_backup::restore_backup,
+            settings::get_settings,
+            settings::update_settings,
+            api::regenerate_api_token,
+            reminders::set_reminder,
+            reminders::snooze_reminder,
+            reminders::dismiss_reminder,
+            tasks::list_tasks,
+            tasks::toggle_task,
+            stats::get_stats,
+            sync::sync_now,
+            git::get_note_history,
+            git::get_note_revision,
+            git::git_pull,
+            git::git_push,
+            hooks::get_hook_log,
+            hooks::clear_hook_log,
+            arrange::tile_note_windows,
+            arrange::cascade_note_windows,
+            arrange::stack_note_windows,
+            arrange::align_note_windows,
+            arrange::snap_note_window,
+            templates::list_templates,
+            templates::create_note_from_template,
+            daily::open_daily_note,
+            daily::open_previous_daily_note,
+            daily::open_next_daily_note,
+            deep_link::copy_note_link,
+            encryption::encrypt_note,
+            encryption::decrypt_note,
+            encryption::unlock_note,
+            encryption::lock_note,
+            vault::get_library_status,
+            vault::enable_library_encryption,
+            vault::disable_library_encryption,
+            vault::unlock_library,
+            vault::lock_library,
+            vault::change_library_password,
+            vault::export_recovery_key,
+            links::resolve_link,
+            links::get_backlinks,
+            groups::create_group,
+            groups::rename_group,
+            groups::set_group_color,
+            groups::delete_group,
+            groups::reorder_groups,
+            groups::move_notes_to_group,
+            groups::set_group_windows_visible
         ])
         .setup(|app| {
-            // 앱 시작 시 메인 윈도우 크기 복원
-            let index = read_index().unwrap_or(NotesIndex {
-                notes: vec![],
-                main_window: None,
-            });
+            // 라이브러리가 암호화되어 있으면 마스터 비밀번호를 입력받은 뒤(unlock_library) 시작
+            // 메인 윈도우가 get_library_status로 잠김을 확인하고 비밀번호 입력 화면을 표시
+            if vault::is_enabled() {
+                println!("Library is encrypted, asking for master password");
+                if let Some(window) = app.get_webview_window("main") {
+                    let _ = window.show();
+                    let _ = window.set_focus();
+                }
+            } else {
+                start_background_tasks(app.handle());
+            }
+
+            // stickermd:// 링크 등록 (설치 프로그램 없이 실행한 경우) 및 macOS의 open-url 처리
+            {
+                use tauri_plugin_deep_link::DeepLinkExt;
+
+                #[cfg(any(windows, target_os = "linux"))]
+                if let Err(e) = app.deep_link().register_all() {
+                    log_to_file(&format!("Failed to register deep link scheme: {}", e));
+                }
+
+                let handle = app.handle().clone();
+                app.deep_link().on_open_url(move |event| {
+                    for url in event.urls() {
+                        deep_link::handle(handle.clone(), url.to_string());
+                    }
+                });
+
+                // Windows/Linux에서 링크로 실행된 경우 (첫 실행의 링크는 on_open_url로 오지 않음)
+                #[cfg(any(windows, target_os = "linux"))]
+                if let Ok(Some(urls)) = app.deep_link().get_current() {
+                    for url in urls {
+                        deep_link::handle(app.handle().clone(), url.to_string());
+                    }
+                }
+            }
+
+            // 앱 시작 시 메인 윈도우 크기와 위치 복원 (크기는 논리 크기로 저장됨)
+            let index = read_index().unwrap_or_default();
 
             if let Some(size) = index.main_window {
                 if let Some(window) = app.get_webview_window("main") {
                     println!("Restoring main window size: {}x{}", size.width, size.height);
-                    let _ = window.set_size(tauri::Size::Physical(tauri::PhysicalSize {
-                        width: size.width as u32,
-                        height: size.height as u32,
-                    }));
+                    let saved_position = size.x.zip(size.y);
+                    if let Err(e) =
+                        placement::restore(&window, saved_position, (size.width, size.height))
+                    {
+                        println!("Failed to restore main window: {}", e);
+                    }
                 }
             }
 
@@ -764,6 +1676,11 @@ pub fn run() {
             if args.len() > 1 {
                 // 첫 번째 인자는 실행 파일 경로이므로 두 번째부터 확인
                 for arg in args.iter().skip(1) {
+                    // 딥 링크는 위의 deep-link 플러그인에서 처리
+                    if deep_link::is_deep_link(arg) {
+                        break;
+                    }
+
                     let path = std::path::Path::new(arg);
                     let mut abs_path = if path.is_absolute() {
                         path.to_path_buf()
@@ -810,6 +1727,9 @@ pub fn run() {
         .on_menu_event(|app, event| {
             let id = event.id().as_ref();
             println!("Menu event: {}", id);
+            if id.starts_with("arrange_") {
+                arrange::handle_menu_event(app, id);
+            }
             let _ = app.emit("menu-event", id);
         })
         .run(tauri::generate_context!())
@@ -824,20 +1744,19 @@ mod tests {
 
     #[test]
     fn test_save_note() {
-        let path = "test_note.md";
+        let name = format!("stickermd_save_{}.md", uuid::Uuid::new_v4());
+        let path = std::env::temp_dir()
+            .join(name)
+            .to_string_lossy()
+            .to_string();
         let content = "# Hello Rust";
 
-        // 테스트 전 파일 정리
-        if Path::new(path).exists() {
-            fs::remove_file(path).unwrap();
-        }
-
-        // 파일 저장 실행
-        let result = save_note(path.to_string(), content.to_string());
+        // 파일 저장 실행 (인덱스에 없는 임시 파일이므로 인덱스는 바뀌지 않음)
+        let result = save_note(path.clone(), content.to_string());
         assert!(result.is_ok());
 
         // 저장된 내용 확인
-        let mut file = fs::File::open(path).unwrap();
+        let mut file = fs::File::open(&path).unwrap();
         let mut saved_content = String::new();
         file.read_to_string(&mut saved_content).unwrap();
 
@@ -846,6 +1765,46 @@ mod tests {
         // 테스트 후 파일 정리
         fs::remove_file(path).unwrap();
     }
+
+    #[test]
+    fn test_note_for_file_fills_content_stats() {
+        let note = note_for_file(
+            "n1".to_string(),
+            "/notes/plan.md".to_string(),
+            "# Plan\nSee [[Goals]] soon",
+            "2024-01-01T00:00:00+00:00".to_string(),
+        );
+
+        assert_eq!(note.title, "Plan");
+        assert_eq!(note.links, vec!["Goals".to_string()]);
+        assert_eq!(note.word_count, 5);
+        assert_eq!(
+            note.content_hash,
+            Some(content_hash("# Plan\nSee [[Goals]] soon"))
+        );
+        assert!(!note.encrypted);
+    }
+
+    #[test]
+    fn test_save_note_seals_encrypted_note() {
+        let path =
+            std::env::temp_dir().join(format!("stickermd_sealed_{}.md", uuid::Uuid::new_v4()));
+        let path = path.to_string_lossy().to_string();
+        let index = NotesIndex {
+            notes: vec![NoteMetadata {
+                id: "locked".to_string(),
+                file_path: path.clone(),
+                encrypted: true,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        // 잠금 해제되지 않은 암호화 노트는 평문으로 쓰지 않음
+        let result = write_note_file(Some(&index), &path, "# Secret".to_string());
+        assert_eq!(result, Err(encryption::LOCKED.to_string()));
+        assert!(!Path::new(&path).exists());
+    }
     #[test]
     fn test_extract_title_korean() {
         let content = "# 한글 제목 테스트입니다. 이 제목은 50자가 넘지 않지만 멀티바이트 처리를 테스트합니다.";
@@ -860,4 +1819,141 @@ mod tests {
         assert_eq!(title.chars().count(), 53); // 50 chars + "..."
         assert!(title.ends_with("..."));
     }
+
+    #[test]
+    fn test_sanitize_and_unique_name() {
+        assert_eq!(
+            sanitize_file_name("회의: 3/4 <draft>?. "),
+            "회의_ 3_4 _draft__"
+        );
+        assert_eq!(sanitize_file_name(" .. "), "Untitled");
+
+        let mut used = std::collections::HashSet::new();
+        assert_eq!(unique_name(&mut used, "memo.md"), "memo.md");
+        assert_eq!(unique_name(&mut used, "memo.md"), "memo_1.md");
+        assert_eq!(unique_name(&mut used, "memo.md"), "memo_2.md");
+    }
+
+    #[test]
+    fn test_sort_notes_keeps_pinned_on_top() {
+        let note = |id: &str, title: &str, updated_at: &str, pinned: bool| NoteMetadata {
+            id: id.to_string(),
+            title: title.to_string(),
+            updated_at: updated_at.to_string(),
+            pinned,
+            ..Default::default()
+        };
+        let mut notes = vec![
+            note("a", "banana", "2024-01-01T00:00:00Z", false),
+            note("b", "Apple", "2024-03-01T00:00:00+09:00", false),
+            note("c", "cherry", "invalid", false),
+            note("d", "date", "2023-01-01T00:00:00Z", true),
+        ];
+
+        sort_notes(&mut notes, NoteSort::Updated);
+        let ids: Vec<&str> = notes.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, vec!["d", "b", "a", "c"]);
+
+        sort_notes(&mut notes, NoteSort::Title);
+        let ids: Vec<&str> = notes.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, vec!["d", "b", "a", "c"]);
+
+        let ordered = order_by_ids(notes, &["c".to_string(), "x".to_string()], |n| &n.id);
+        let ids: Vec<&str> = ordered.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, vec!["c", "d", "b", "a"]);
+    }
+
+    #[test]
+    fn test_refresh_content_stats() {
+        let mut note = NoteMetadata::default();
+        refresh_content_stats(&mut note, "# 제목\n안녕 [[World]]  foo\n");
+
+        assert_eq!(note.word_count, 5);
+        assert_eq!(note.char_count, 23);
+        assert_eq!(note.links, vec!["World"]);
+        assert_eq!(note.content_hash.as_ref().map(String::len), Some(64));
+        assert_ne!(note.content_hash, Some(content_hash("# 제목")));
+    }
+
+    #[test]
+    fn test_slugify_title() {
+        assert_eq!(slugify_title("회의록: 2024년 3월!"), "회의록-2024년-3월");
+        assert_eq!(slugify_title("  Hello,   World  "), "hello-world");
+        assert_eq!(slugify_title("Untitled Note"), "untitled");
+        assert_eq!(slugify_title("???"), "untitled");
+        assert_eq!(slugify_title("CON"), "con-note");
+        assert_eq!(slugify_title("com7"), "com7-note");
+        assert_eq!(slugify_title("LPT9"), "lpt9-note");
+        assert_eq!(slugify_title("com10"), "com10");
+        assert_eq!(slugify_title(&"가".repeat(100)).chars().count(), 60);
+    }
+
+    #[test]
+    fn test_write_new_note_adds_suffix_on_collision() {
+        let dir = std::env::temp_dir().join(format!("stickermd_new_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let (first, title) = write_new_note(&dir, "# Hello, World\n본문").unwrap();
+        assert_eq!(title, "Hello, World");
+        assert_eq!(first, dir.join("hello-world.md"));
+        assert_eq!(fs::read_to_string(&first).unwrap(), "# Hello, World\n본문");
+
+        // 같은 제목은 기존 파일을 덮어쓰지 않고 번호를 붙임
+        let (second, _) = write_new_note(&dir, "# hello world").unwrap();
+        assert_eq!(second, dir.join("hello-world_1.md"));
+        assert_eq!(fs::read_to_string(&first).unwrap(), "# Hello, World\n본문");
+
+        let (untitled, title) = write_new_note(&dir, "").unwrap();
+        assert_eq!(title, "Untitled Note");
+        assert_eq!(untitled, dir.join("untitled.md"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_rename_to_title() {
+        let dir = std::env::temp_dir().join(format!("stickermd_rename_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("old.md"), "# 새 제목").unwrap();
+        fs::write(dir.join("새-제목.md"), "다른 노트").unwrap();
+
+        let mut note = NoteMetadata {
+            title: "새 제목".to_string(),
+            file_path: dir.join("old.md").to_string_lossy().to_string(),
+            ..Default::default()
+        };
+        // 이미 있는 이름과 겹치면 번호를 붙이고 변경 전 경로 반환
+        assert_eq!(
+            rename_to_title(&mut note, &dir).unwrap(),
+            Some(dir.join("old.md"))
+        );
+        assert_eq!(PathBuf::from(&note.file_path), dir.join("새-제목_1.md"));
+        assert!(!dir.join("old.md").exists());
+        assert_eq!(
+            fs::read_to_string(dir.join("새-제목.md")).unwrap(),
+            "다른 노트"
+        );
+
+        // 번호가 붙은 이름도 제목에 맞는 이름이므로 그대로 둠
+        assert_eq!(rename_to_title(&mut note, &dir).unwrap(), None);
+
+        // 노트 폴더 밖의 파일은 바꾸지 않음
+        let mut outside = NoteMetadata {
+            title: "새 제목".to_string(),
+            file_path: std::env::temp_dir()
+                .join("elsewhere.md")
+                .to_string_lossy()
+                .to_string(),
+            ..Default::default()
+        };
+        assert_eq!(rename_to_title(&mut outside, &dir).unwrap(), None);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_extract_tags() {
+        let content = "# Title\n할 일 #업무 #Work, #1 issue#not\n```\n#include\n```\n#work again";
+        assert_eq!(extract_tags(content), vec!["업무", "work"]);
+    }
 }
@@ -0,0 +1,217 @@
+// 노트 간 위키 링크([[노트 제목]], [[id]], [[제목|별칭]])와 백링크
+
+use crate::{read_file, read_index, write_atomic, NoteMetadata, NotesIndex};
+use std::path::Path;
+
+// 링크로 노트 찾기 커맨드
+#[tauri::command]
+pub fn resolve_link(text: String) -> Result<Option<NoteMetadata>, String> {
+    let index = read_index()?;
+    Ok(resolve(&index.notes, &text).cloned())
+}
+
+// 해당 노트를 링크하는 노트 목록 커맨드
+#[tauri::command]
+pub fn get_backlinks(id: String) -> Result<Vec<NoteMetadata>, String> {
+    let index = read_index()?;
+
+    Ok(index
+        .notes
+        .iter()
+        .filter(|note| note.id != id)
+        .filter(|note| {
+            note.links
+                .iter()
+                .any(|link| resolve(&index.notes, link).is_some_and(|target| target.id == id))
+        })
+        .cloned()
+        .collect())
+}
+
+// 링크 텍스트를 노트로 해석: id -> 제목 -> 대소문자 무시 제목 순
+pub(crate) fn resolve<'a>(notes: &'a [NoteMetadata], text: &str) -> Option<&'a NoteMetadata> {
+    let text = text.trim();
+    notes
+        .iter()
+        .find(|n| n.id == text)
+        .or_else(|| notes.iter().find(|n| n.title == text))
+        .or_else(|| {
+            let lower = text.to_lowercase();
+            notes.iter().find(|n| n.title.to_lowercase() == lower)
+        })
+}
+
+// 노트 제목이 바뀌었을 때 다른 노트의 [[이전 제목]] 링크를 새 제목으로 변경
+// 내용이 바뀐 노트의 id 목록 반환 (인덱스 저장은 호출자가 담당)
+pub(crate) fn rewrite_links_for_rename(
+    index: &mut NotesIndex,
+    renamed_id: &str,
+    old_title: &str,
+    new_title: &str,
+) -> Vec<String> {
+    // 같은 제목의 다른 노트가 있으면 어느 노트를 가리키는지 알 수 없으므로 변경하지 않음
+    let ambiguous = index
+        .notes
+        .iter()
+        .any(|n| n.id != renamed_id && n.title.to_lowercase() == old_title.to_lowercase());
+    if ambiguous || old_title == new_title {
+        return vec![];
+    }
+
+    let mut changed = Vec::new();
+    for note in index.notes.iter_mut().filter(|n| n.id != renamed_id) {
+        if !note
+            .links
+            .iter()
+            .any(|l| l.to_lowercase() == old_title.to_lowercase())
+        {
+            continue;
+        }
+
+        let Ok(bytes) = read_file(Path::new(&note.file_path)) else {
+            continue;
+        };
+        let content = String::from_utf8_lossy(&bytes);
+
+        if let Some(new_content) = rewrite_wiki_links(&content, old_title, new_title) {
+            match write_atomic(Path::new(&note.file_path), new_content.as_bytes()) {
+                Ok(()) => {
+                    note.links = parse_wiki_links(&new_content);
+                    changed.push(note.id.clone());
+                }
+                Err(e) => println!("Failed to rewrite links in {}: {}", note.file_path, e),
+            }
+        }
+    }
+
+    changed
+}
+
+// [[대상#제목|별칭]] 형식에서 대상 부분 추출
+fn link_target(inner: &str) -> &str {
+    let end = inner.find(['|', '#']).unwrap_or(inner.len());
+    inner[..end].trim()
+}
+
+// 본문의 위키 링크 대상 목록 (코드 블록 제외, 중복 제거)
+pub(crate) fn parse_wiki_links(content: &str) -> Vec<String> {
+    let mut links: Vec<String> = Vec::new();
+    let mut in_code_block = false;
+
+    for line in content.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+
+        let mut rest = line;
+        while let Some(start) = rest.find("[[") {
+            let Some(len) = rest[start + 2..].find("]]") else {
+                break;
+            };
+            let target = link_target(&rest[start + 2..start + 2 + len]);
+            if !target.is_empty() && !links.iter().any(|l| l == target) {
+                links.push(target.to_string());
+            }
+            rest = &rest[start + 2 + len + 2..];
+        }
+    }
+
+    links
+}
+
+// 대상이 old_title인 링크를 new_title로 교체 (별칭/헤딩은 유지, 코드 블록 제외), 변경이 없으면 None
+fn rewrite_wiki_links(content: &str, old_title: &str, new_title: &str) -> Option<String> {
+    let old_lower = old_title.to_lowercase();
+    let mut result = String::with_capacity(content.len());
+    let mut changed = false;
+    let mut in_code_block = false;
+
+    for line in content.split_inclusive('\n') {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            result.push_str(line);
+            continue;
+        }
+        if in_code_block {
+            result.push_str(line);
+            continue;
+        }
+
+        // 줄을 넘어가는 [[ ... ]]는 링크가 아니므로 줄 단위로 처리
+        let mut rest = line;
+        while let Some(start) = rest.find("[[") {
+            let Some(len) = rest[start + 2..].find("]]") else {
+                break;
+            };
+            let inner = &rest[start + 2..start + 2 + len];
+            result.push_str(&rest[..start + 2]);
+
+            if link_target(inner).to_lowercase() == old_lower {
+                let suffix_start = inner.find(['|', '#']).unwrap_or(inner.len());
+                result.push_str(new_title);
+                result.push_str(&inner[suffix_start..]);
+                changed = true;
+            } else {
+                result.push_str(inner);
+            }
+            result.push_str("]]");
+            rest = &rest[start + 2 + len + 2..];
+        }
+        result.push_str(rest);
+    }
+
+    changed.then_some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_wiki_links() {
+        let content = "See [[Project Plan]] and [[회의록|meeting]]\n[[Project Plan#Goals]] [[ ]]\n```\n[[code]]\n```";
+        assert_eq!(parse_wiki_links(content), vec!["Project Plan", "회의록"]);
+    }
+
+    #[test]
+    fn test_rewrite_wiki_links_keeps_alias() {
+        let content = "[[old title]] / [[Old Title|별칭]] / [[Old Title#h]] / [[Other]]";
+        assert_eq!(
+            rewrite_wiki_links(content, "Old Title", "New").unwrap(),
+            "[[New]] / [[New|별칭]] / [[New#h]] / [[Other]]"
+        );
+        assert!(rewrite_wiki_links(content, "Missing", "New").is_none());
+    }
+
+    #[test]
+    fn test_rewrite_wiki_links_skips_code_blocks() {
+        let content = "[[Old]]\n```\n[[Old]]\n```\n[[Old\n]] [[old|x]]";
+        assert_eq!(
+            rewrite_wiki_links(content, "Old", "New").unwrap(),
+            "[[New]]\n```\n[[Old]]\n```\n[[Old\n]] [[New|x]]"
+        );
+    }
+
+    #[test]
+    fn test_resolve_prefers_id_then_title() {
+        let notes = vec![
+            NoteMetadata {
+                id: "a".to_string(),
+                title: "Alpha".to_string(),
+                ..Default::default()
+            },
+            NoteMetadata {
+                id: "b".to_string(),
+                title: "a".to_string(),
+                ..Default::default()
+            },
+        ];
+        assert_eq!(resolve(&notes, "a").unwrap().id, "a");
+        assert_eq!(resolve(&notes, "alpha").unwrap().id, "a");
+        assert!(resolve(&notes, "gamma").is_none());
+    }
+}
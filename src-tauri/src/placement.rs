@@ -0,0 +1,298 @@
+// 윈도우 배치 (여러 모니터, 모니터별 배율)
+//
+// 위치는 가상 데스크톱의 물리 좌표(outer position)로, 크기는 논리 크기(inner size)로 저장한다.
+// 배율이 다른 모니터로 옮겨도 노트가 같은 크기로 보이고, 위치는 모니터 배치 그대로 복원된다.
+// 저장된 위치가 현재 연결된 모니터 밖이면 (모니터를 분리한 경우 등) 가장 가까운 모니터 안으로 옮긴다.
+// 옮긴 위치는 저장하지 않으므로 모니터를 다시 연결하면 원래 위치로 열린다.
+
+use tauri::{LogicalSize, Manager, PhysicalPosition, WebviewWindow};
+
+// 윈도우를 끌어서 옮길 수 있도록 화면에 보여야 하는 최소 크기 (논리 픽셀)
+const MIN_VISIBLE: f64 = 48.0;
+// 새 노트를 계단식으로 놓을 때의 간격과 작업 영역 가장자리 여백 (논리 픽셀)
+const CASCADE_STEP: f64 = 32.0;
+const CASCADE_MARGIN: f64 = 48.0;
+// 계단 한 줄이 아래 끝에 닿으면 다음 줄을 이만큼 오른쪽에서 시작 (간격의 배수)
+const CASCADE_COLUMN_SHIFT: i32 = 3;
+const MAX_CASCADE_COLUMNS: i32 = 8;
+
+// 모니터의 작업 영역 (물리 픽셀, 작업 표시줄/메뉴 막대 제외)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Area {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+    pub scale: f64,
+}
+
+impl Area {
+    pub(crate) fn right(&self) -> i32 {
+        self.x + self.width
+    }
+
+    pub(crate) fn bottom(&self) -> i32 {
+        self.y + self.height
+    }
+
+    // 논리 길이를 이 모니터의 물리 픽셀로
+    pub(crate) fn physical(&self, logical: f64) -> i32 {
+        (logical * self.scale).round() as i32
+    }
+
+    // 점에서 작업 영역까지 거리의 제곱 (안에 있으면 0)
+    fn distance_sq(&self, x: i32, y: i32) -> i64 {
+        let dx = (self.x - x).max(x - self.right()).max(0) as i64;
+        let dy = (self.y - y).max(y - self.bottom()).max(0) as i64;
+        dx * dx + dy * dy
+    }
+}
+
+// 현재 연결된 모니터의 작업 영역 목록
+pub(crate) fn monitor_areas(app: &tauri::AppHandle) -> Vec<Area> {
+    app.available_monitors()
+        .unwrap_or_default()
+        .iter()
+        .map(|monitor| {
+            let work_area = monitor.work_area();
+            // 작업 영역을 알 수 없는 플랫폼은 모니터 전체 영역 사용
+            let (position, size) = if work_area.size.width > 0 && work_area.size.height > 0 {
+                (work_area.position, work_area.size)
+            } else {
+                (*monitor.position(), *monitor.size())
+            };
+            Area {
+                x: position.x,
+                y: position.y,
+                width: size.width as i32,
+                height: size.height as i32,
+                scale: monitor.scale_factor(),
+            }
+        })
+        .filter(|area| area.width > 0 && area.height > 0)
+        .collect()
+}
+
+// 점이 있거나 점에서 가장 가까운 모니터
+pub(crate) fn nearest(areas: &[Area], x: i32, y: i32) -> Option<&Area> {
+    areas.iter().min_by_key(|area| area.distance_sq(x, y))
+}
+
+// 윈도우 위쪽 가장자리(끌어서 옮기는 부분)가 어느 모니터에서든 충분히 보이는지
+fn is_visible(areas: &[Area], (x, y): (i32, i32), size: (f64, f64)) -> bool {
+    areas.iter().any(|area| {
+        let (width, height) = (area.physical(size.0), area.physical(size.1));
+        let min = area.physical(MIN_VISIBLE);
+        let overlap = (x + width).min(area.right()) - x.max(area.x);
+        overlap >= min.min(width) && y >= area.y && y <= area.bottom() - min.min(height)
+    })
+}
+
+// 윈도우 전체가 작업 영역 안에 들어오는 위치 (윈도우가 더 크면 왼쪽 위에 맞춤)
+fn clamp_into(area: &Area, (x, y): (i32, i32), size: (f64, f64)) -> (i32, i32) {
+    let (width, height) = (area.physical(size.0), area.physical(size.1));
+    (
+        x.min(area.right() - width).max(area.x),
+        y.min(area.bottom() - height).max(area.y),
+    )
+}
+
+// 저장된 위치를 현재 모니터 구성에 맞춤
+// 충분히 보이면 그대로, 아니면 가장 가까운 모니터 안으로 옮긴 위치
+pub(crate) fn resolve(areas: &[Area], position: (i32, i32), size: (f64, f64)) -> (i32, i32) {
+    if is_visible(areas, position, size) {
+        return position;
+    }
+    match nearest(areas, position.0, position.1) {
+        Some(area) => clamp_into(area, position, size),
+        None => position,
+    }
+}
+
+// 새 윈도우 위치: 열려 있는 윈도우와 같은 자리에 겹치지 않도록 계단식으로 배치
+// 아래 끝에 닿으면 오른쪽으로 옮겨 다음 줄을 시작하고, 빈 자리가 없으면 첫 자리 사용
+pub(crate) fn cascade(area: &Area, occupied: &[(i32, i32)], size: (f64, f64)) -> (i32, i32) {
+    let (width, height) = (area.physical(size.0), area.physical(size.1));
+    let margin = area.physical(CASCADE_MARGIN);
+    let step = area.physical(CASCADE_STEP).max(1);
+    let rows = ((area.height - height - 2 * margin) / step).max(0) + 1;
+    let columns = ((area.width - width - 2 * margin) / (step * CASCADE_COLUMN_SHIFT))
+        .clamp(0, MAX_CASCADE_COLUMNS - 1)
+        + 1;
+
+    let candidate = |column: i32, row: i32| {
+        let x = area.x + margin + (row + column * CASCADE_COLUMN_SHIFT) * step;
+        let y = area.y + margin + row * step;
+        clamp_into(area, (x, y), size)
+    };
+    let is_free = |(x, y): (i32, i32)| {
+        !occupied
+            .iter()
+            .any(|&(ox, oy)| (ox - x).abs() < step / 2 && (oy - y).abs() < step / 2)
+    };
+
+    (0..columns)
+        .flat_map(|column| (0..rows).map(move |row| (column, row)))
+        .map(|(column, row)| candidate(column, row))
+        .find(|&position| is_free(position))
+        .unwrap_or_else(|| candidate(0, 0))
+}
+
+// 마우스 포인터가 있는 모니터 (알 수 없으면 주 모니터)
+fn pointer_area<'a>(app: &tauri::AppHandle, areas: &'a [Area]) -> Option<&'a Area> {
+    let point = app
+        .cursor_position()
+        .ok()
+        .map(|p| (p.x.round() as i32, p.y.round() as i32))
+        .or_else(|| {
+            app.primary_monitor()
+                .ok()
+                .flatten()
+                .map(|m| (m.position().x, m.position().y))
+        });
+    match point {
+        Some((x, y)) => nearest(areas, x, y),
+        None => areas.first(),
+    }
+}
+
+// 열려 있는 노트 윈도우의 위치
+pub(crate) fn note_window_positions(app: &tauri::AppHandle) -> Vec<(i32, i32)> {
+    app.webview_windows()
+        .into_iter()
+        .filter(|(label, _)| label.starts_with("note_"))
+        .filter(|(_, window)| window.is_visible().unwrap_or(false))
+        .filter_map(|(_, window)| window.outer_position().ok())
+        .map(|p| (p.x, p.y))
+        .collect()
+}
+
+// 노트 윈도우를 열 위치 (저장된 위치가 없으면 포인터가 있는 모니터에 계단식으로)
+pub(crate) fn note_position(
+    app: &tauri::AppHandle,
+    saved: Option<(i32, i32)>,
+    size: (f64, f64),
+) -> Option<(i32, i32)> {
+    let areas = monitor_areas(app);
+    match saved {
+        Some(position) => Some(resolve(&areas, position, size)),
+        None => {
+            let area = pointer_area(app, &areas)?;
+            Some(cascade(area, &note_window_positions(app), size))
+        }
+    }
+}
+
+// 윈도우를 물리 위치로 옮기고 논리 크기로 맞춤
+// (위치를 먼저 옮겨야 옮겨 간 모니터의 배율로 크기가 계산됨)
+pub(crate) fn apply(
+    window: &WebviewWindow,
+    position: Option<(i32, i32)>,
+    size: (f64, f64),
+) -> Result<(), String> {
+    if let Some((x, y)) = position {
+        window
+            .set_position(PhysicalPosition::new(x, y))
+            .map_err(|e| e.to_string())?;
+    }
+    window
+        .set_size(LogicalSize::new(size.0, size.1))
+        .map_err(|e| e.to_string())
+}
+
+// 저장된 위치를 확인해서 윈도우에 적용, 위치를 적용했는지 반환
+pub(crate) fn restore(
+    window: &WebviewWindow,
+    saved: Option<(i32, i32)>,
+    size: (f64, f64),
+) -> Result<bool, String> {
+    let areas = monitor_areas(window.app_handle());
+    let position = saved.map(|position| resolve(&areas, position, size));
+    apply(window, position, size)?;
+    Ok(position.is_some())
+}
+
+// 현재 윈도우의 위치(물리)와 크기(논리)
+pub(crate) fn geometry(window: &WebviewWindow) -> Result<((i32, i32), (f64, f64)), String> {
+    let scale = window.scale_factor().map_err(|e| e.to_string())?;
+    let position = window.outer_position().map_err(|e| e.to_string())?;
+    let size = window
+        .inner_size()
+        .map_err(|e| e.to_string())?
+        .to_logical::<f64>(scale);
+    Ok(((position.x, position.y), (size.width, size.height)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 왼쪽: 1920x1040 (배율 1), 오른쪽: 2560x1400 (배율 2)
+    const LEFT: Area = Area {
+        x: 0,
+        y: 0,
+        width: 1920,
+        height: 1040,
+        scale: 1.0,
+    };
+    const RIGHT: Area = Area {
+        x: 1920,
+        y: 0,
+        width: 2560,
+        height: 1400,
+        scale: 2.0,
+    };
+
+    #[test]
+    fn test_resolve_keeps_visible_position() {
+        let areas = [LEFT, RIGHT];
+        assert_eq!(resolve(&areas, (100, 100), (400.0, 400.0)), (100, 100));
+        // 오른쪽 모니터에 걸쳐 있어도 위쪽 가장자리가 보이면 그대로
+        assert_eq!(resolve(&areas, (1800, 200), (400.0, 400.0)), (1800, 200));
+        assert_eq!(resolve(&areas, (4300, 1200), (400.0, 400.0)), (4300, 1200));
+    }
+
+    #[test]
+    fn test_resolve_moves_back_from_unplugged_monitor() {
+        // 오른쪽 모니터를 분리한 뒤 그 위치에 저장된 노트
+        assert_eq!(resolve(&[LEFT], (3000, 500), (400.0, 400.0)), (1520, 500));
+        // 위쪽 가장자리가 화면 위로 벗어난 노트
+        assert_eq!(resolve(&[LEFT], (300, -200), (400.0, 400.0)), (300, 0));
+        // 작업 영역보다 큰 윈도우는 왼쪽 위에 맞춤
+        assert_eq!(resolve(&[LEFT], (-5000, 0), (3000.0, 400.0)), (0, 0));
+        // 배율 2 모니터에서는 물리 크기 800x800으로 계산
+        assert_eq!(resolve(&[RIGHT], (5000, 1500), (400.0, 400.0)), (3680, 600));
+        // 모니터 정보가 없으면 그대로
+        assert_eq!(resolve(&[], (3000, 500), (400.0, 400.0)), (3000, 500));
+    }
+
+    #[test]
+    fn test_cascade_skips_occupied_positions() {
+        let size = (400.0, 400.0);
+        assert_eq!(cascade(&LEFT, &[], size), (48, 48));
+        assert_eq!(cascade(&LEFT, &[(48, 48)], size), (80, 80));
+        // 정확히 같지 않아도 가까운 자리는 사용 중으로 봄
+        assert_eq!(cascade(&LEFT, &[(50, 45), (80, 80)], size), (112, 112));
+        // 배율 2 모니터에서는 간격도 물리 픽셀로 두 배
+        assert_eq!(cascade(&RIGHT, &[(2016, 96)], size), (2080, 160));
+    }
+
+    #[test]
+    fn test_cascade_starts_next_column_at_bottom() {
+        // 높이 600: 400 높이 노트는 한 줄에 4개 (y = 48, 80, 112, 144)
+        let area = Area {
+            height: 600,
+            ..LEFT
+        };
+        let column: Vec<(i32, i32)> = (0..4).map(|i| (48 + i * 32, 48 + i * 32)).collect();
+        assert_eq!(cascade(&area, &column, (400.0, 400.0)), (144, 48));
+
+        // 빈 자리가 없으면 첫 자리
+        let tiny = Area {
+            width: 400,
+            height: 400,
+            ..LEFT
+        };
+        assert_eq!(cascade(&tiny, &[(0, 0)], (400.0, 400.0)), (0, 0));
+    }
+}
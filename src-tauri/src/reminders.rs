@@ -0,0 +1,352 @@
+// 노트 리마인더 (마감 시각 알림)
+//
+// 백그라운드 스레드가 주기적으로 인덱스를 확인하여 시각이 지난 리마인더에 대해
+// 데스크톱 알림을 띄우고 해당 노트 윈도우를 앞으로 가져온다.
+
+use crate::{lock_index, open_note_window, read_index, write_index, NoteMetadata};
+use chrono::{DateTime, Datelike, Duration, Local, Months, NaiveDateTime, TimeZone, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+use tauri::{Emitter, Manager};
+use tauri_plugin_notification::NotificationExt;
+
+const CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(30);
+
+#[derive(Serialize, Deserialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum Recurrence {
+    Daily,
+    // 월~금
+    Weekdays,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+// 리마인더 스케줄러 시작
+pub fn start_scheduler(app: tauri::AppHandle) {
+    std::thread::spawn(move || loop {
+        if let Err(e) = fire_due_reminders(&app) {
+            println!("Reminder check failed: {}", e);
+        }
+        std::thread::sleep(CHECK_INTERVAL);
+    });
+}
+
+// 리마인더 설정 커맨드 (reminder_at이 None이면 해제)
+#[tauri::command]
+pub fn set_reminder(
+    app: tauri::AppHandle,
+    id: String,
+    reminder_at: Option<String>,
+    recurrence: Option<Recurrence>,
+) -> Result<NoteMetadata, String> {
+    if let Some(at) = &reminder_at {
+        DateTime::parse_from_rfc3339(at).map_err(|e| format!("Invalid reminder time: {}", e))?;
+    }
+
+    update_note(&app, &id, |note| {
+        note.reminder_anchor = recurrence.and(reminder_at.clone());
+        note.reminder_at = reminder_at;
+        note.reminder_recurrence = recurrence;
+        note.snoozed_until = None;
+        note.reminder_fired = false;
+    })
+}
+
+// 다시 알림 커맨드: 지금부터 minutes분 뒤에 다시 알림
+#[tauri::command]
+pub fn snooze_reminder(
+    app: tauri::AppHandle,
+    id: String,
+    minutes: u32,
+) -> Result<NoteMetadata, String> {
+    let until = Utc::now() + Duration::minutes(minutes as i64);
+
+    update_note(&app, &id, |note| {
+        note.snoozed_until = Some(until.to_rfc3339());
+        note.reminder_fired = false;
+    })
+}
+
+// 알림 확인 커맨드: 반복 리마인더는 다음 회차로, 아니면 해제
+#[tauri::command]
+pub fn dismiss_reminder(app: tauri::AppHandle, id: String) -> Result<NoteMetadata, String> {
+    let now = Utc::now();
+
+    update_note(&app, &id, |note| {
+        // 기준 시각이 없는 기존 리마인더는 현재 예정 시각을 기준으로 삼음
+        let anchor = parse_time(note.reminder_anchor.as_deref())
+            .or_else(|| parse_time(note.reminder_at.as_deref()));
+        let next = match (anchor, note.reminder_recurrence) {
+            (Some(anchor), Some(recurrence)) => {
+                Some(next_occurrence_after(anchor, recurrence, now, &Local))
+            }
+            _ => None,
+        };
+        if next.is_none() {
+            note.reminder_anchor = None;
+        } else if note.reminder_anchor.is_none() {
+            note.reminder_anchor = note.reminder_at.clone();
+        }
+        note.reminder_at = next.map(|t| t.to_rfc3339());
+        note.snoozed_until = None;
+        note.reminder_fired = false;
+    })
+}
+
+fn update_note<F>(app: &tauri::AppHandle, id: &str, apply: F) -> Result<NoteMetadata, String>
+where
+    F: FnOnce(&mut NoteMetadata),
+{
+    let updated = {
+        let _guard = lock_index();
+        let mut index = read_index()?;
+        let note = index
+            .notes
+            .iter_mut()
+            .find(|n| n.id == id)
+            .ok_or("Note not found")?;
+        apply(note);
+        let updated = note.clone();
+        write_index(&index)?;
+        updated
+    };
+
+    // 목록 갱신 이벤트 발행
+    let _ = app.emit("refresh-notes-list", ());
+
+    Ok(updated)
+}
+
+// 시각이 지난 리마인더 알림 표시
+fn fire_due_reminders(app: &tauri::AppHandle) -> Result<(), String> {
+    let now = Utc::now();
+    let due = {
+        // 알림 표시 중에 다른 곳에서 바뀐 내용을 덮어쓰지 않도록 잠금 안에서 읽고 바로 기록
+        let _guard = lock_index();
+        let mut index = read_index()?;
+        let due = mark_due_fired(&mut index.notes, now);
+        if due.is_empty() {
+            return Ok(());
+        }
+        write_index(&index)?;
+        due
+    };
+
+    for note in due {
+        println!("Reminder fired for note: {}", note.id);
+        crate::hooks::fire(crate::hooks::HookEvent::ReminderFired, &note, None);
+
+        if let Err(e) = app
+            .notification()
+            .builder()
+            .title(&note.title)
+            .body(reminder_body(&note))
+            .show()
+        {
+            println!("Failed to show notification: {}", e);
+        }
+
+        // 노트 윈도우를 열고 앞으로 가져오기
+        let app_handle = app.clone();
+        tauri::async_runtime::spawn(async move {
+            if open_note_window(app_handle.clone(), note.id.clone())
+                .await
+                .is_ok()
+            {
+                if let Some(window) = app_handle.get_webview_window(&format!("note_{}", note.id)) {
+                    let _ = window.unminimize();
+                    let _ = window.show();
+                    let _ = window.set_focus();
+                }
+            }
+            let _ = app_handle.emit("reminder-fired", note.id);
+        });
+    }
+
+    let _ = app.emit("refresh-notes-list", ());
+    Ok(())
+}
+
+// 시각이 지난 노트의 reminder_fired만 켜고 해당 노트 반환
+fn mark_due_fired(notes: &mut [NoteMetadata], now: DateTime<Utc>) -> Vec<NoteMetadata> {
+    notes
+        .iter_mut()
+        .filter(|note| is_due(note, now))
+        .map(|note| {
+            note.reminder_fired = true;
+            note.clone()
+        })
+        .collect()
+}
+
+fn reminder_body(note: &NoteMetadata) -> String {
+    match parse_time(note.reminder_at.as_deref()) {
+        Some(at) => format!(
+            "Reminder: {}",
+            at.with_timezone(&Local).format("%Y-%m-%d %H:%M")
+        ),
+        None => "Reminder".to_string(),
+    }
+}
+
+fn parse_time(value: Option<&str>) -> Option<DateTime<Utc>> {
+    value
+        .and_then(|v| DateTime::parse_from_rfc3339(v).ok())
+        .map(|t| t.with_timezone(&Utc))
+}
+
+// 알림을 띄워야 하는지 확인 (다시 알림 시각이 있으면 우선)
+fn is_due(note: &NoteMetadata, now: DateTime<Utc>) -> bool {
+    // 보관된 노트는 알리지 않음
+    if note.reminder_fired || note.archived {
+        return false;
+    }
+    let due = parse_time(note.snoozed_until.as_deref())
+        .or_else(|| parse_time(note.reminder_at.as_deref()));
+    matches!(due, Some(at) if at <= now)
+}
+
+// 반복 규칙에 따라 now 이후의 첫 회차 계산 (놓친 회차는 건너뜀)
+// 월/연 반복은 anchor + n개월로 계산하여 말일 조정(1/31 -> 2/29)이 다음 회차에 이어지지 않게 함
+fn next_occurrence_after<Tz: TimeZone>(
+    anchor: DateTime<Utc>,
+    recurrence: Recurrence,
+    now: DateTime<Utc>,
+    tz: &Tz,
+) -> DateTime<Utc> {
+    // 일광 절약 시간 변경에도 같은 현지 시각을 유지하도록 현지 시간 기준으로 계산
+    let start = anchor.with_timezone(tz).naive_local();
+    let mut local = start;
+    let mut next = anchor;
+    let mut count = 0;
+
+    while next <= now {
+        count += 1;
+        local = match recurrence {
+            Recurrence::Monthly => add_months(start, count),
+            Recurrence::Yearly => add_months(start, count * 12),
+            _ => step(local, recurrence),
+        };
+        next = match tz.from_local_datetime(&local).earliest() {
+            Some(t) => t.with_timezone(&Utc),
+            // 존재하지 않는 현지 시각(DST 전환)은 한 시간 뒤로
+            None => tz
+                .from_local_datetime(&(local + Duration::hours(1)))
+                .earliest()
+                .map(|t| t.with_timezone(&Utc))
+                .unwrap_or(now + Duration::minutes(1)),
+        };
+    }
+    next
+}
+
+// 개월 수 더하기 (없는 날짜는 그 달의 말일로)
+fn add_months(local: NaiveDateTime, months: u32) -> NaiveDateTime {
+    local
+        .checked_add_months(Months::new(months))
+        .unwrap_or(local + Duration::days(30 * months as i64))
+}
+
+fn step(local: NaiveDateTime, recurrence: Recurrence) -> NaiveDateTime {
+    match recurrence {
+        Recurrence::Daily => local + Duration::days(1),
+        Recurrence::Weekdays => {
+            let days = match local.weekday() {
+                Weekday::Fri => 3,
+                Weekday::Sat => 2,
+                _ => 1,
+            };
+            local + Duration::days(days)
+        }
+        Recurrence::Weekly => local + Duration::weeks(1),
+        Recurrence::Monthly => add_months(local, 1),
+        Recurrence::Yearly => add_months(local, 12),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn note_with(reminder_at: &str, snoozed_until: Option<&str>, fired: bool) -> NoteMetadata {
+        NoteMetadata {
+            id: "n".to_string(),
+            reminder_at: Some(reminder_at.to_string()),
+            snoozed_until: snoozed_until.map(str::to_string),
+            reminder_fired: fired,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_is_due_respects_snooze_and_fired() {
+        let now = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+
+        assert!(is_due(&note_with("2024-03-01T11:00:00Z", None, false), now));
+        assert!(!is_due(
+            &note_with("2024-03-01T13:00:00Z", None, false),
+            now
+        ));
+        assert!(!is_due(&note_with("2024-03-01T11:00:00Z", None, true), now));
+        assert!(!is_due(
+            &note_with("2024-03-01T11:00:00Z", Some("2024-03-01T12:10:00Z"), false),
+            now
+        ));
+    }
+
+    #[test]
+    fn test_mark_due_fired_only_touches_due_notes() {
+        let now = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+        let mut notes = vec![
+            note_with("2024-03-01T11:00:00Z", None, false),
+            note_with("2024-03-01T13:00:00Z", None, false),
+        ];
+        notes[1].id = "later".to_string();
+
+        let due = mark_due_fired(&mut notes, now);
+
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].id, "n");
+        assert!(notes[0].reminder_fired);
+        assert!(!notes[1].reminder_fired);
+    }
+
+    #[test]
+    fn test_next_occurrence_skips_missed() {
+        let at = Utc.with_ymd_and_hms(2024, 1, 31, 9, 0, 0).unwrap();
+        let now = Utc.with_ymd_and_hms(2024, 2, 3, 0, 0, 0).unwrap();
+
+        let daily = next_occurrence_after(at, Recurrence::Daily, now, &Utc);
+        assert_eq!(daily, Utc.with_ymd_and_hms(2024, 2, 3, 9, 0, 0).unwrap());
+
+        // 1월 31일 + 1개월은 2월 말일로 조정됨
+        let monthly = next_occurrence_after(at, Recurrence::Monthly, now, &Utc);
+        assert_eq!(monthly, Utc.with_ymd_and_hms(2024, 2, 29, 9, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_monthly_and_yearly_follow_anchor() {
+        let anchor = Utc.with_ymd_and_hms(2024, 1, 31, 9, 0, 0).unwrap();
+
+        // 2월 말일로 조정된 뒤에도 3월에는 다시 31일
+        let now = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+        let monthly = next_occurrence_after(anchor, Recurrence::Monthly, now, &Utc);
+        assert_eq!(monthly, Utc.with_ymd_and_hms(2024, 3, 31, 9, 0, 0).unwrap());
+
+        // 2월 29일의 연 반복은 평년에 28일, 윤년에 다시 29일
+        let leap = Utc.with_ymd_and_hms(2024, 2, 29, 9, 0, 0).unwrap();
+        let now = Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap();
+        let yearly = next_occurrence_after(leap, Recurrence::Yearly, now, &Utc);
+        assert_eq!(yearly, Utc.with_ymd_and_hms(2026, 2, 28, 9, 0, 0).unwrap());
+        let now = Utc.with_ymd_and_hms(2027, 3, 1, 0, 0, 0).unwrap();
+        let yearly = next_occurrence_after(leap, Recurrence::Yearly, now, &Utc);
+        assert_eq!(yearly, Utc.with_ymd_and_hms(2028, 2, 29, 9, 0, 0).unwrap());
+
+        // 현지 시간대 기준 (UTC+9의 1월 31일 18시)
+        let seoul = chrono::FixedOffset::east_opt(9 * 3600).unwrap();
+        let now = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+        let monthly = next_occurrence_after(anchor, Recurrence::Monthly, now, &seoul);
+        assert_eq!(monthly, Utc.with_ymd_and_hms(2024, 3, 31, 9, 0, 0).unwrap());
+    }
+}
@@ -0,0 +1,284 @@
+// 앱 설정 (저장소 루트의 settings.json)
+
+use crate::get_storage_dir;
+use crate::hooks::HookEvent;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use tauri::Emitter;
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AppSettings {
+    pub api: ApiSettings,
+    pub backup: BackupSettings,
+    pub git: GitSettings,
+    // 노트 이벤트 훅 (설정 순서대로 실행)
+    pub hooks: Vec<HookSettings>,
+    pub notes: NoteSettings,
+    pub security: SecuritySettings,
+    pub sync: SyncSettings,
+    pub windows: WindowSettings,
+}
+
+// 노트 이벤트 훅 설정
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct HookSettings {
+    pub name: String,
+    pub event: HookEvent,
+    // 실행 파일 경로 (셸을 거치지 않고 실행)
+    pub command: String,
+    pub args: Vec<String>,
+    pub enabled: bool,
+    // 이 시간(초)이 지나면 종료
+    pub timeout_seconds: u32,
+}
+
+impl Default for HookSettings {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            event: HookEvent::default(),
+            command: String::new(),
+            args: Vec::new(),
+            enabled: true,
+            timeout_seconds: 10,
+        }
+    }
+}
+
+// 로컬 HTTP API 설정
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ApiSettings {
+    pub enabled: bool,
+    // 127.0.0.1에서 요청을 받을 포트
+    pub port: u16,
+    // 요청에 필요한 Bearer 토큰, 없으면 서버 시작 시 생성
+    pub token: Option<String>,
+}
+
+impl Default for ApiSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: 27183,
+            token: None,
+        }
+    }
+}
+
+// 동기화 설정
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SyncSettings {
+    pub backend: SyncBackend,
+    // 동기화 폴더 (Dropbox, Syncthing, NAS 등), 없으면 동기화 안 함
+    pub folder: Option<String>,
+    pub webdav: WebDavSettings,
+    // 자동 동기화 주기 (분), 0이면 수동 동기화만
+    pub interval_minutes: u32,
+}
+
+impl Default for SyncSettings {
+    fn default() -> Self {
+        Self {
+            backend: SyncBackend::Folder,
+            folder: None,
+            webdav: WebDavSettings::default(),
+            interval_minutes: 15,
+        }
+    }
+}
+
+// 동기화 대상 종류
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncBackend {
+    #[default]
+    Folder,
+    #[serde(rename = "webdav")]
+    WebDav,
+}
+
+// WebDAV 서버 설정 (Nextcloud, ownCloud, NAS 등)
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct WebDavSettings {
+    // 노트를 저장할 폴더의 URL, 없으면 동기화 안 함
+    pub url: Option<String>,
+    pub username: Option<String>,
+    pub password: Option<String>,
+}
+
+// git 저장소 모드 설정
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct GitSettings {
+    // 저장소 루트를 git 저장소로 관리
+    pub enabled: bool,
+    // pull/push할 원격 저장소 주소
+    pub remote: Option<String>,
+    // 마지막 저장 후 이 시간(초) 동안 저장이 없으면 커밋
+    pub commit_delay_seconds: u32,
+}
+
+impl Default for GitSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            remote: None,
+            commit_delay_seconds: 30,
+        }
+    }
+}
+
+// 노트 윈도우 배치 설정
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct WindowSettings {
+    // 드래그가 끝나면 화면 가장자리나 다른 노트에 붙이기
+    pub snap: bool,
+    // 이 거리(논리 픽셀) 안에 있는 가장자리에 붙임
+    pub snap_distance: u32,
+    // 정렬할 때와 붙일 때 윈도우 사이 간격 (논리 픽셀)
+    pub gap: u32,
+}
+
+impl Default for WindowSettings {
+    fn default() -> Self {
+        Self {
+            snap: true,
+            snap_distance: 16,
+            gap: 8,
+        }
+    }
+}
+
+// 암호화된 노트 설정
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SecuritySettings {
+    // 잠금 해제한 노트를 사용하지 않을 때 자동으로 잠그는 시간 (분), 0이면 자동 잠금 안 함
+    pub auto_lock_minutes: u32,
+}
+
+impl Default for SecuritySettings {
+    fn default() -> Self {
+        Self {
+            auto_lock_minutes: 5,
+        }
+    }
+}
+
+// 노트 파일 관리 설정
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoteSettings {
+    // 노트 폴더 안의 노트를 노트북(그룹)별 하위 폴더로 정리
+    pub group_folders: bool,
+    // 제목이 바뀌면 노트 폴더 안의 파일 이름도 제목에 맞춰 변경
+    pub rename_on_title_change: bool,
+    pub daily: DailySettings,
+}
+
+// 데일리 노트 설정
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DailySettings {
+    // 노트 폴더 기준 하위 폴더
+    pub folder: String,
+    // 파일 이름 형식 (chrono 형식 문자열, 확장자 제외)
+    pub file_format: String,
+    // 새 데일리 노트에 사용할 템플릿 이름, 없으면 날짜 제목만 작성
+    pub template: Option<String>,
+}
+
+impl Default for DailySettings {
+    fn default() -> Self {
+        Self {
+            folder: "daily".to_string(),
+            file_format: "%Y-%m-%d".to_string(),
+            template: None,
+        }
+    }
+}
+
+// 자동 백업 설정
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct BackupSettings {
+    pub enabled: bool,
+    // 백업 주기 (시간 단위)
+    pub interval_hours: u32,
+    // 백업 저장 위치, 없으면 저장소 루트의 backups 폴더
+    pub directory: Option<String>,
+    // 보관할 일별/주별 백업 개수
+    pub keep_daily: usize,
+    pub keep_weekly: usize,
+}
+
+impl Default for BackupSettings {
+    fn default() -> Self {
+        Self {
+            // 자동 백업은 사용자가 켠 경우에만 실행
+            enabled: false,
+            interval_hours: 24,
+            directory: None,
+            keep_daily: 7,
+            keep_weekly: 4,
+        }
+    }
+}
+
+fn get_settings_path() -> Result<PathBuf, String> {
+    let storage_dir = get_storage_dir()?;
+    if !storage_dir.exists() {
+        fs::create_dir_all(&storage_dir).map_err(|e| e.to_string())?;
+    }
+    Ok(storage_dir.join("settings.json"))
+}
+
+// 설정 파일 읽기 (없거나 손상된 경우 기본값)
+pub fn read_settings() -> Result<AppSettings, String> {
+    let path = get_settings_path()?;
+    if !path.exists() {
+        return Ok(AppSettings::default());
+    }
+
+    let content = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    match serde_json::from_str(&content) {
+        Ok(settings) => Ok(settings),
+        Err(e) => {
+            println!(
+                "Warning: Failed to parse settings.json ({}), using defaults",
+                e
+            );
+            Ok(AppSettings::default())
+        }
+    }
+}
+
+pub fn write_settings(settings: &AppSettings) -> Result<(), String> {
+    let path = get_settings_path()?;
+    let content = serde_json::to_string_pretty(settings).map_err(|e| e.to_string())?;
+    fs::write(&path, content).map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+// 설정 조회 커맨드
+#[tauri::command]
+pub fn get_settings() -> Result<AppSettings, String> {
+    read_settings()
+}
+
+// 설정 저장 커맨드
+#[tauri::command]
+pub fn update_settings(app: tauri::AppHandle, settings: AppSettings) -> Result<(), String> {
+    write_settings(&settings)?;
+
+    // 이벤트 발행: 설정이 변경되었음을 알림
+    let _ = app.emit("settings-changed", ());
+    Ok(())
+}
@@ -0,0 +1,296 @@
+// 라이브러리 통계 (목록 윈도우의 대시보드용)
+
+use crate::{extract_tags, get_notes_dir, read_file, read_index, NoteMetadata};
+use chrono::{DateTime, Local, NaiveDate};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+const DEFAULT_STALE_DAYS: i64 = 90;
+const DEFAULT_LIMIT: usize = 5;
+
+#[derive(Serialize)]
+pub struct CountEntry {
+    // 그룹 id / 태그 / 색상 (그룹 없음, 기본색은 None)
+    key: Option<String>,
+    label: String,
+    count: usize,
+}
+
+#[derive(Serialize, Clone)]
+pub struct NoteStat {
+    id: String,
+    title: String,
+    words: usize,
+    chars: usize,
+    bytes: u64,
+    created_at: String,
+    updated_at: String,
+}
+
+#[derive(Serialize, Default)]
+pub struct DayActivity {
+    // 현지 날짜 (YYYY-MM-DD)
+    date: String,
+    created: usize,
+    edited: usize,
+}
+
+#[derive(Serialize, Default)]
+pub struct StorageStats {
+    notes_bytes: u64,
+    images_bytes: u64,
+    image_count: usize,
+}
+
+#[derive(Serialize)]
+pub struct LibraryStats {
+    total_notes: usize,
+    total_words: usize,
+    total_chars: usize,
+    by_group: Vec<CountEntry>,
+    by_tag: Vec<CountEntry>,
+    by_color: Vec<CountEntry>,
+    notes: Vec<NoteStat>,
+    // 날짜 오름차순
+    activity: Vec<DayActivity>,
+    storage: StorageStats,
+    largest: Vec<NoteStat>,
+    oldest: Vec<NoteStat>,
+    // stale_days일 이상 수정되지 않은 노트 (오래된 순)
+    stale: Vec<NoteStat>,
+}
+
+// 통계 조회 커맨드
+#[tauri::command]
+pub fn get_stats(stale_days: Option<i64>, limit: Option<usize>) -> Result<LibraryStats, String> {
+    let index = read_index()?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let stale_before =
+        chrono::Utc::now() - chrono::Duration::days(stale_days.unwrap_or(DEFAULT_STALE_DAYS));
+
+    let mut notes = Vec::new();
+    let mut tag_counts: BTreeMap<String, usize> = BTreeMap::new();
+    let mut storage = StorageStats::default();
+
+    for note in &index.notes {
+        // 파일을 읽을 수 없거나 암호화된 노트는 인덱스에 저장된 통계 사용
+        let (words, chars, bytes) = match read_file(Path::new(&note.file_path)) {
+            Ok(data) if note.encrypted => (note.word_count, note.char_count, data.len() as u64),
+            Ok(data) => {
+                let content = String::from_utf8_lossy(&data);
+                for tag in extract_tags(&content) {
+                    *tag_counts.entry(tag).or_default() += 1;
+                }
+                (
+                    content.split_whitespace().count(),
+                    content.chars().count(),
+                    data.len() as u64,
+                )
+            }
+            Err(_) => (note.word_count, note.char_count, 0),
+        };
+        storage.notes_bytes += bytes;
+
+        notes.push(NoteStat {
+            id: note.id.clone(),
+            title: note.title.clone(),
+            words,
+            chars,
+            bytes,
+            created_at: note.created_at.clone(),
+            updated_at: note.updated_at.clone(),
+        });
+    }
+
+    let images_dir = get_notes_dir()?.join("images");
+    let (images_bytes, image_count) = dir_size(&images_dir);
+    storage.images_bytes = images_bytes;
+    storage.image_count = image_count;
+
+    let mut largest = notes.clone();
+    largest.sort_by_key(|n| std::cmp::Reverse(n.chars));
+    largest.truncate(limit);
+
+    // 시각을 해석할 수 없는 노트는 제외
+    let mut oldest: Vec<NoteStat> = notes
+        .iter()
+        .filter(|n| parse_time(&n.created_at).is_some())
+        .cloned()
+        .collect();
+    oldest.sort_by_key(|n| parse_time(&n.created_at));
+    oldest.truncate(limit);
+
+    let mut stale: Vec<NoteStat> = notes
+        .iter()
+        .filter(|n| parse_time(&n.updated_at).is_some_and(|t| t < stale_before))
+        .cloned()
+        .collect();
+    stale.sort_by_key(|n| parse_time(&n.updated_at));
+    stale.truncate(limit);
+
+    Ok(LibraryStats {
+        total_notes: notes.len(),
+        total_words: notes.iter().map(|n| n.words).sum(),
+        total_chars: notes.iter().map(|n| n.chars).sum(),
+        by_group: count_by_group(&index.notes, &index.groups),
+        by_tag: sorted_counts(
+            tag_counts
+                .into_iter()
+                .map(|(tag, count)| (Some(tag.clone()), format!("#{}", tag), count))
+                .collect(),
+        ),
+        by_color: count_by(&index.notes, |n| n.color.clone(), "Default"),
+        activity: activity_by_day(&index.notes),
+        notes,
+        storage,
+        largest,
+        oldest,
+        stale,
+    })
+}
+
+fn parse_time(value: &str) -> Option<DateTime<chrono::Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|t| t.with_timezone(&chrono::Utc))
+}
+
+fn local_date(value: &str) -> Option<NaiveDate> {
+    parse_time(value).map(|t| t.with_timezone(&Local).date_naive())
+}
+
+// 그룹별 노트 수 (그룹 표시 순서대로, 그룹 없음은 마지막)
+fn count_by_group(notes: &[NoteMetadata], groups: &[crate::groups::NoteGroup]) -> Vec<CountEntry> {
+    let mut entries: Vec<CountEntry> = groups
+        .iter()
+        .map(|g| CountEntry {
+            key: Some(g.id.clone()),
+            label: g.name.clone(),
+            count: notes
+                .iter()
+                .filter(|n| n.group_id.as_deref() == Some(g.id.as_str()))
+                .count(),
+        })
+        .collect();
+
+    // 삭제된 그룹을 가리키는 노트도 그룹 없음으로 집계
+    let ungrouped = notes
+        .iter()
+        .filter(|n| {
+            n.group_id
+                .as_ref()
+                .is_none_or(|id| !groups.iter().any(|g| &g.id == id))
+        })
+        .count();
+    if ungrouped > 0 {
+        entries.push(CountEntry {
+            key: None,
+            label: "Ungrouped".to_string(),
+            count: ungrouped,
+        });
+    }
+    entries
+}
+
+fn count_by<F>(notes: &[NoteMetadata], key_of: F, none_label: &str) -> Vec<CountEntry>
+where
+    F: Fn(&NoteMetadata) -> Option<String>,
+{
+    let mut counts: BTreeMap<Option<String>, usize> = BTreeMap::new();
+    for note in notes {
+        *counts.entry(key_of(note)).or_default() += 1;
+    }
+
+    sorted_counts(
+        counts
+            .into_iter()
+            .map(|(key, count)| {
+                let label = key.clone().unwrap_or_else(|| none_label.to_string());
+                (key, label, count)
+            })
+            .collect(),
+    )
+}
+
+// 많은 순, 같으면 이름 순
+fn sorted_counts(mut counts: Vec<(Option<String>, String, usize)>) -> Vec<CountEntry> {
+    counts.sort_by(|a, b| b.2.cmp(&a.2).then_with(|| a.1.cmp(&b.1)));
+    counts
+        .into_iter()
+        .map(|(key, label, count)| CountEntry { key, label, count })
+        .collect()
+}
+
+// 날짜별 생성/수정 노트 수 (버전 기록이 없으므로 created_at / updated_at 기준)
+fn activity_by_day(notes: &[NoteMetadata]) -> Vec<DayActivity> {
+    let mut days: BTreeMap<NaiveDate, DayActivity> = BTreeMap::new();
+
+    for note in notes {
+        let created = local_date(&note.created_at);
+        if let Some(date) = created {
+            days.entry(date).or_default().created += 1;
+        }
+        // 생성 직후의 updated_at은 편집으로 세지 않음
+        if let Some(date) = local_date(&note.updated_at) {
+            if note.updated_at != note.created_at {
+                days.entry(date).or_default().edited += 1;
+            }
+        }
+    }
+
+    days.into_iter()
+        .map(|(date, mut activity)| {
+            activity.date = date.format("%Y-%m-%d").to_string();
+            activity
+        })
+        .collect()
+}
+
+// 폴더 안 파일의 전체 크기와 개수 (하위 폴더 포함)
+fn dir_size(dir: &Path) -> (u64, usize) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return (0, 0);
+    };
+
+    let mut total = (0, 0);
+    for entry in entries.flatten() {
+        let Ok(meta) = entry.metadata() else {
+            continue;
+        };
+        if meta.is_dir() {
+            let (bytes, count) = dir_size(&entry.path());
+            total.0 += bytes;
+            total.1 += count;
+        } else {
+            total.0 += meta.len();
+            total.1 += 1;
+        }
+    }
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_activity_by_day_skips_unedited() {
+        let note = |created_at: &str, updated_at: &str| NoteMetadata {
+            created_at: created_at.to_string(),
+            updated_at: updated_at.to_string(),
+            ..Default::default()
+        };
+        let notes = vec![
+            note("2024-05-01T12:00:00Z", "2024-05-01T12:00:00Z"),
+            note("2024-05-01T12:00:00Z", "2024-05-03T12:00:00Z"),
+            note("invalid", "2024-05-03T13:00:00Z"),
+        ];
+
+        let activity = activity_by_day(&notes);
+        let summary: Vec<(usize, usize)> = activity.iter().map(|d| (d.created, d.edited)).collect();
+        assert_eq!(summary, vec![(2, 0), (0, 2)]);
+        assert_eq!(activity[0].date.len(), 10);
+    }
+}
@@ -0,0 +1,1134 @@
+// 노트 동기화 (일반 폴더: Dropbox, Syncthing, NAS 등 / WebDAV)
+//
+// 동기화 대상 구조:
+// - index.json     : 노트별 메타데이터 + revision(업로드할 때마다 새로 발급) + hash + ETag,
+//                    삭제된 노트 기록(tombstone)
+// - notes/<파일명> : 노트 본문
+// - images/<파일명>: 노트 폴더의 images (한쪽에만 있는 파일을 복사)
+//
+// 로컬의 sync_state.json에 노트별로 마지막 동기화 시점의 revision과 hash를 저장하고
+// 이를 기준으로 로컬 변경과 원격 변경을 구분한다. 양쪽 모두 바뀐 노트는 원격 내용을
+// 충돌 사본으로 받고 로컬 내용을 새 revision으로 올린다. 인덱스는 파일 단위가 아니라
+// 노트 id 단위로 병합하며, index.json은 ETag가 같을 때만 덮어쓰고 로컬 파일은 그 뒤에 바꾼다.
+
+use crate::settings::{read_settings, SyncBackend, SyncSettings};
+use crate::webdav::WebDavRemote;
+use crate::{
+    content_hash, extract_title, get_notes_dir, get_storage_dir, lock_index, read_file, read_index,
+    refresh_content_stats, unique_name, write_atomic, write_index, NoteMetadata, NotesIndex,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{Emitter, Manager};
+
+const STATE_FILE: &str = "sync_state.json";
+const REMOTE_INDEX: &str = "index.json";
+const CHECK_INTERVAL: Duration = Duration::from_secs(60);
+// 다른 기기가 동시에 index.json을 바꾼 경우 다시 시도하는 횟수
+const MAX_ATTEMPTS: usize = 3;
+// 삭제 기록 보관 기간
+const TOMBSTONE_DAYS: i64 = 90;
+
+// index.json을 덮어쓰는 사이에 다른 기기가 먼저 바꾼 경우의 오류
+pub(crate) const PRECONDITION_FAILED: &str = "Remote index changed during sync";
+
+// 수동 동기화와 예약 동기화가 동시에 실행되지 않도록 잠금
+static SYNC_LOCK: Mutex<()> = Mutex::new(());
+
+// 동기화 대상 저장소. 경로는 "index.json", "notes/<파일명>" 같은 상대 경로
+pub(crate) trait Remote {
+    // 파일 읽기: (내용, ETag), 없으면 None
+    fn get(&mut self, path: &str) -> Result<Option<(Vec<u8>, String)>, String>;
+    // 파일 쓰기: 새 ETag 반환, 조건이 맞지 않으면 PRECONDITION_FAILED
+    fn put(&mut self, path: &str, data: &[u8], condition: Condition) -> Result<String, String>;
+    // 파일 삭제 (없어도 성공)
+    fn delete(&mut self, path: &str) -> Result<(), String>;
+    // 폴더 안의 파일 이름 -> ETag (폴더가 없으면 만들고 빈 목록)
+    fn list(&mut self, dir: &str) -> Result<BTreeMap<String, String>, String>;
+}
+
+// 쓰기 조건
+#[derive(Clone, Copy)]
+pub(crate) enum Condition<'a> {
+    Always,
+    // 현재 ETag가 같을 때만
+    Matches(&'a str),
+    // 파일이 없을 때만
+    Missing,
+}
+
+// 동기화 대상의 index.json
+#[derive(Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct RemoteIndex {
+    notes: Vec<RemoteNote>,
+    #[serde(default)]
+    groups: Vec<crate::groups::NoteGroup>,
+    #[serde(default)]
+    deleted: Vec<Tombstone>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct RemoteNote {
+    // file_path는 동기화 대상 기준 상대 경로 ("notes/<파일명>")
+    #[serde(flatten)]
+    note: NoteMetadata,
+    revision: String,
+    hash: String,
+    // 마지막으로 올렸을 때의 파일 ETag (다르면 서버/폴더에서 직접 수정된 것)
+    #[serde(default)]
+    etag: Option<String>,
+}
+
+// 삭제된 노트 기록 (다른 기기에서도 삭제하도록)
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct Tombstone {
+    id: String,
+    deleted_at: String,
+}
+
+// 마지막 동기화 시점의 노트 상태
+#[derive(Serialize, Deserialize, Clone)]
+struct SyncedNote {
+    revision: String,
+    hash: String,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct SyncState {
+    // 동기화 대상(폴더 경로 또는 WebDAV URL)이 바뀌면 기록을 초기화
+    folder: String,
+    notes: BTreeMap<String, SyncedNote>,
+    last_synced_at: Option<String>,
+}
+
+#[derive(Serialize, Default, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncReport {
+    uploaded: usize,
+    downloaded: usize,
+    deleted_local: usize,
+    deleted_remote: usize,
+    conflicts: usize,
+    // 파일이 없거나 아직 동기화 도구가 내려받지 않은 노트
+    skipped: usize,
+    #[serde(skip)]
+    changed_ids: Vec<String>,
+    #[serde(skip)]
+    deleted_ids: Vec<String>,
+}
+
+// 원격 index.json을 저장한 뒤에 적용할 로컬 파일 변경
+enum LocalChange {
+    Write(PathBuf, String),
+    Remove(PathBuf),
+}
+
+// 동기화 대상 메타데이터 (창 크기, 다시 알림 상태 등 기기별 정보는 제외)
+#[derive(Serialize)]
+struct SyncedFields<'a> {
+    title: &'a str,
+    created_at: &'a str,
+    color: &'a Option<String>,
+    pinned: bool,
+    reminder_at: &'a Option<String>,
+    reminder_recurrence: &'a Option<crate::reminders::Recurrence>,
+    // 없으면 생략 (기준 시각이 없는 기존 노트의 해시가 바뀌지 않도록)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reminder_anchor: Option<&'a str>,
+    group_id: &'a Option<String>,
+    archived: bool,
+    archived_at: &'a Option<String>,
+    encrypted: bool,
+}
+
+// 본문과 동기화 대상 메타데이터의 해시
+fn fingerprint(note: &NoteMetadata, content: &str) -> String {
+    let fields = SyncedFields {
+        title: &note.title,
+        created_at: &note.created_at,
+        color: &note.color,
+        pinned: note.pinned,
+        reminder_at: &note.reminder_at,
+        reminder_recurrence: &note.reminder_recurrence,
+        reminder_anchor: note.reminder_anchor.as_deref(),
+        group_id: &note.group_id,
+        archived: note.archived,
+        archived_at: &note.archived_at,
+        encrypted: note.encrypted,
+    };
+    let fields = serde_json::to_string(&fields).unwrap_or_default();
+    content_hash(&format!("{}\n{}", fields, content))
+}
+
+// 원격 노트의 동기화 대상 메타데이터를 로컬 노트에 반영
+fn apply_remote(local: &mut NoteMetadata, remote: &NoteMetadata, content: &str) {
+    local.title = remote.title.clone();
+    local.created_at = remote.created_at.clone();
+    local.updated_at = remote.updated_at.clone();
+    local.color = remote.color.clone();
+    local.pinned = remote.pinned;
+    local.reminder_at = remote.reminder_at.clone();
+    local.reminder_recurrence = remote.reminder_recurrence;
+    local.reminder_anchor = remote.reminder_anchor.clone();
+    local.group_id = remote.group_id.clone();
+    local.archived = remote.archived;
+    local.archived_at = remote.archived_at.clone();
+    local.encrypted = remote.encrypted;
+
+    if local.encrypted {
+        crate::encryption::hide_content_stats(local, content);
+    } else {
+        refresh_content_stats(local, content);
+    }
+}
+
+// 경로에서 파일 이름만 사용 (동기화 대상 밖 접근 방지)
+fn file_name_of(path: &str) -> Option<String> {
+    Path::new(path)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+}
+
+fn read_text(path: &Path) -> Option<String> {
+    read_file(path)
+        .ok()
+        .map(|bytes| String::from_utf8_lossy(&bytes).to_string())
+}
+
+// 원격 노트 본문 읽기, 파일이 없으면 None
+fn fetch(remote: &mut dyn Remote, entry: &RemoteNote) -> Result<Option<String>, String> {
+    let Some(name) = file_name_of(&entry.note.file_path) else {
+        return Ok(None);
+    };
+    Ok(remote
+        .get(&format!("notes/{}", name))?
+        .map(|(bytes, _)| String::from_utf8_lossy(&bytes).to_string()))
+}
+
+fn device_name() -> String {
+    std::env::var("COMPUTERNAME")
+        .or_else(|_| std::env::var("HOSTNAME"))
+        .unwrap_or_else(|_| "another device".to_string())
+}
+
+// 로컬 인덱스와 동기화 대상을 노트 id 단위로 동기화
+// 오류가 나면 index와 state는 일부만 바뀌었을 수 있으므로 호출자가 버려야 함
+fn sync_remote(
+    index: &mut NotesIndex,
+    state: &mut SyncState,
+    remote: &mut dyn Remote,
+    notes_dir: &Path,
+    device: &str,
+) -> Result<SyncReport, String> {
+    let (mut remote_index, index_etag) = match remote.get(REMOTE_INDEX)? {
+        Some((bytes, etag)) => (
+            serde_json::from_slice::<RemoteIndex>(&bytes)
+                .map_err(|e| format!("Invalid sync index: {}", e))?,
+            Some(etag),
+        ),
+        None => (RemoteIndex::default(), None),
+    };
+    let listing = remote.list("notes")?;
+    detect_external_edits(&mut remote_index, &listing, remote)?;
+
+    let mut report = SyncReport::default();
+    let mut changes = Vec::new();
+    let mut remote_removals = Vec::new();
+    let mut planned_paths = HashSet::new();
+    let mut used_names: HashSet<String> = listing.keys().cloned().collect();
+    used_names.extend(
+        remote_index
+            .notes
+            .iter()
+            .filter_map(|r| file_name_of(&r.note.file_path)),
+    );
+
+    let mut ids: Vec<String> = index.notes.iter().map(|n| n.id.clone()).collect();
+    for r in &remote_index.notes {
+        if !ids.contains(&r.note.id) {
+            ids.push(r.note.id.clone());
+        }
+    }
+
+    for id in ids {
+        let local_pos = index.notes.iter().position(|n| n.id == id);
+        let remote_pos = remote_index.notes.iter().position(|r| r.note.id == id);
+        let base = state.notes.get(&id).cloned();
+
+        let local_content = match local_pos {
+            Some(pos) => match read_text(Path::new(&index.notes[pos].file_path)) {
+                Some(content) => Some(content),
+                None => {
+                    report.skipped += 1;
+                    continue;
+                }
+            },
+            None => None,
+        };
+        // 인덱스에는 있지만 파일이 아직 없는 노트 (동기화 도구가 받는 중 등)
+        if let Some(pos) = remote_pos {
+            let uploaded = file_name_of(&remote_index.notes[pos].note.file_path)
+                .is_some_and(|name| listing.contains_key(&name));
+            if !uploaded {
+                report.skipped += 1;
+                continue;
+            }
+        }
+        let local_hash = local_pos
+            .zip(local_content.as_deref())
+            .map(|(pos, content)| fingerprint(&index.notes[pos], content));
+
+        match (local_pos, remote_pos) {
+            (Some(pos), None) => {
+                // 다른 기기에서 삭제되었고 로컬에서 바뀌지 않았으면 로컬도 삭제
+                let deleted = remote_index.deleted.iter().any(|t| t.id == id);
+                if deleted && base.is_some_and(|b| Some(&b.hash) == local_hash.as_ref()) {
+                    let note = index.notes.remove(pos);
+                    let path = PathBuf::from(&note.file_path);
+                    if path.starts_with(notes_dir) {
+                        changes.push(LocalChange::Remove(path));
+                    }
+                    state.notes.remove(&id);
+                    report.deleted_local += 1;
+                    report.deleted_ids.push(id);
+                    continue;
+                }
+
+                // 삭제 후 로컬에서 다시 수정된 노트는 삭제 기록을 지우고 다시 올림
+                remote_index.deleted.retain(|t| t.id != id);
+                upload(
+                    &index.notes[pos],
+                    local_content.as_deref().unwrap_or_default(),
+                    &mut remote_index,
+                    remote,
+                    &mut used_names,
+                    state,
+                )?;
+                report.uploaded += 1;
+            }
+            (None, Some(pos)) => {
+                // 로컬에서 삭제되었고 원격에서 바뀌지 않았으면 원격도 삭제
+                if base.is_some_and(|b| b.revision == remote_index.notes[pos].revision) {
+                    let removed = remote_index.notes.remove(pos);
+                    remote_removals.extend(file_name_of(&removed.note.file_path));
+                    remote_index.deleted.push(Tombstone {
+                        id: id.clone(),
+                        deleted_at: chrono::Utc::now().to_rfc3339(),
+                    });
+                    state.notes.remove(&id);
+                    report.deleted_remote += 1;
+                    continue;
+                }
+
+                let entry = remote_index.notes[pos].clone();
+                let Some(content) = fetch(remote, &entry)? else {
+                    report.skipped += 1;
+                    continue;
+                };
+                download(
+                    index,
+                    None,
+                    &entry,
+                    content,
+                    notes_dir,
+                    &mut planned_paths,
+                    &mut changes,
+                );
+                state.notes.insert(
+                    id,
+                    SyncedNote {
+                        revision: entry.revision,
+                        hash: entry.hash,
+                    },
+                );
+                report.downloaded += 1;
+            }
+            (Some(local), Some(pos)) => {
+                let entry = remote_index.notes[pos].clone();
+                let local_hash = local_hash.unwrap_or_default();
+                if local_hash == entry.hash {
+                    state.notes.insert(
+                        id,
+                        SyncedNote {
+                            revision: entry.revision,
+                            hash: entry.hash,
+                        },
+                    );
+                    continue;
+                }
+
+                let local_changed = base.as_ref().is_none_or(|b| b.hash != local_hash);
+                let remote_changed = base
+                    .as_ref()
+                    .is_none_or(|b| b.revision != entry.revision || b.hash != entry.hash);
+
+                if local_changed && !remote_changed {
+                    upload(
+                        &index.notes[local],
+                        local_content.as_deref().unwrap_or_default(),
+                        &mut remote_index,
+                        remote,
+                        &mut used_names,
+                        state,
+                    )?;
+                    report.uploaded += 1;
+                    continue;
+                }
+
+                let Some(remote_content) = fetch(remote, &entry)? else {
+                    report.skipped += 1;
+                    continue;
+                };
+
+                if !local_changed {
+                    download(
+                        index,
+                        Some(local),
+                        &entry,
+                        remote_content,
+                        notes_dir,
+                        &mut planned_paths,
+                        &mut changes,
+                    );
+                    state.notes.insert(
+                        id.clone(),
+                        SyncedNote {
+                            revision: entry.revision,
+                            hash: entry.hash,
+                        },
+                    );
+                    report.downloaded += 1;
+                    report.changed_ids.push(id);
+                    continue;
+                }
+
+                // 양쪽 모두 바뀜: 원격 내용은 새 id의 충돌 사본으로 받아 함께 올림
+                let copy = conflict_copy(&entry, device);
+                let copy_pos = download(
+                    index,
+                    None,
+                    &copy,
+                    remote_content.clone(),
+                    notes_dir,
+                    &mut planned_paths,
+                    &mut changes,
+                );
+                upload(
+                    &index.notes[copy_pos],
+                    &remote_content,
+                    &mut remote_index,
+                    remote,
+                    &mut used_names,
+                    state,
+                )?;
+                upload(
+                    &index.notes[local],
+                    local_content.as_deref().unwrap_or_default(),
+                    &mut remote_index,
+                    remote,
+                    &mut used_names,
+                    state,
+                )?;
+                report.conflicts += 1;
+                report.uploaded += 1;
+            }
+            (None, None) => {}
+        }
+    }
+
+    merge_groups(index, &mut remote_index);
+    let cutoff = chrono::Utc::now() - chrono::Duration::days(TOMBSTONE_DAYS);
+    remote_index.deleted.retain(|t| {
+        chrono::DateTime::parse_from_rfc3339(&t.deleted_at).is_ok_and(|at| at > cutoff)
+    });
+
+    // 다른 기기가 먼저 index.json을 바꿨으면 실패 (로컬은 아직 바뀌지 않음)
+    let content = serde_json::to_string_pretty(&remote_index).map_err(|e| e.to_string())?;
+    let condition = match &index_etag {
+        Some(etag) => Condition::Matches(etag),
+        None => Condition::Missing,
+    };
+    remote.put(REMOTE_INDEX, content.as_bytes(), condition)?;
+
+    for name in remote_removals {
+        if let Err(e) = remote.delete(&format!("notes/{}", name)) {
+            println!("Sync: failed to delete remote note {}: {}", name, e);
+        }
+    }
+    for change in changes {
+        match change {
+            LocalChange::Write(path, content) => write_atomic(&path, content.as_bytes())?,
+            LocalChange::Remove(path) => {
+                let _ = fs::remove_file(path);
+            }
+        }
+    }
+    sync_images(remote, &notes_dir.join("images"))?;
+
+    Ok(report)
+}
+
+// 기록된 ETag와 다른 노트 파일은 서버/폴더에서 직접 수정된 것으로 보고 새 revision 부여
+fn detect_external_edits(
+    remote_index: &mut RemoteIndex,
+    listing: &BTreeMap<String, String>,
+    remote: &mut dyn Remote,
+) -> Result<(), String> {
+    for entry in remote_index.notes.iter_mut() {
+        let Some(name) = file_name_of(&entry.note.file_path) else {
+            continue;
+        };
+        let Some(actual) = listing.get(&name) else {
+            continue;
+        };
+
+        match entry.etag.as_deref() {
+            Some(etag) if !crate::webdav::same_etag(etag, actual) => {
+                let Some((bytes, etag)) = remote.get(&format!("notes/{}", name))? else {
+                    continue;
+                };
+                let content = String::from_utf8_lossy(&bytes);
+                if !entry.note.encrypted {
+                    entry.note.title = extract_title(&content);
+                }
+                entry.hash = fingerprint(&entry.note, &content);
+                entry.revision = uuid::Uuid::new_v4().to_string();
+                entry.etag = Some(etag);
+            }
+            Some(_) => {}
+            None => entry.etag = Some(actual.clone()),
+        }
+    }
+    Ok(())
+}
+
+// 로컬 노트를 새 revision으로 올림
+fn upload(
+    note: &NoteMetadata,
+    content: &str,
+    remote_index: &mut RemoteIndex,
+    remote: &mut dyn Remote,
+    used_names: &mut HashSet<String>,
+    state: &mut SyncState,
+) -> Result<(), String> {
+    let existing = remote_index.notes.iter().position(|r| r.note.id == note.id);
+    let file_name = match existing
+        .and_then(|pos| file_name_of(&remote_index.notes[pos].note.file_path))
+    {
+        Some(name) => name,
+        None => {
+            let name = file_name_of(&note.file_path).unwrap_or_else(|| format!("{}.md", note.id));
+            unique_name(used_names, &name)
+        }
+    };
+
+    let file_path = format!("notes/{}", file_name);
+    let etag = remote.put(&file_path, content.as_bytes(), Condition::Always)?;
+
+    let hash = fingerprint(note, content);
+    let entry = RemoteNote {
+        note: NoteMetadata {
+            file_path,
+            ..note.clone()
+        },
+        revision: uuid::Uuid::new_v4().to_string(),
+        hash: hash.clone(),
+        etag: Some(etag),
+    };
+    state.notes.insert(
+        note.id.clone(),
+        SyncedNote {
+            revision: entry.revision.clone(),
+            hash,
+        },
+    );
+    match existing {
+        Some(pos) => remote_index.notes[pos] = entry,
+        None => remote_index.notes.push(entry),
+    }
+    Ok(())
+}
+
+// 원격 노트를 로컬 인덱스에 반영하고 파일 쓰기를 예약, 로컬 인덱스에서의 위치 반환
+fn download(
+    index: &mut NotesIndex,
+    local: Option<usize>,
+    entry: &RemoteNote,
+    content: String,
+    notes_dir: &Path,
+    planned_paths: &mut HashSet<PathBuf>,
+    changes: &mut Vec<LocalChange>,
+) -> usize {
+    match local {
+        Some(pos) => {
+            apply_remote(&mut index.notes[pos], &entry.note, &content);
+            changes.push(LocalChange::Write(
+                PathBuf::from(&index.notes[pos].file_path),
+                content,
+            ));
+            pos
+        }
+        None => {
+            let file_name = file_name_of(&entry.note.file_path)
+                .unwrap_or_else(|| format!("{}.md", entry.note.id));
+            let path = plan_path(notes_dir, &file_name, planned_paths);
+
+            let mut note = NoteMetadata {
+                file_path: path.to_string_lossy().to_string(),
+                ..entry.note.clone()
+            };
+            apply_remote(&mut note, &entry.note, &content);
+            index.notes.push(note);
+            changes.push(LocalChange::Write(path, content));
+            index.notes.len() - 1
+        }
+    }
+}
+
+// 디스크에도 없고 이번 동기화에서 쓸 예정도 없는 파일 경로
+fn plan_path(dir: &Path, name: &str, planned_paths: &mut HashSet<PathBuf>) -> PathBuf {
+    let mut used = HashSet::new();
+    loop {
+        let candidate = dir.join(unique_name(&mut used, name));
+        if !candidate.exists() && planned_paths.insert(candidate.clone()) {
+            return candidate;
+        }
+    }
+}
+
+// 충돌 사본: 새 id, 파일 이름과 제목에 기기 이름과 날짜 표시
+fn conflict_copy(entry: &RemoteNote, device: &str) -> RemoteNote {
+    let suffix = format!(
+        "conflict {} {}",
+        device,
+        chrono::Local::now().format("%Y-%m-%d")
+    );
+    let stem = Path::new(&entry.note.file_path)
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| "note".to_string());
+
+    let mut copy = entry.clone();
+    copy.note.id = uuid::Uuid::new_v4().to_string();
+    copy.note.title = format!("{} ({})", entry.note.title, suffix);
+    copy.note.file_path = format!(
+        "notes/{}.md",
+        crate::sanitize_file_name(&format!("{} ({})", stem, suffix))
+    );
+    copy.note.pinned = false;
+    copy
+}
+
+// 그룹은 id 기준 합집합 (한쪽에만 있는 그룹을 다른 쪽에 추가)
+fn merge_groups(index: &mut NotesIndex, remote: &mut RemoteIndex) {
+    for group in &remote.groups {
+        if !index.groups.iter().any(|g| g.id == group.id) {
+            index.groups.push(group.clone());
+        }
+    }
+    for group in &index.groups {
+        if !remote.groups.iter().any(|g| g.id == group.id) {
+            remote.groups.push(group.clone());
+        }
+    }
+}
+
+// 동기화 전(before)과 후(after)를 비교해 바뀐 항목만 최신 목록(fresh)에 id 기준으로 반영
+fn merge_by_id<T: Serialize + Clone>(
+    fresh: &mut Vec<T>,
+    before: &[T],
+    after: &[T],
+    id: impl Fn(&T) -> &str,
+) {
+    let same = |a: &T, b: &T| serde_json::to_value(a).ok() == serde_json::to_value(b).ok();
+    fresh.retain(|item| {
+        let removed =
+            before.iter().any(|b| id(b) == id(item)) && !after.iter().any(|a| id(a) == id(item));
+        !removed
+    });
+    for item in after {
+        if before.iter().any(|b| id(b) == id(item) && same(b, item)) {
+            continue;
+        }
+        match fresh.iter_mut().find(|f| id(f) == id(item)) {
+            Some(existing) => *existing = item.clone(),
+            None => fresh.push(item.clone()),
+        }
+    }
+}
+
+// 이미지는 이름 기준으로 한쪽에만 있는 파일을 복사 (save_image가 고유한 이름을 붙이므로 내용은 바뀌지 않음)
+fn sync_images(remote: &mut dyn Remote, local_dir: &Path) -> Result<(), String> {
+    let remote_names = remote.list("images")?;
+    let local_names: HashSet<String> = fs::read_dir(local_dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|e| e.path().is_file())
+                .map(|e| e.file_name().to_string_lossy().to_string())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    for name in local_names
+        .iter()
+        .filter(|n| !remote_names.contains_key(*n))
+    {
+        let result = read_file(&local_dir.join(name))
+            .and_then(|bytes| remote.put(&format!("images/{}", name), &bytes, Condition::Always));
+        if let Err(e) = result {
+            println!("Sync: failed to upload image {}: {}", name, e);
+        }
+    }
+    for name in remote_names.keys().filter(|n| !local_names.contains(*n)) {
+        let result = remote.get(&format!("images/{}", name)).and_then(|file| {
+            let (bytes, _) = file.ok_or("Image not found")?;
+            fs::create_dir_all(local_dir).map_err(|e| e.to_string())?;
+            write_atomic(&local_dir.join(name), &bytes)
+        });
+        if let Err(e) = result {
+            println!("Sync: failed to download image {}: {}", name, e);
+        }
+    }
+    Ok(())
+}
+
+// 일반 폴더 동기화 대상 (ETag는 파일 크기와 수정 시각)
+pub(crate) struct FolderRemote {
+    root: PathBuf,
+}
+
+impl FolderRemote {
+    pub(crate) fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    fn etag(path: &Path) -> Option<String> {
+        let metadata = fs::metadata(path).ok()?;
+        let modified = metadata
+            .modified()
+            .ok()?
+            .duration_since(std::time::UNIX_EPOCH)
+            .ok()?;
+        Some(format!("{:x}-{:x}", metadata.len(), modified.as_nanos()))
+    }
+}
+
+impl Remote for FolderRemote {
+    fn get(&mut self, path: &str) -> Result<Option<(Vec<u8>, String)>, String> {
+        let path = self.root.join(path);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let bytes = fs::read(&path).map_err(|e| e.to_string())?;
+        Ok(Some((bytes, Self::etag(&path).unwrap_or_default())))
+    }
+
+    fn put(&mut self, path: &str, data: &[u8], condition: Condition) -> Result<String, String> {
+        let path = self.root.join(path);
+        let current = Self::etag(&path);
+        let allowed = match condition {
+            Condition::Always => true,
+            Condition::Matches(etag) => current.as_deref() == Some(etag),
+            Condition::Missing => current.is_none(),
+        };
+        if !allowed {
+            return Err(PRECONDITION_FAILED.to_string());
+        }
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        write_atomic(&path, data)?;
+        Self::etag(&path).ok_or_else(|| "Failed to read file metadata".to_string())
+    }
+
+    fn delete(&mut self, path: &str) -> Result<(), String> {
+        match fs::remove_file(self.root.join(path)) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.to_string()),
+            _ => Ok(()),
+        }
+    }
+
+    fn list(&mut self, dir: &str) -> Result<BTreeMap<String, String>, String> {
+        let dir = self.root.join(dir);
+        fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+        Ok(fs::read_dir(&dir)
+            .map_err(|e| e.to_string())?
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| p.is_file())
+            .filter_map(|p| {
+                let name = p.file_name()?.to_string_lossy().to_string();
+                // write_atomic의 임시 파일 제외
+                if name.starts_with('.') && name.ends_with(".tmp") {
+                    return None;
+                }
+                Some((name, Self::etag(&p)?))
+            })
+            .collect())
+    }
+}
+
+// 설정에 따라 동기화 대상 생성, (state에 기록할 대상 이름, 저장소) 반환
+fn open_remote(settings: &SyncSettings) -> Result<(String, Box<dyn Remote>), String> {
+    match settings.backend {
+        SyncBackend::Folder => {
+            let folder = settings.folder.clone().ok_or("Sync folder is not set")?;
+            Ok((folder.clone(), Box::new(FolderRemote::new(folder))))
+        }
+        SyncBackend::WebDav => {
+            let webdav = &settings.webdav;
+            let url = webdav.url.clone().ok_or("WebDAV URL is not set")?;
+            let remote =
+                WebDavRemote::new(&url, webdav.username.as_deref(), webdav.password.as_deref())?;
+            Ok((url, Box::new(remote)))
+        }
+    }
+}
+
+fn get_state_path() -> Result<PathBuf, String> {
+    Ok(get_storage_dir()?.join(STATE_FILE))
+}
+
+fn read_state() -> Result<SyncState, String> {
+    let path = get_state_path()?;
+    Ok(fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default())
+}
+
+fn write_state(state: &SyncState) -> Result<(), String> {
+    let content = serde_json::to_string_pretty(state).map_err(|e| e.to_string())?;
+    write_atomic(&get_state_path()?, content.as_bytes())
+}
+
+fn run_sync(app: &tauri::AppHandle) -> Result<SyncReport, String> {
+    let (target, mut remote) = open_remote(&read_settings()?.sync)?;
+    // 암호화된 라이브러리를 평문으로 내보내지 않도록 함
+    if crate::vault::is_enabled() {
+        return Err("Sync is not available while library encryption is enabled".to_string());
+    }
+
+    let _guard = SYNC_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut attempt = 1;
+    let (before, index, mut state, report) = loop {
+        let before = read_index()?;
+        let mut index = before.clone();
+        let mut state = read_state()?;
+        if state.folder != target {
+            state = SyncState {
+                folder: target.clone(),
+                ..Default::default()
+            };
+        }
+
+        match sync_remote(
+            &mut index,
+            &mut state,
+            remote.as_mut(),
+            &get_notes_dir()?,
+            &device_name(),
+        ) {
+            Ok(report) => break (before, index, state, report),
+            Err(e) if e == PRECONDITION_FAILED && attempt < MAX_ATTEMPTS => attempt += 1,
+            Err(e) => return Err(e),
+        }
+    };
+    {
+        // 원격 작업 중에 다른 곳에서 바뀐 인덱스를 덮어쓰지 않도록 다시 읽고 바뀐 노트만 반영
+        let _index_guard = lock_index();
+        let mut fresh = read_index()?;
+        merge_by_id(&mut fresh.notes, &before.notes, &index.notes, |n| &n.id);
+        merge_by_id(&mut fresh.groups, &before.groups, &index.groups, |g| &g.id);
+        write_index(&fresh)?;
+    }
+    state.last_synced_at = Some(chrono::Utc::now().to_rfc3339());
+    write_state(&state)?;
+
+    for id in &report.deleted_ids {
+        if let Some(window) = app.get_webview_window(&format!("note_{}", id)) {
+            let _ = window.close();
+        }
+    }
+    for id in &report.changed_ids {
+        let _ = app.emit("note-file-changed", id);
+    }
+    let _ = app.emit("refresh-notes-list", ());
+    Ok(report)
+}
+
+// 지금 동기화 커맨드
+#[tauri::command]
+pub fn sync_now(app: tauri::AppHandle) -> Result<SyncReport, String> {
+    run_sync(&app)
+}
+
+// 동기화 스케줄러 시작 (설정의 주기마다, 동기화 대상이 설정된 경우만)
+pub fn start_scheduler(app: tauri::AppHandle) {
+    std::thread::spawn(move || {
+        let mut last_run: Option<Instant> = None;
+        loop {
+            let settings = read_settings().map(|s| s.sync).unwrap_or_default();
+            let configured = match settings.backend {
+                SyncBackend::Folder => settings.folder.is_some(),
+                SyncBackend::WebDav => settings.webdav.url.is_some(),
+            };
+            let interval = Duration::from_secs(settings.interval_minutes as u64 * 60);
+            let due = configured
+                && settings.interval_minutes > 0
+                && last_run.is_none_or(|t| t.elapsed() >= interval);
+
+            if due {
+                last_run = Some(Instant::now());
+                match run_sync(&app) {
+                    Ok(report) => println!("Sync finished: {:?}", report),
+                    Err(e) => println!("Sync failed: {}", e),
+                }
+            }
+            std::thread::sleep(CHECK_INTERVAL);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Device {
+        index: NotesIndex,
+        state: SyncState,
+        notes_dir: PathBuf,
+    }
+
+    impl Device {
+        fn new(root: &Path, name: &str) -> Self {
+            let notes_dir = root.join(name);
+            fs::create_dir_all(&notes_dir).unwrap();
+            Device {
+                index: NotesIndex::default(),
+                state: SyncState::default(),
+                notes_dir,
+            }
+        }
+
+        fn write(&mut self, id: &str, content: &str) {
+            let path = self.notes_dir.join(format!("{}.md", id));
+            fs::write(&path, content).unwrap();
+            match self.index.notes.iter_mut().find(|n| n.id == id) {
+                Some(note) => refresh_content_stats(note, content),
+                None => self.index.notes.push(NoteMetadata {
+                    id: id.to_string(),
+                    title: extract_title(content),
+                    file_path: path.to_string_lossy().to_string(),
+                    ..Default::default()
+                }),
+            }
+        }
+
+        fn read(&self, id: &str) -> String {
+            let note = self.index.notes.iter().find(|n| n.id == id).unwrap();
+            fs::read_to_string(&note.file_path).unwrap()
+        }
+
+        fn sync(&mut self, remote: &mut dyn Remote) -> SyncReport {
+            sync_remote(
+                &mut self.index,
+                &mut self.state,
+                remote,
+                &self.notes_dir,
+                "test",
+            )
+            .unwrap()
+        }
+    }
+
+    #[test]
+    fn test_sync_propagates_edits_and_deletes() {
+        let root = std::env::temp_dir().join(format!("stickermd_sync_{}", uuid::Uuid::new_v4()));
+        let mut remote = FolderRemote::new(root.join("remote"));
+        let mut a = Device::new(&root, "a");
+        let mut b = Device::new(&root, "b");
+
+        a.write("n1", "# 장보기\n우유");
+        assert_eq!(a.sync(&mut remote).uploaded, 1);
+        assert_eq!(b.sync(&mut remote).downloaded, 1);
+        assert_eq!(b.read("n1"), "# 장보기\n우유");
+
+        // 메타데이터만 바뀌어도 전달
+        b.index.notes[0].pinned = true;
+        b.sync(&mut remote);
+        a.sync(&mut remote);
+        assert!(a.index.notes[0].pinned);
+
+        // 한쪽에서만 바뀐 노트는 충돌 없이 반영
+        a.write("n1", "# 장보기\n우유\n계란");
+        a.sync(&mut remote);
+        let report = b.sync(&mut remote);
+        assert_eq!((report.downloaded, report.conflicts), (1, 0));
+        assert_eq!(b.read("n1"), "# 장보기\n우유\n계란");
+
+        // 삭제 전파
+        let removed = b.index.notes.remove(0);
+        fs::remove_file(&removed.file_path).unwrap();
+        assert_eq!(b.sync(&mut remote).deleted_remote, 1);
+        assert_eq!(a.sync(&mut remote).deleted_local, 1);
+        assert!(a.index.notes.is_empty());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_sync_creates_conflict_copy() {
+        let root = std::env::temp_dir().join(format!("stickermd_sync_{}", uuid::Uuid::new_v4()));
+        let mut remote = FolderRemote::new(root.join("remote"));
+        let mut a = Device::new(&root, "a");
+        let mut b = Device::new(&root, "b");
+
+        a.write("n1", "# 메모\n원본");
+        a.sync(&mut remote);
+        b.sync(&mut remote);
+
+        a.write("n1", "# 메모\nA에서 수정");
+        b.write("n1", "# 메모\nB에서 수정");
+        a.sync(&mut remote);
+        let report = b.sync(&mut remote);
+        assert_eq!((report.conflicts, report.uploaded), (1, 1));
+
+        // B의 원본은 B의 내용 유지, A의 내용은 충돌 사본으로
+        assert_eq!(b.read("n1"), "# 메모\nB에서 수정");
+        let copy = b.index.notes.iter().find(|n| n.id != "n1").unwrap();
+        assert!(copy.title.contains("conflict"));
+        assert_eq!(b.read(&copy.id.clone()), "# 메모\nA에서 수정");
+
+        // A도 다음 동기화에서 B의 내용과 충돌 사본을 받음
+        let report = a.sync(&mut remote);
+        assert_eq!((report.downloaded, report.conflicts), (2, 0));
+        assert_eq!(a.read("n1"), "# 메모\nB에서 수정");
+        assert_eq!(a.index.notes.len(), 2);
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_sync_over_webdav() {
+        let root = std::env::temp_dir().join(format!("stickermd_sync_{}", uuid::Uuid::new_v4()));
+        let server = crate::webdav::test_server::start();
+        let mut remote = WebDavRemote::new(&server, Some("user"), Some("secret")).unwrap();
+        let mut a = Device::new(&root, "a");
+        let mut b = Device::new(&root, "b");
+
+        a.write("n1", "# 회의\n안건");
+        a.write("n2", "# 할 일");
+        fs::create_dir_all(a.notes_dir.join("images")).unwrap();
+        fs::write(a.notes_dir.join("images").join("pic.png"), [1u8, 2, 3]).unwrap();
+        assert_eq!(a.sync(&mut remote).uploaded, 2);
+        assert_eq!(b.sync(&mut remote).downloaded, 2);
+        assert_eq!(
+            fs::read(b.notes_dir.join("images").join("pic.png")).unwrap(),
+            vec![1u8, 2, 3]
+        );
+
+        // 서버에서 직접 수정된 파일은 ETag로 감지하여 받음
+        remote
+            .put(
+                "notes/n1.md",
+                "# 회의\n웹에서 수정".as_bytes(),
+                Condition::Always,
+            )
+            .unwrap();
+        let report = a.sync(&mut remote);
+        assert_eq!((report.downloaded, report.conflicts), (1, 0));
+        assert_eq!(a.read("n1"), "# 회의\n웹에서 수정");
+
+        // 삭제는 tombstone으로 전달
+        a.index.notes.retain(|n| n.id != "n2");
+        assert_eq!(a.sync(&mut remote).deleted_remote, 1);
+        assert_eq!(b.sync(&mut remote).deleted_local, 1);
+        let (index, _) = remote.get(REMOTE_INDEX).unwrap().unwrap();
+        let index: RemoteIndex = serde_json::from_slice(&index).unwrap();
+        assert_eq!(index.deleted.len(), 1);
+        assert_eq!(index.deleted[0].id, "n2");
+
+        // 동기화 도중 다른 기기가 index.json을 바꾸면 로컬을 바꾸지 않고 실패
+        let (_, etag) = remote.get(REMOTE_INDEX).unwrap().unwrap();
+        b.write("n1", "# 회의\nB에서 수정");
+        let mut stale = RacingRemote {
+            inner: &mut remote,
+            raced: false,
+        };
+        let result = sync_remote(&mut b.index, &mut b.state, &mut stale, &b.notes_dir, "test");
+        assert_eq!(result.unwrap_err(), PRECONDITION_FAILED);
+        assert_ne!(remote.get(REMOTE_INDEX).unwrap().unwrap().1, etag);
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_merge_by_id_keeps_changes_made_during_sync() {
+        let note = |id: &str, title: &str| NoteMetadata {
+            id: id.to_string(),
+            title: title.to_string(),
+            ..Default::default()
+        };
+        let before = vec![note("a", "A"), note("b", "B"), note("c", "C")];
+        // 동기화 결과: a 수정, b 삭제, d 추가
+        let after = vec![note("a", "A2"), note("c", "C"), note("d", "D")];
+        // 동기화 중에 로컬에서 c를 수정하고 e를 새로 만듦
+        let mut fresh = vec![
+            note("a", "A"),
+            note("b", "B"),
+            note("c", "C3"),
+            note("e", "E"),
+        ];
+
+        merge_by_id(&mut fresh, &before, &after, |n| &n.id);
+
+        let titles: Vec<(&str, &str)> = fresh
+            .iter()
+            .map(|n| (n.id.as_str(), n.title.as_str()))
+            .collect();
+        assert_eq!(
+            titles,
+            vec![("a", "A2"), ("c", "C3"), ("e", "E"), ("d", "D")]
+        );
+    }
+
+    // index.json을 읽은 직후 다른 기기가 index.json을 바꾼 것처럼 동작
+    struct RacingRemote<'a> {
+        inner: &'a mut WebDavRemote,
+        raced: bool,
+    }
+
+    impl Remote for RacingRemote<'_> {
+        fn get(&mut self, path: &str) -> Result<Option<(Vec<u8>, String)>, String> {
+            let result = self.inner.get(path)?;
+            if path == REMOTE_INDEX && !self.raced {
+                self.raced = true;
+                let (bytes, _) = result.clone().unwrap();
+                self.inner.put(path, &bytes, Condition::Always)?;
+            }
+            Ok(result)
+        }
+
+        fn put(&mut self, path: &str, data: &[u8], condition: Condition) -> Result<String, String> {
+            self.inner.put(path, data, condition)
+        }
+
+        fn delete(&mut self, path: &str) -> Result<(), String> {
+            self.inner.delete(path)
+        }
+
+        fn list(&mut self, dir: &str) -> Result<BTreeMap<String, String>, String> {
+            self.inner.list(dir)
+        }
+    }
+}
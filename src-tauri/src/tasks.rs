@@ -0,0 +1,285 @@
+// 전체 노트의 GFM 할 일(- [ ] / - [x]) 모아보기
+
+use crate::{
+    extract_tags, lock_index, read_file, read_index, sync_note_content, write_atomic, write_index,
+};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tauri::Emitter;
+
+#[derive(Deserialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskStatus {
+    Open,
+    Done,
+    #[default]
+    All,
+}
+
+#[derive(Serialize)]
+pub struct TaskItem {
+    note_id: String,
+    note_title: String,
+    // 1부터 시작하는 줄 번호 (toggle_task에 그대로 전달)
+    line: usize,
+    text: String,
+    done: bool,
+    // 들여쓰기 수준 (하위 할 일 표시용)
+    indent: usize,
+    // 할 일 자체의 태그 + 노트의 태그
+    tags: Vec<String>,
+}
+
+// 할 일 목록 조회 커맨드
+#[tauri::command]
+pub fn list_tasks(
+    status: Option<TaskStatus>,
+    note_id: Option<String>,
+    tag: Option<String>,
+) -> Result<Vec<TaskItem>, String> {
+    let index = read_index()?;
+    let status = status.unwrap_or_default();
+    let tag = tag.map(|t| t.trim_start_matches('#').to_lowercase());
+    let mut tasks = Vec::new();
+
+    for note in &index.notes {
+        // 암호화된 노트의 할 일은 목록에 포함하지 않음
+        if note.encrypted || note_id.as_deref().is_some_and(|id| id != note.id) {
+            continue;
+        }
+
+        // 파일이 없거나 읽을 수 없는 노트는 건너뜀
+        let Ok(bytes) = read_file(Path::new(&note.file_path)) else {
+            continue;
+        };
+        let content = String::from_utf8_lossy(&bytes);
+        let note_tags = extract_tags(&content);
+
+        for (line, text, done, indent) in parse_tasks(&content) {
+            let mut tags = extract_tags(&text);
+            for t in &note_tags {
+                if !tags.contains(t) {
+                    tags.push(t.clone());
+                }
+            }
+
+            let matches_status = match status {
+                TaskStatus::Open => !done,
+                TaskStatus::Done => done,
+                TaskStatus::All => true,
+            };
+            let matches_tag = tag.as_ref().is_none_or(|t| tags.contains(t));
+
+            if matches_status && matches_tag {
+                tasks.push(TaskItem {
+                    note_id: note.id.clone(),
+                    note_title: note.title.clone(),
+                    line,
+                    text,
+                    done,
+                    indent,
+                    tags,
+                });
+            }
+        }
+    }
+
+    Ok(tasks)
+}
+
+// 할 일 체크 상태 토글 커맨드
+// expected_text가 주어지면 해당 줄의 내용이 같을 때만 수정 (목록 조회 후 파일이 바뀐 경우 방지)
+#[tauri::command]
+pub fn toggle_task(
+    app: tauri::AppHandle,
+    note_id: String,
+    line: usize,
+    expected_text: Option<String>,
+) -> Result<bool, String> {
+    // 인덱스를 쓸 때까지 잠금 유지 (API 서버 스레드와 동시 수정 방지)
+    let guard = lock_index();
+    let mut index = read_index()?;
+    let note = index
+        .notes
+        .iter()
+        .find(|n| n.id == note_id)
+        .ok_or("Note not found")?;
+    if note.encrypted {
+        return Err("Encrypted notes cannot be modified here".to_string());
+    }
+    let path = note.file_path.clone();
+
+    let bytes = read_file(Path::new(&path))?;
+    let content = String::from_utf8(bytes).map_err(|_| "Note is not valid UTF-8".to_string())?;
+
+    let (new_content, done) = toggle_task_line(&content, line, expected_text.as_deref())?;
+    write_atomic(Path::new(&path), new_content.as_bytes())?;
+
+    // 저장과 같은 방식으로 제목/통계/링크 갱신 (첫 줄이 할 일이면 제목도 바뀜)
+    let rewritten = sync_note_content(
+        &mut index,
+        &note_id,
+        &new_content,
+        chrono::Utc::now().to_rfc3339(),
+    )?;
+    write_index(&index)?;
+    drop(guard);
+
+    // 열린 노트 윈도우가 내용을 다시 불러오도록 알림
+    let _ = app.emit("note-file-changed", &note_id);
+    for id in rewritten {
+        let _ = app.emit("note-file-changed", id);
+    }
+    let _ = app.emit("refresh-notes-list", ());
+
+    Ok(done)
+}
+
+// 할 일 줄의 체크박스 위치 파싱: (체크박스 문자 바이트 위치, 완료 여부, 들여쓰기, 본문 시작 위치)
+fn parse_task_line(line: &str) -> Option<(usize, bool, usize, usize)> {
+    let trimmed = line.trim_start();
+    let indent = line.len() - trimmed.len();
+
+    // 목록 기호: "-", "*", "+" 또는 "1." / "1)"
+    let marker_len = if trimmed.starts_with(['-', '*', '+']) {
+        1
+    } else {
+        let digits = trimmed.chars().take_while(|c| c.is_ascii_digit()).count();
+        if digits == 0 || !trimmed[digits..].starts_with(['.', ')']) {
+            return None;
+        }
+        digits + 1
+    };
+
+    let rest = trimmed[marker_len..].strip_prefix(' ')?;
+    let checkbox = rest.get(..3)?;
+    let done = match checkbox {
+        "[ ]" => false,
+        "[x]" | "[X]" => true,
+        _ => return None,
+    };
+
+    let checkbox_pos = indent + marker_len + 1 + 1;
+    let text_start = indent + marker_len + 1 + 3;
+    // 체크박스 뒤에는 공백이나 줄 끝이 와야 함
+    match line[text_start..].chars().next() {
+        None | Some(' ') | Some('\t') | Some('\r') => {}
+        _ => return None,
+    }
+
+    Some((checkbox_pos, done, indent, text_start))
+}
+
+// 본문에서 할 일 목록 추출: (줄 번호, 내용, 완료 여부, 들여쓰기)
+fn parse_tasks(content: &str) -> Vec<(usize, String, bool, usize)> {
+    let mut tasks = Vec::new();
+    let mut in_code_block = false;
+
+    for (i, line) in content.lines().enumerate() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+
+        if let Some((_, done, indent, text_start)) = parse_task_line(line) {
+            tasks.push((i + 1, line[text_start..].trim().to_string(), done, indent));
+        }
+    }
+
+    tasks
+}
+
+// 지정한 줄의 체크박스 토글 (줄바꿈 문자는 원본 그대로 유지)
+fn toggle_task_line(
+    content: &str,
+    line: usize,
+    expected_text: Option<&str>,
+) -> Result<(String, bool), String> {
+    let mut result = String::with_capacity(content.len());
+    let mut toggled = None;
+    let mut in_code_block = false;
+
+    for (i, raw_line) in content.split_inclusive('\n').enumerate() {
+        // parse_tasks와 같이 코드 블록 안의 줄은 할 일로 보지 않음
+        let is_fence = raw_line.trim_start().starts_with("```");
+        if is_fence {
+            in_code_block = !in_code_block;
+        }
+        if i + 1 != line {
+            result.push_str(raw_line);
+            continue;
+        }
+        if is_fence || in_code_block {
+            return Err(format!("Line {} is not a task", line));
+        }
+
+        let (checkbox_pos, done, _, text_start) =
+            parse_task_line(raw_line).ok_or(format!("Line {} is not a task", line))?;
+
+        if let Some(expected) = expected_text {
+            if raw_line[text_start..].trim() != expected.trim() {
+                return Err("Task has changed since it was listed".to_string());
+            }
+        }
+
+        result.push_str(&raw_line[..checkbox_pos]);
+        result.push(if done { ' ' } else { 'x' });
+        result.push_str(&raw_line[checkbox_pos + 1..]);
+        toggled = Some(!done);
+    }
+
+    toggled
+        .map(|done| (result, done))
+        .ok_or(format!("Line {} not found", line))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tasks_skips_code_blocks() {
+        let content = "# 할 일\n- [ ] 우유 사기\n  * [x] 완료됨\n1. [ ] 번호 목록\n```\n- [ ] 코드\n```\n- [link](url)\n-[ ] 공백 없음";
+        let tasks = parse_tasks(content);
+
+        assert_eq!(
+            tasks,
+            vec![
+                (2, "우유 사기".to_string(), false, 0),
+                (3, "완료됨".to_string(), true, 2),
+                (4, "번호 목록".to_string(), false, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_toggle_task_line_preserves_crlf() {
+        let content = "# T\r\n- [ ] one\r\n- [x] two\r\n";
+
+        let (toggled, done) = toggle_task_line(content, 2, Some("one")).unwrap();
+        assert!(done);
+        assert_eq!(toggled, "# T\r\n- [x] one\r\n- [x] two\r\n");
+
+        let (toggled, done) = toggle_task_line(&toggled, 3, None).unwrap();
+        assert!(!done);
+        assert_eq!(toggled, "# T\r\n- [x] one\r\n- [ ] two\r\n");
+
+        assert!(toggle_task_line(content, 1, None).is_err());
+        assert!(toggle_task_line(content, 2, Some("changed")).is_err());
+    }
+
+    #[test]
+    fn test_toggle_task_line_skips_code_blocks() {
+        let content = "- [ ] a\n```\n- [ ] code\n```\n- [ ] b\n";
+
+        assert!(toggle_task_line(content, 3, None).is_err());
+        assert!(toggle_task_line(content, 2, None).is_err());
+
+        let (toggled, done) = toggle_task_line(content, 5, Some("b")).unwrap();
+        assert!(done);
+        assert_eq!(toggled, "- [ ] a\n```\n- [ ] code\n```\n- [x] b\n");
+    }
+}
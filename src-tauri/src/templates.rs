@@ -0,0 +1,299 @@
+// 노트 템플릿 (저장소의 templates 폴더에 있는 Markdown 파일)
+//
+// 지원하는 자리표시자:
+//   {{date}}, {{time}}           현재 날짜(YYYY-MM-DD) / 시각(HH:MM), 현지 시간 기준
+//   {{date:%Y년 %m월 %d일}}      chrono 형식 문자열 지정
+//   {{clipboard}}                클립보드 텍스트 (프론트엔드가 읽어서 전달)
+//   {{cursor}}                   노트를 연 뒤 커서를 둘 위치 (본문에서는 제거되고 노트 윈도우 URL로 전달)
+//
+// 템플릿 맨 앞의 front matter에 filename을 지정하면 해당 이름으로 저장한다.
+// 같은 파일이 이미 있으면 새로 만들지 않고 기존 노트를 연다 (데일리 노트용).
+//   ---
+//   filename: daily/{{date}}
+//   ---
+
+use crate::{
+    available_path, extract_title, get_notes_dir, get_storage_dir, open_file_from_path,
+    open_note_window_at, sanitize_file_name, slugify_title, write_atomic,
+};
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, Local};
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const DEFAULT_TEMPLATES: &[(&str, &str)] = &[
+    ("Daily.md", "---\nfilename: daily/{{date}}\n---\n# {{date}}\n\n## 할 일\n- [ ] {{cursor}}\n\n## 메모\n"),
+    ("Meeting.md", "# 회의 {{date}} {{time}}\n\n참석자: \n\n## 안건\n{{cursor}}\n\n## 결정 사항\n\n## 후속 작업\n- [ ] \n"),
+];
+
+#[derive(Serialize)]
+pub struct TemplateInfo {
+    // 템플릿 이름 (확장자 제외)
+    name: String,
+    file_path: String,
+    // front matter에 filename이 지정된 템플릿 (날짜별 파일 등)
+    fixed_file_name: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct TemplateNote {
+    id: String,
+    file_path: String,
+    // {{cursor}} 위치 (UTF-16 오프셋, 에디터에 그대로 사용)
+    cursor: Option<usize>,
+    // 새로 만들지 않고 기존 파일을 연 경우
+    existing: bool,
+}
+
+fn get_templates_dir() -> Result<PathBuf, String> {
+    let dir = get_storage_dir()?.join("templates");
+
+    // 처음 사용할 때 기본 템플릿 생성
+    if !dir.exists() {
+        fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+        for (name, content) in DEFAULT_TEMPLATES {
+            fs::write(dir.join(name), content).map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(dir)
+}
+
+// 템플릿 목록 커맨드
+#[tauri::command]
+pub fn list_templates() -> Result<Vec<TemplateInfo>, String> {
+    let dir = get_templates_dir()?;
+    let mut templates = Vec::new();
+
+    for entry in fs::read_dir(&dir).map_err(|e| e.to_string())?.flatten() {
+        let path = entry.path();
+        if !path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("md"))
+        {
+            continue;
+        }
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let (front_matter, _) = split_front_matter(&content);
+
+        templates.push(TemplateInfo {
+            name: path
+                .file_stem()
+                .map(|s| s.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            file_path: path.to_string_lossy().to_string(),
+            fixed_file_name: front_matter_value(front_matter, "filename"),
+        });
+    }
+
+    templates.sort_by_key(|t| t.name.to_lowercase());
+    Ok(templates)
+}
+
+// 템플릿으로 노트 생성 커맨드: 렌더링 후 저장, 인덱스 등록, 윈도우 열기
+#[tauri::command]
+pub async fn create_note_from_template(
+    app: tauri::AppHandle,
+    name: String,
+    clipboard: Option<String>,
+) -> Result<TemplateNote, String> {
+    let template = read_template(&name)?;
+    let now = Local::now();
+
+    let (front_matter, body) = split_front_matter(&template);
+    let (content, cursor) = render(body, now, clipboard.as_deref());
+
+    let notes_dir = get_notes_dir()?;
+    let (path, existing) = match front_matter_value(front_matter, "filename") {
+        Some(file_name) => {
+            let (file_name, _) = render(&file_name, now, None);
+            let path = fixed_note_path(&notes_dir, &file_name);
+            let existing = path.exists();
+            (path, existing)
+        }
+        None => {
+            // 새 노트(save_new_note)와 같은 제목 기반 이름
+            let file_name = format!("{}.md", slugify_title(&extract_title(&content)));
+            (available_path(&notes_dir, &file_name, None), false)
+        }
+    };
+
+    if !existing {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        write_atomic(&path, content.as_bytes())?;
+    }
+
+    let cursor = if existing { None } else { cursor };
+    let file_path = path.to_string_lossy().to_string();
+    let id = open_file_from_path(app.clone(), file_path.clone()).await?;
+    open_note_window_at(app, id.clone(), cursor).await?;
+
+    Ok(TemplateNote {
+        id,
+        file_path,
+        cursor,
+        existing,
+    })
+}
+
+fn read_template(name: &str) -> Result<String, String> {
+    let template_path = get_templates_dir()?.join(format!("{}.md", sanitize_file_name(name)));
+    fs::read_to_string(&template_path).map_err(|_| format!("Template not found: {}", name))
+}
+
+// 템플릿 본문만 렌더링 (front matter의 filename과 {{cursor}}는 무시)
+pub(crate) fn render_template_body(name: &str, now: DateTime<Local>) -> Result<String, String> {
+    let template = read_template(name)?;
+    let (_, body) = split_front_matter(&template);
+    Ok(render(body, now, None).0)
+}
+
+// notes 폴더 기준 상대 경로 (폴더 구분자는 유지하고 각 부분만 정리)
+pub(crate) fn fixed_note_path(notes_dir: &Path, file_name: &str) -> PathBuf {
+    let mut path = notes_dir.to_path_buf();
+    let segments: Vec<&str> = file_name
+        .split(['/', '\\'])
+        .map(str::trim)
+        .filter(|s| !s.is_empty() && *s != "." && *s != "..")
+        .collect();
+
+    for segment in &segments {
+        path.push(sanitize_file_name(segment));
+    }
+    if segments.is_empty() {
+        path.push("Untitled");
+    }
+    if !path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("md"))
+    {
+        let mut name = path.file_name().unwrap_or_default().to_os_string();
+        name.push(".md");
+        path.set_file_name(name);
+    }
+    path
+}
+
+// "---"로 둘러싼 front matter와 본문 분리
+fn split_front_matter(content: &str) -> (&str, &str) {
+    let Some(rest) = content
+        .strip_prefix("---\n")
+        .or_else(|| content.strip_prefix("---\r\n"))
+    else {
+        return ("", content);
+    };
+
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        if line.trim_end() == "---" {
+            return (&rest[..offset], &rest[offset + line.len()..]);
+        }
+        offset += line.len();
+    }
+    ("", content)
+}
+
+fn front_matter_value(front_matter: &str, key: &str) -> Option<String> {
+    front_matter.lines().find_map(|line| {
+        let (k, v) = line.split_once(':')?;
+        let v = v.trim().trim_matches(['"', '\'']);
+        (k.trim() == key && !v.is_empty()).then(|| v.to_string())
+    })
+}
+
+// 자리표시자 치환, {{cursor}} 위치 반환 (알 수 없는 자리표시자는 그대로 유지)
+fn render(
+    template: &str,
+    now: DateTime<Local>,
+    clipboard: Option<&str>,
+) -> (String, Option<usize>) {
+    let mut result = String::with_capacity(template.len());
+    let mut cursor = None;
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        result.push_str(&rest[..start]);
+        let placeholder = &rest[start + 2..start + 2 + len];
+        let (name, format) = match placeholder.split_once(':') {
+            Some((name, format)) => (name.trim(), Some(format)),
+            None => (placeholder.trim(), None),
+        };
+
+        match name {
+            "date" => result.push_str(&format_time(now, format.unwrap_or("%Y-%m-%d"))),
+            "time" => result.push_str(&format_time(now, format.unwrap_or("%H:%M"))),
+            "clipboard" => result.push_str(clipboard.unwrap_or("")),
+            "cursor" if cursor.is_none() => cursor = Some(result.encode_utf16().count()),
+            "cursor" => {}
+            _ => {
+                result.push_str("{{");
+                result.push_str(placeholder);
+                result.push_str("}}");
+            }
+        }
+        rest = &rest[start + 2 + len + 2..];
+    }
+    result.push_str(rest);
+
+    (result, cursor)
+}
+
+// 잘못된 형식 문자열은 패닉 대신 기본 형식 사용
+pub(crate) fn format_time(now: DateTime<Local>, format: &str) -> String {
+    if StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) {
+        return now.format("%Y-%m-%d").to_string();
+    }
+    now.format(format).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_render_placeholders() {
+        let now = Local.with_ymd_and_hms(2024, 7, 5, 9, 3, 0).unwrap();
+        let (content, cursor) = render(
+            "# {{date}} {{time}}\n{{date:%m/%d}} {{clipboard}}\n- {{cursor}}\n{{unknown}} {{date:%Q}}",
+            now,
+            Some("복사"),
+        );
+
+        assert_eq!(
+            content,
+            "# 2024-07-05 09:03\n07/05 복사\n- \n{{unknown}} 2024-07-05"
+        );
+        assert_eq!(
+            cursor,
+            Some("# 2024-07-05 09:03\n07/05 복사\n- ".encode_utf16().count())
+        );
+    }
+
+    #[test]
+    fn test_front_matter_and_fixed_path() {
+        let (front_matter, body) = split_front_matter("---\nfilename: daily/{{date}}\n---\n# Hi\n");
+        assert_eq!(body, "# Hi\n");
+        assert_eq!(
+            front_matter_value(front_matter, "filename").as_deref(),
+            Some("daily/{{date}}")
+        );
+        assert_eq!(
+            split_front_matter("# No front matter").1,
+            "# No front matter"
+        );
+
+        let notes_dir = Path::new("/notes");
+        assert_eq!(
+            fixed_note_path(notes_dir, "daily/../2024-07-05"),
+            Path::new("/notes/daily/2024-07-05.md")
+        );
+    }
+}
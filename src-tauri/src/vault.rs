@@ -0,0 +1,537 @@
+// 라이브러리 전체 암호화 (저장소 루트의 index.json, 노트, 이미지)
+//
+// - 파일은 무작위 데이터 키(AES-256-GCM)로 암호화: MAGIC + nonce(12바이트) + 암호문
+// - 데이터 키는 마스터 비밀번호(Argon2id)와 복구 키로 각각 감싸서 vault.json에 저장
+// - 풀린 데이터 키는 메모리에만 보관하며, 비밀번호 변경은 데이터 키를 다시 감싸기만 함
+//
+// vault.json이 있으면 암호화가 켜진 상태. 암호화되지 않은 기존 파일도 그대로 읽을 수 있고
+// 다음 저장 시 암호화된다.
+
+use crate::encryption::{derive_key, new_key};
+use crate::{get_notes_dir, get_storage_dir, lock_index, read_index, write_atomic_raw};
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::Emitter;
+
+const VAULT_FILE: &str = "vault.json";
+const MAGIC: &[u8] = b"STICKERMD-VAULT1\n";
+const NONCE_LEN: usize = 12;
+
+// 라이브러리가 잠겨 있을 때 반환하는 오류 (프론트엔드에서 비밀번호 입력 화면 표시)
+pub const LIBRARY_LOCKED: &str = "LIBRARY_LOCKED";
+
+// 풀린 데이터 키 (메모리에만 보관)
+static DATA_KEY: Mutex<Option<[u8; 32]>> = Mutex::new(None);
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct VaultFile {
+    version: u32,
+    password: PasswordSlot,
+    recovery: WrappedKey,
+}
+
+// 마스터 비밀번호로 감싼 데이터 키
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct PasswordSlot {
+    memory_kib: u32,
+    iterations: u32,
+    parallelism: u32,
+    salt: String,
+    #[serde(flatten)]
+    key: WrappedKey,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct WrappedKey {
+    nonce: String,
+    wrapped_key: String,
+}
+
+#[derive(Serialize, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub struct LibraryStatus {
+    enabled: bool,
+    unlocked: bool,
+}
+
+fn wrap(kek: &[u8; 32], data_key: &[u8; 32]) -> Result<WrappedKey, String> {
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(kek));
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let wrapped = cipher
+        .encrypt(&nonce, data_key.as_ref())
+        .map_err(|_| "Failed to wrap library key".to_string())?;
+    Ok(WrappedKey {
+        nonce: STANDARD.encode(nonce),
+        wrapped_key: STANDARD.encode(wrapped),
+    })
+}
+
+fn unwrap(kek: &[u8; 32], wrapped: &WrappedKey, error: &str) -> Result<[u8; 32], String> {
+    let decode = |value: &str| STANDARD.decode(value).map_err(|e| e.to_string());
+    let nonce = decode(&wrapped.nonce)?;
+    if nonce.len() != NONCE_LEN {
+        return Err("Invalid vault file".to_string());
+    }
+
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(kek));
+    let data_key = cipher
+        .decrypt(
+            Nonce::from_slice(&nonce),
+            decode(&wrapped.wrapped_key)?.as_ref(),
+        )
+        .map_err(|_| error.to_string())?;
+    data_key
+        .try_into()
+        .map_err(|_| "Invalid vault file".to_string())
+}
+
+fn password_slot(password: &str, data_key: &[u8; 32]) -> Result<PasswordSlot, String> {
+    let kek = new_key(password)?;
+    Ok(PasswordSlot {
+        memory_kib: kek.memory_kib,
+        iterations: kek.iterations,
+        parallelism: kek.parallelism,
+        salt: STANDARD.encode(&kek.salt),
+        key: wrap(&kek.key, data_key)?,
+    })
+}
+
+// 복구 키: 32바이트 무작위 값을 16진수 8자리씩 '-'로 구분
+fn format_recovery_key(key: &[u8; 32]) -> String {
+    let hex: String = key.iter().map(|b| format!("{:02X}", b)).collect();
+    hex.as_bytes()
+        .chunks(8)
+        .map(|c| String::from_utf8_lossy(c).to_string())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+fn parse_recovery_key(text: &str) -> Result<[u8; 32], String> {
+    let hex: String = text
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .collect();
+    let invalid = || "Invalid recovery key".to_string();
+    if hex.len() != 64 || !hex.is_ascii() {
+        return Err(invalid());
+    }
+
+    let mut key = [0u8; 32];
+    for (i, byte) in key.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).map_err(|_| invalid())?;
+    }
+    Ok(key)
+}
+
+impl VaultFile {
+    // 새 데이터 키와 복구 키 생성, (vault, 데이터 키, 복구 키 문자열) 반환
+    fn create(password: &str) -> Result<(Self, [u8; 32], String), String> {
+        let mut data_key = [0u8; 32];
+        OsRng.fill_bytes(&mut data_key);
+        let mut recovery_key = [0u8; 32];
+        OsRng.fill_bytes(&mut recovery_key);
+
+        let vault = VaultFile {
+            version: 1,
+            password: password_slot(password, &data_key)?,
+            recovery: wrap(&recovery_key, &data_key)?,
+        };
+        Ok((vault, data_key, format_recovery_key(&recovery_key)))
+    }
+
+    fn unlock(&self, password: &str) -> Result<[u8; 32], String> {
+        let slot = &self.password;
+        let salt = STANDARD.decode(&slot.salt).map_err(|e| e.to_string())?;
+        let kek = derive_key(
+            password,
+            salt,
+            slot.memory_kib,
+            slot.iterations,
+            slot.parallelism,
+        )?;
+        unwrap(&kek.key, &slot.key, "Wrong password")
+    }
+
+    fn unlock_with_recovery_key(&self, recovery_key: &str) -> Result<[u8; 32], String> {
+        unwrap(
+            &parse_recovery_key(recovery_key)?,
+            &self.recovery,
+            "Wrong recovery key",
+        )
+    }
+
+    // 데이터 키를 새 비밀번호로 다시 감쌈 (파일은 다시 암호화할 필요 없음)
+    fn set_password(&mut self, data_key: &[u8; 32], new_password: &str) -> Result<(), String> {
+        self.password = password_slot(new_password, data_key)?;
+        Ok(())
+    }
+
+    // 새 복구 키 발급 (이전 복구 키는 더 이상 사용할 수 없음)
+    fn rotate_recovery_key(&mut self, data_key: &[u8; 32]) -> Result<String, String> {
+        let mut recovery_key = [0u8; 32];
+        OsRng.fill_bytes(&mut recovery_key);
+        self.recovery = wrap(&recovery_key, data_key)?;
+        Ok(format_recovery_key(&recovery_key))
+    }
+}
+
+fn encrypt_bytes(data_key: &[u8; 32], plaintext: &[u8]) -> Result<Vec<u8>, String> {
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(data_key));
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext)
+        .map_err(|_| "Failed to encrypt file".to_string())?;
+
+    let mut out = Vec::with_capacity(MAGIC.len() + NONCE_LEN + ciphertext.len());
+    out.extend_from_slice(MAGIC);
+    out.extend_from_slice(&nonce);
+    out.extend_from_slice(&ciphertext);
+    Ok(out)
+}
+
+fn decrypt_bytes(data_key: &[u8; 32], data: &[u8]) -> Result<Vec<u8>, String> {
+    let body = data
+        .strip_prefix(MAGIC)
+        .filter(|b| b.len() >= NONCE_LEN)
+        .ok_or("Invalid encrypted file")?;
+    let (nonce, ciphertext) = body.split_at(NONCE_LEN);
+
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(data_key));
+    cipher
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| "Encrypted file is corrupted".to_string())
+}
+
+fn data_key() -> Option<[u8; 32]> {
+    *DATA_KEY.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn set_data_key(key: Option<[u8; 32]>) {
+    *DATA_KEY.lock().unwrap_or_else(|e| e.into_inner()) = key;
+}
+
+fn get_vault_path() -> Result<PathBuf, String> {
+    Ok(get_storage_dir()?.join(VAULT_FILE))
+}
+
+fn read_vault() -> Result<VaultFile, String> {
+    let content = fs::read_to_string(get_vault_path()?)
+        .map_err(|_| "Library encryption is not enabled".to_string())?;
+    serde_json::from_str(&content).map_err(|e| format!("Invalid vault file: {}", e))
+}
+
+fn write_vault(vault: &VaultFile) -> Result<(), String> {
+    let content = serde_json::to_string_pretty(vault).map_err(|e| e.to_string())?;
+    write_atomic_raw(&get_vault_path()?, content.as_bytes())
+}
+
+// 라이브러리 암호화가 켜져 있는지 확인
+pub fn is_enabled() -> bool {
+    get_vault_path().is_ok_and(|p| p.exists())
+}
+
+pub fn is_unlocked() -> bool {
+    data_key().is_some()
+}
+
+// 파일에서 읽은 내용 복호화 (암호화되지 않은 파일은 그대로 반환)
+pub fn decode(data: Vec<u8>) -> Result<Vec<u8>, String> {
+    if !data.starts_with(MAGIC) {
+        return Ok(data);
+    }
+    let key = data_key().ok_or(LIBRARY_LOCKED)?;
+    decrypt_bytes(&key, &data)
+}
+
+// 파일에 쓸 내용 암호화 (암호화가 켜져 있고 저장소 루트 안의 파일인 경우만)
+pub fn encode<'a>(path: &Path, data: &'a [u8]) -> Result<Cow<'a, [u8]>, String> {
+    if !is_enabled() || !path.starts_with(get_storage_dir()?) {
+        return Ok(Cow::Borrowed(data));
+    }
+    let key = data_key().ok_or(LIBRARY_LOCKED)?;
+    Ok(Cow::Owned(encrypt_bytes(&key, data)?))
+}
+
+// 암호화 대상 파일: index.json, 노트 폴더(노트와 이미지), 저장소 루트의 자동 백업
+fn library_files() -> Result<Vec<PathBuf>, String> {
+    let storage_dir = get_storage_dir()?;
+    let mut files = vec![storage_dir.join("index.json")];
+    collect_files(&get_notes_dir()?, &mut files);
+
+    let backups_dir = storage_dir.join("backups");
+    if let Ok(entries) = fs::read_dir(&backups_dir) {
+        files.extend(
+            entries
+                .flatten()
+                .map(|e| e.path())
+                .filter(|p| p.extension().is_some_and(|ext| ext == "zip")),
+        );
+    }
+
+    // 노트 폴더 밖이지만 저장소 루트 안에 있는 노트
+    if let Ok(index) = read_index() {
+        for note in index.notes {
+            let path = PathBuf::from(note.file_path);
+            if path.starts_with(&storage_dir) && !files.contains(&path) {
+                files.push(path);
+            }
+        }
+    }
+
+    files.retain(|p| p.is_file());
+    Ok(files)
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for path in entries.flatten().map(|e| e.path()) {
+        if path.is_dir() {
+            collect_files(&path, files);
+        } else if !path
+            .file_name()
+            .is_some_and(|n| n.to_string_lossy().ends_with(".tmp"))
+        {
+            files.push(path);
+        }
+    }
+}
+
+// 라이브러리 상태 조회 커맨드 (앱 시작 시 프론트엔드가 비밀번호 입력 여부 판단)
+#[tauri::command]
+pub fn get_library_status() -> LibraryStatus {
+    LibraryStatus {
+        enabled: is_enabled(),
+        unlocked: is_unlocked(),
+    }
+}
+
+// 라이브러리 암호화 켜기 커맨드: 기존 파일을 모두 암호화하고 복구 키 반환
+#[tauri::command]
+pub fn enable_library_encryption(
+    app: tauri::AppHandle,
+    password: String,
+) -> Result<String, String> {
+    if is_enabled() {
+        return Err("Library encryption is already enabled".to_string());
+    }
+
+    // 파일을 다시 쓰는 동안 인덱스 저장이 끼어들지 않도록 잠금 유지
+    let guard = lock_index();
+    // 목록은 암호화를 켜기 전에 수집 (인덱스가 아직 평문)
+    let files = library_files()?;
+    let (vault, data_key, recovery_key) = VaultFile::create(&password)?;
+    write_vault(&vault)?;
+    set_data_key(Some(data_key));
+
+    // 중간에 실패해도 남은 평문 파일은 읽을 수 있고 다음 저장 시 암호화됨
+    for path in files {
+        let data = fs::read(&path).map_err(|e| e.to_string())?;
+        if !data.starts_with(MAGIC) {
+            write_atomic_raw(&path, &encrypt_bytes(&data_key, &data)?)?;
+        }
+    }
+    drop(guard);
+
+    let _ = app.emit("library-unlocked", ());
+    Ok(recovery_key)
+}
+
+// 라이브러리 암호화 끄기 커맨드: 모든 파일을 평문으로 되돌림
+#[tauri::command]
+pub fn disable_library_encryption(app: tauri::AppHandle, password: String) -> Result<(), String> {
+    let data_key = read_vault()?.unlock(&password)?;
+    let guard = lock_index();
+    set_data_key(Some(data_key));
+
+    for path in library_files()? {
+        let data = fs::read(&path).map_err(|e| e.to_string())?;
+        if data.starts_with(MAGIC) {
+            write_atomic_raw(&path, &decrypt_bytes(&data_key, &data)?)?;
+        }
+    }
+
+    // 모든 파일을 복호화한 뒤에만 vault.json 삭제
+    fs::remove_file(get_vault_path()?).map_err(|e| e.to_string())?;
+    set_data_key(None);
+    drop(guard);
+
+    let _ = app.emit("refresh-notes-list", ());
+    Ok(())
+}
+
+// 라이브러리 잠금 해제 커맨드 (비밀번호 또는 복구 키)
+#[tauri::command]
+pub fn unlock_library(
+    app: tauri::AppHandle,
+    password: Option<String>,
+    recovery_key: Option<String>,
+) -> Result<(), String> {
+    let vault = read_vault()?;
+    let data_key = match (password, recovery_key) {
+        (Some(password), _) => vault.unlock(&password)?,
+        (None, Some(recovery_key)) => vault.unlock_with_recovery_key(&recovery_key)?,
+        (None, None) => return Err("Password or recovery key is required".to_string()),
+    };
+    set_data_key(Some(data_key));
+
+    crate::start_background_tasks(&app);
+    let _ = app.emit("library-unlocked", ());
+    let _ = app.emit("refresh-notes-list", ());
+    crate::deep_link::handle_pending(&app);
+    Ok(())
+}
+
+// 라이브러리 잠금 커맨드: 메모리의 키를 지우고 열린 노트 윈도우 닫기
+#[tauri::command]
+pub fn lock_library(app: tauri::AppHandle) {
+    use tauri::Manager;
+
+    set_data_key(None);
+    for (label, window) in app.webview_windows() {
+        if label.starts_with("note_") {
+            let _ = window.close();
+        }
+    }
+    let _ = app.emit("library-locked", ());
+}
+
+// 마스터 비밀번호 변경 커맨드 (현재 비밀번호 또는 복구 키로 확인)
+#[tauri::command]
+pub fn change_library_password(
+    current_password: Option<String>,
+    recovery_key: Option<String>,
+    new_password: String,
+) -> Result<(), String> {
+    let mut vault = read_vault()?;
+    let data_key = match (current_password, recovery_key) {
+        (Some(password), _) => vault.unlock(&password)?,
+        (None, Some(recovery_key)) => vault.unlock_with_recovery_key(&recovery_key)?,
+        (None, None) => return Err("Current password or recovery key is required".to_string()),
+    };
+    vault.set_password(&data_key, &new_password)?;
+    write_vault(&vault)?;
+    set_data_key(Some(data_key));
+    Ok(())
+}
+
+// 복구 키 내보내기 커맨드: 새 복구 키를 발급하여 텍스트 파일로 저장하고 경로 반환
+// output_path가 없으면 저장 다이얼로그 표시
+#[tauri::command]
+pub async fn export_recovery_key(
+    app: tauri::AppHandle,
+    password: String,
+    output_path: Option<String>,
+) -> Result<String, String> {
+    use tauri_plugin_dialog::{DialogExt, FilePath};
+
+    let mut vault = read_vault()?;
+    let data_key = vault.unlock(&password)?;
+
+    let output = match output_path {
+        Some(path) => PathBuf::from(path),
+        None => {
+            let file_path = app
+                .dialog()
+                .file()
+                .add_filter("Text", &["txt"])
+                .set_file_name("StickerMD-recovery-key.txt")
+                .blocking_save_file();
+
+            match file_path {
+                Some(FilePath::Path(path)) => path,
+                _ => return Err("No file selected".to_string()),
+            }
+        }
+    };
+    // 복구 키를 암호화된 저장소 안에 두면 잠겼을 때 꺼낼 수 없음
+    if output.starts_with(get_storage_dir()?) {
+        return Err("Recovery key must be saved outside the library folder".to_string());
+    }
+
+    let recovery_key = vault.rotate_recovery_key(&data_key)?;
+    fs::write(
+        &output,
+        format!(
+            "StickerMD library recovery key\n\n{}\n\nCreated: {}\n",
+            recovery_key,
+            chrono::Local::now().format("%Y-%m-%d %H:%M")
+        ),
+    )
+    .map_err(|e| e.to_string())?;
+    write_vault(&vault)?;
+
+    Ok(output.to_string_lossy().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_change_password_keeps_data_key() {
+        let (mut vault, data_key, recovery_key) = VaultFile::create("old password").unwrap();
+        let sealed = encrypt_bytes(&data_key, "# 비밀 노트".as_bytes()).unwrap();
+
+        vault.set_password(&data_key, "new password").unwrap();
+        // vault.json을 다시 읽은 것처럼 직렬화 후 확인
+        let vault: VaultFile =
+            serde_json::from_str(&serde_json::to_string(&vault).unwrap()).unwrap();
+
+        assert!(vault.unlock("old password").is_err());
+        let unlocked = vault.unlock("new password").unwrap();
+        assert_eq!(unlocked, data_key);
+        assert_eq!(
+            decrypt_bytes(&unlocked, &sealed).unwrap(),
+            "# 비밀 노트".as_bytes()
+        );
+        // 비밀번호를 바꿔도 복구 키는 유지
+        assert_eq!(
+            vault.unlock_with_recovery_key(&recovery_key).unwrap(),
+            data_key
+        );
+    }
+
+    #[test]
+    fn test_recovery_key_rotation() {
+        let (mut vault, data_key, old_key) = VaultFile::create("password").unwrap();
+        let new_key = vault.rotate_recovery_key(&data_key).unwrap();
+
+        assert!(vault.unlock_with_recovery_key(&old_key).is_err());
+        // 대소문자, 공백, 구분자가 달라도 같은 키로 인식
+        let typed = new_key.to_lowercase().replace('-', " ");
+        assert_eq!(vault.unlock_with_recovery_key(&typed).unwrap(), data_key);
+        assert!(parse_recovery_key("ABCD").is_err());
+    }
+
+    #[test]
+    fn test_file_encryption() {
+        let mut data_key = [0u8; 32];
+        OsRng.fill_bytes(&mut data_key);
+
+        let sealed = encrypt_bytes(&data_key, b"{\"notes\":[]}").unwrap();
+        assert!(sealed.starts_with(MAGIC));
+        assert_eq!(
+            decrypt_bytes(&data_key, &sealed).unwrap(),
+            b"{\"notes\":[]}"
+        );
+
+        let mut tampered = sealed.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(decrypt_bytes(&data_key, &tampered).is_err());
+
+        // 암호화되지 않은 파일은 그대로 통과
+        assert_eq!(decode(b"# plain".to_vec()).unwrap(), b"# plain");
+    }
+}
@@ -0,0 +1,511 @@
+// WebDAV 동기화 대상 (Nextcloud, ownCloud, NAS 등)
+//
+// 설정의 URL 아래에 폴더 동기화와 같은 구조(index.json, notes/, images/)로 저장한다.
+// 파일 변경은 서버의 ETag로 구분하고, index.json은 If-Match 조건부 PUT으로만 덮어쓴다.
+
+use crate::sync::{Condition, Remote, PRECONDITION_FAILED};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::time::Duration;
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+// 경로 구성 요소 하나에 허용되는 문자 외에는 모두 인코딩
+const SEGMENT_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+const PROPFIND_BODY: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<d:propfind xmlns:d="DAV:"><d:prop><d:getetag/><d:resourcetype/></d:prop></d:propfind>"#;
+
+pub(crate) struct WebDavRemote {
+    agent: ureq::Agent,
+    base_url: String,
+    authorization: Option<String>,
+}
+
+impl WebDavRemote {
+    pub(crate) fn new(
+        url: &str,
+        username: Option<&str>,
+        password: Option<&str>,
+    ) -> Result<Self, String> {
+        let url = url.trim().trim_end_matches('/');
+        if !url.starts_with("http://") && !url.starts_with("https://") {
+            return Err("WebDAV URL must start with http:// or https://".to_string());
+        }
+
+        let authorization = username.filter(|u| !u.is_empty()).map(|username| {
+            let credentials = format!("{}:{}", username, password.unwrap_or_default());
+            format!("Basic {}", STANDARD.encode(credentials))
+        });
+        Ok(Self {
+            agent: ureq::AgentBuilder::new().timeout(TIMEOUT).build(),
+            base_url: url.to_string(),
+            authorization,
+        })
+    }
+
+    fn url(&self, path: &str) -> String {
+        let mut url = self.base_url.clone();
+        for segment in path.split('/').filter(|s| !s.is_empty()) {
+            url.push('/');
+            url.push_str(&utf8_percent_encode(segment, SEGMENT_ENCODE_SET).to_string());
+        }
+        url
+    }
+
+    // 요청 전송, 404는 None
+    fn send(
+        &self,
+        method: &str,
+        path: &str,
+        headers: &[(&str, &str)],
+        body: Option<&[u8]>,
+    ) -> Result<Option<ureq::Response>, String> {
+        let mut request = self.agent.request(method, &self.url(path));
+        if let Some(authorization) = &self.authorization {
+            request = request.set("Authorization", authorization);
+        }
+        for (name, value) in headers {
+            request = request.set(name, value);
+        }
+
+        let result = match body {
+            Some(body) => request.send_bytes(body),
+            None => request.call(),
+        };
+        match result {
+            Ok(response) => Ok(Some(response)),
+            Err(ureq::Error::Status(404, _)) => Ok(None),
+            Err(ureq::Error::Status(412, _)) => Err(PRECONDITION_FAILED.to_string()),
+            Err(ureq::Error::Status(401, _)) => Err("WebDAV authentication failed".to_string()),
+            Err(ureq::Error::Status(code, _)) => {
+                Err(format!("WebDAV {} {} failed: HTTP {}", method, path, code))
+            }
+            Err(e) => Err(format!("WebDAV request failed: {}", e)),
+        }
+    }
+
+    // 서버가 준 그대로의 ETag (약한 ETag의 W/ 포함, If-Match에 그대로 사용)
+    fn etag_of(response: &ureq::Response) -> Option<String> {
+        response.header("ETag").map(|etag| etag.trim().to_string())
+    }
+
+    fn mkcol(&self, path: &str) -> Result<(), String> {
+        self.send("MKCOL", path, &[], None)?
+            .map(|_| ())
+            .ok_or_else(|| format!("WebDAV folder not found: {}", self.base_url))
+    }
+}
+
+impl Remote for WebDavRemote {
+    fn get(&mut self, path: &str) -> Result<Option<(Vec<u8>, String)>, String> {
+        let Some(response) = self.send("GET", path, &[], None)? else {
+            return Ok(None);
+        };
+        let etag = Self::etag_of(&response).unwrap_or_default();
+        let mut bytes = Vec::new();
+        response
+            .into_reader()
+            .read_to_end(&mut bytes)
+            .map_err(|e| e.to_string())?;
+        Ok(Some((bytes, etag)))
+    }
+
+    fn put(&mut self, path: &str, data: &[u8], condition: Condition) -> Result<String, String> {
+        let condition = match condition {
+            Condition::Always => None,
+            Condition::Matches(etag) => Some(("If-Match", if_match_value(etag))),
+            Condition::Missing => Some(("If-None-Match", "*".to_string())),
+        };
+        let headers: Vec<(&str, &str)> = condition
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        let response = self
+            .send("PUT", path, &headers, Some(data))?
+            .ok_or_else(|| format!("WebDAV folder not found: {}", path))?;
+
+        // PUT 응답에 ETag를 주지 않는 서버는 다시 조회
+        if let Some(etag) = Self::etag_of(&response) {
+            return Ok(etag);
+        }
+        self.send("HEAD", path, &[], None)?
+            .as_ref()
+            .and_then(Self::etag_of)
+            .ok_or_else(|| "WebDAV server did not return an ETag".to_string())
+    }
+
+    fn delete(&mut self, path: &str) -> Result<(), String> {
+        self.send("DELETE", path, &[], None).map(|_| ())
+    }
+
+    fn list(&mut self, dir: &str) -> Result<BTreeMap<String, String>, String> {
+        let headers = [
+            ("Depth", "1"),
+            ("Content-Type", "application/xml; charset=utf-8"),
+        ];
+        let response = self.send("PROPFIND", dir, &headers, Some(PROPFIND_BODY.as_bytes()))?;
+        let Some(response) = response else {
+            // 처음 동기화할 때는 폴더가 없으므로 생성 (상위 폴더가 없으면 함께 생성)
+            if self.mkcol(dir).is_err() {
+                let _ = self.mkcol("");
+                self.mkcol(dir)?;
+            }
+            return Ok(BTreeMap::new());
+        };
+
+        let xml = response.into_string().map_err(|e| e.to_string())?;
+        Ok(parse_multistatus(&xml))
+    }
+}
+
+// PROPFIND 응답에서 파일 이름 -> ETag (폴더 제외)
+fn parse_multistatus(xml: &str) -> BTreeMap<String, String> {
+    let mut files = BTreeMap::new();
+    for response in xml_elements(xml, "response") {
+        if !xml_elements(response, "collection").is_empty() {
+            continue;
+        }
+        let Some(href) = xml_elements(response, "href").first().map(|h| unescape(h)) else {
+            continue;
+        };
+        let Some(etag) = xml_elements(response, "getetag")
+            .first()
+            .map(|e| unescape(e))
+        else {
+            continue;
+        };
+
+        let name = href
+            .trim_end_matches('/')
+            .rsplit('/')
+            .next()
+            .unwrap_or_default();
+        let name = percent_decode_str(name).decode_utf8_lossy().to_string();
+        if !name.is_empty() {
+            files.insert(name, etag.trim().to_string());
+        }
+    }
+    files
+}
+
+// 이름공간 접두사와 관계없이 로컬 이름이 같은 요소들의 내용
+fn xml_elements<'a>(xml: &'a str, local_name: &str) -> Vec<&'a str> {
+    let mut elements = Vec::new();
+    let mut rest = xml;
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start + 1..];
+        let Some(end) = rest.find('>') else {
+            break;
+        };
+        let tag = &rest[..end];
+        rest = &rest[end + 1..];
+        if tag.starts_with(['/', '?', '!']) {
+            continue;
+        }
+
+        let name = tag
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default();
+        if name.rsplit(':').next() != Some(local_name) {
+            continue;
+        }
+        if tag.ends_with('/') {
+            elements.push("");
+            continue;
+        }
+
+        let closing = format!("</{}>", name);
+        let Some(close) = rest.find(&closing) else {
+            break;
+        };
+        elements.push(&rest[..close]);
+        rest = &rest[close + closing.len()..];
+    }
+    elements
+}
+
+fn unescape(text: &str) -> String {
+    text.trim()
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+// 약한 ETag 표시와 따옴표 제거 (비교용)
+fn normalize_etag(etag: &str) -> &str {
+    etag.trim().trim_start_matches("W/").trim_matches('"')
+}
+
+// 두 ETag가 같은 버전을 가리키는지 (W/와 따옴표 차이는 무시)
+pub(crate) fn same_etag(a: &str, b: &str) -> bool {
+    normalize_etag(a) == normalize_etag(b)
+}
+
+// If-Match 헤더 값: 서버가 준 ETag를 그대로 쓰고, 따옴표 없이 저장된 이전 값만 따옴표로 감쌈
+fn if_match_value(etag: &str) -> String {
+    let etag = etag.trim();
+    if etag.starts_with('"') || etag.starts_with("W/") {
+        etag.to_string()
+    } else {
+        format!("\"{}\"", etag)
+    }
+}
+
+// 테스트용 WebDAV 서버 (GET, HEAD, PUT, DELETE, MKCOL, PROPFIND만 지원)
+#[cfg(test)]
+pub(crate) mod test_server {
+    use super::STANDARD;
+    use base64::Engine;
+    use std::collections::{BTreeMap, BTreeSet};
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::sync::{Arc, Mutex};
+
+    const ROOT: &str = "/dav";
+
+    #[derive(Default)]
+    struct Store {
+        files: BTreeMap<String, (Vec<u8>, u64)>,
+        folders: BTreeSet<String>,
+        next_etag: u64,
+    }
+
+    // 서버를 시작하고 기본 URL 반환 (사용자 user, 비밀번호 secret)
+    pub(crate) fn start() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let mut store = Store::default();
+        store.folders.insert(ROOT.to_string());
+        let store = Arc::new(Mutex::new(store));
+
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let store = store.clone();
+                std::thread::spawn(move || serve(stream, &store));
+            }
+        });
+        format!("http://{}{}", addr, ROOT)
+    }
+
+    fn serve(stream: TcpStream, store: &Mutex<Store>) {
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut writer = stream;
+        let expected = format!("Basic {}", STANDARD.encode("user:secret"));
+
+        loop {
+            let mut line = String::new();
+            if reader.read_line(&mut line).unwrap_or(0) == 0 {
+                return;
+            }
+            let mut parts = line.split_whitespace();
+            let method = parts.next().unwrap_or_default().to_string();
+            let path = parts
+                .next()
+                .unwrap_or_default()
+                .trim_end_matches('/')
+                .to_string();
+
+            let mut headers = BTreeMap::new();
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                let header = header.trim_end();
+                if header.is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = header.split_once(':') {
+                    headers.insert(name.trim().to_lowercase(), value.trim().to_string());
+                }
+            }
+            let length = headers
+                .get("content-length")
+                .and_then(|l| l.parse().ok())
+                .unwrap_or(0);
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+
+            let (status, etag, body) = if headers.get("authorization") != Some(&expected) {
+                (401, None, Vec::new())
+            } else {
+                handle(&mut store.lock().unwrap(), &method, &path, &headers, body)
+            };
+
+            let mut response = format!(
+                "HTTP/1.1 {} X\r\nContent-Length: {}\r\n",
+                status,
+                body.len()
+            );
+            if let Some(etag) = etag {
+                response.push_str(&format!("ETag: \"{}\"\r\n", etag));
+            }
+            response.push_str("\r\n");
+            let _ = writer.write_all(response.as_bytes());
+            if method != "HEAD" {
+                let _ = writer.write_all(&body);
+            }
+        }
+    }
+
+    fn handle(
+        store: &mut Store,
+        method: &str,
+        path: &str,
+        headers: &BTreeMap<String, String>,
+        body: Vec<u8>,
+    ) -> (u16, Option<u64>, Vec<u8>) {
+        let parent = path.rsplit_once('/').map(|(p, _)| p).unwrap_or_default();
+        match method {
+            "GET" | "HEAD" => match store.files.get(path) {
+                Some((data, etag)) => (200, Some(*etag), data.clone()),
+                None => (404, None, Vec::new()),
+            },
+            "PUT" => {
+                if !store.folders.contains(parent) {
+                    return (409, None, Vec::new());
+                }
+                let current = store
+                    .files
+                    .get(path)
+                    .map(|(_, etag)| format!("\"{}\"", etag));
+                let matches = headers
+                    .get("if-match")
+                    .is_none_or(|etag| current.as_ref() == Some(etag));
+                let missing = !headers.contains_key("if-none-match") || current.is_none();
+                if !matches || !missing {
+                    return (412, None, Vec::new());
+                }
+                store.next_etag += 1;
+                let etag = store.next_etag;
+                store.files.insert(path.to_string(), (body, etag));
+                (201, Some(etag), Vec::new())
+            }
+            "DELETE" => match store.files.remove(path) {
+                Some(_) => (204, None, Vec::new()),
+                None => (404, None, Vec::new()),
+            },
+            "MKCOL" => {
+                if store.folders.contains(path) {
+                    (405, None, Vec::new())
+                } else if !store.folders.contains(parent) {
+                    (409, None, Vec::new())
+                } else {
+                    store.folders.insert(path.to_string());
+                    (201, None, Vec::new())
+                }
+            }
+            "PROPFIND" => {
+                if !store.folders.contains(path) {
+                    return (404, None, Vec::new());
+                }
+                let mut xml = format!(
+                    "<?xml version=\"1.0\"?><d:multistatus xmlns:d=\"DAV:\"><d:response><d:href>{}/</d:href><d:propstat><d:prop><d:resourcetype><d:collection/></d:resourcetype></d:prop></d:propstat></d:response>",
+                    path
+                );
+                let prefix = format!("{}/", path);
+                for (file, (_, etag)) in &store.files {
+                    let Some(name) = file.strip_prefix(&prefix) else {
+                        continue;
+                    };
+                    if name.contains('/') {
+                        continue;
+                    }
+                    xml.push_str(&format!(
+                        "<d:response><d:href>{}</d:href><d:propstat><d:prop><d:getetag>&quot;{}&quot;</d:getetag><d:resourcetype/></d:prop></d:propstat></d:response>",
+                        file, etag
+                    ));
+                }
+                xml.push_str("</d:multistatus>");
+                (207, None, xml.into_bytes())
+            }
+            _ => (405, None, Vec::new()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_multistatus() {
+        let xml = r#"<?xml version="1.0"?>
+<d:multistatus xmlns:d="DAV:" xmlns:oc="http://owncloud.org/ns">
+  <d:response>
+    <d:href>/remote.php/dav/files/me/StickerMD/notes/</d:href>
+    <d:propstat><d:prop><d:resourcetype><d:collection/></d:resourcetype></d:prop></d:propstat>
+  </d:response>
+  <d:response>
+    <d:href>/remote.php/dav/files/me/StickerMD/notes/%ED%9A%8C%EC%9D%98%20%EB%A9%94%EB%AA%A8.md</d:href>
+    <d:propstat><d:prop><d:getetag>&quot;5f2a&quot;</d:getetag><d:resourcetype/></d:prop></d:propstat>
+  </d:response>
+  <D:response xmlns:D="DAV:">
+    <D:href>/remote.php/dav/files/me/StickerMD/notes/a&amp;b.md</D:href>
+    <D:propstat><D:prop><D:getetag>W/"77"</D:getetag></D:prop></D:propstat>
+  </D:response>
+</d:multistatus>"#;
+
+        let files = parse_multistatus(xml);
+        assert_eq!(files.len(), 2);
+        assert_eq!(files["회의 메모.md"], "\"5f2a\"");
+        assert_eq!(files["a&b.md"], "W/\"77\"");
+    }
+
+    #[test]
+    fn test_etag_comparison_and_if_match() {
+        assert!(same_etag("W/\"77\"", "\"77\""));
+        assert!(same_etag("\"5f2a\"", "5f2a"));
+        assert!(!same_etag("\"5f2a\"", "\"5f2b\""));
+
+        // 약한 ETag는 W/를 유지한 채로 보냄
+        assert_eq!(if_match_value("W/\"77\""), "W/\"77\"");
+        assert_eq!(if_match_value("\"5f2a\""), "\"5f2a\"");
+        assert_eq!(if_match_value("5f2a"), "\"5f2a\"");
+    }
+
+    #[test]
+    fn test_remote_operations() {
+        let url = test_server::start();
+        let mut remote = WebDavRemote::new(&url, Some("user"), Some("secret")).unwrap();
+
+        // 폴더가 없으면 생성 후 빈 목록
+        assert!(remote.list("notes").unwrap().is_empty());
+        let etag = remote
+            .put("notes/할 일.md", b"# a", Condition::Missing)
+            .unwrap();
+        assert_eq!(remote.list("notes").unwrap()["할 일.md"], etag);
+        assert_eq!(
+            remote.get("notes/할 일.md").unwrap(),
+            Some((b"# a".to_vec(), etag.clone()))
+        );
+
+        // 조건부 쓰기
+        assert_eq!(
+            remote.put("notes/할 일.md", b"# b", Condition::Missing),
+            Err(PRECONDITION_FAILED.to_string())
+        );
+        let next = remote
+            .put("notes/할 일.md", b"# b", Condition::Matches(&etag))
+            .unwrap();
+        assert_ne!(next, etag);
+        assert_eq!(
+            remote.put("notes/할 일.md", b"# c", Condition::Matches(&etag)),
+            Err(PRECONDITION_FAILED.to_string())
+        );
+
+        remote.delete("notes/할 일.md").unwrap();
+        remote.delete("notes/할 일.md").unwrap();
+        assert_eq!(remote.get("notes/할 일.md").unwrap(), None);
+
+        // 인증 실패
+        let mut anonymous = WebDavRemote::new(&url, None, None).unwrap();
+        assert!(anonymous.get("index.json").is_err());
+    }
+}
@@ -2,8 +2,11 @@ import "./App.css";
 import { useState, useEffect } from "react";
 import { getCurrentWebviewWindow } from "@tauri-apps/api/webviewWindow";
 import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
 import { NotesList } from "./pages/NotesList";
 import { Note } from "./components/Note";
+import { UnlockLibrary } from "./components/UnlockLibrary";
+import { getLibraryStatus, isLibraryLocked } from "./api/library";
 
 function App() {
   // IMMEDIATE LOG - executes as soon as this component loads
@@ -13,6 +16,18 @@ function App() {
   const [isNoteWindow, setIsNoteWindow] = useState(false);
   const [noteId, setNoteId] = useState<string | null>(null);
   const [loading, setLoading] = useState(true);
+  // 라이브러리 암호화가 켜져 있고 아직 잠금 해제 전이면 비밀번호 입력 화면 표시
+  const [libraryLocked, setLibraryLocked] = useState(false);
+
+  useEffect(() => {
+    const unlistenLockedPromise = listen('library-locked', () => setLibraryLocked(true));
+    const unlistenUnlockedPromise = listen('library-unlocked', () => setLibraryLocked(false));
+
+    return () => {
+      unlistenLockedPromise.then(unlisten => unlisten());
+      unlistenUnlockedPromise.then(unlisten => unlisten());
+    };
+  }, []);
 
   useEffect(() => {
     const checkWindowType = async () => {
@@ -22,6 +37,8 @@ function App() {
         const webviewWindow = getCurrentWebviewWindow();
         await invoke('frontend_log', { message: '[App.tsx] Got webview window' });
 
+        setLibraryLocked(isLibraryLocked(await getLibraryStatus()));
+
         const label = webviewWindow.label;
         await invoke('frontend_log', { message: `[App.tsx] Window label: ${label}` });
         await invoke('frontend_log', { message: `[App.tsx] Current URL: ${window.location.href}` });
@@ -60,6 +77,10 @@ function App() {
     );
   }
 
+  if (libraryLocked) {
+    return <UnlockLibrary onUnlocked={() => setLibraryLocked(false)} />;
+  }
+
   // Note 윈도우면 Note 컴포넌트 (TitleBar 포함)
   if (isNoteWindow) {
     return (
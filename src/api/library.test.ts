@@ -0,0 +1,34 @@
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { invoke } from '@tauri-apps/api/core';
+import { getLibraryStatus, isLibraryLocked, unlockLibrary } from './library';
+
+vi.mock('@tauri-apps/api/core', () => ({
+    invoke: vi.fn(),
+}));
+
+describe('Library API', () => {
+    beforeEach(() => {
+        vi.mocked(invoke).mockReset();
+    });
+
+    it('reports a locked library only when encryption is enabled', async () => {
+        vi.mocked(invoke).mockResolvedValue({ enabled: true, unlocked: false });
+
+        const status = await getLibraryStatus();
+
+        expect(invoke).toHaveBeenCalledWith('get_library_status');
+        expect(isLibraryLocked(status)).toBe(true);
+        expect(isLibraryLocked({ enabled: false, unlocked: false })).toBe(false);
+        expect(isLibraryLocked({ enabled: true, unlocked: true })).toBe(false);
+    });
+
+    it('unlocks with either a password or a recovery key', async () => {
+        vi.mocked(invoke).mockResolvedValue(undefined);
+
+        await unlockLibrary({ password: 'secret' });
+        expect(invoke).toHaveBeenCalledWith('unlock_library', { password: 'secret', recoveryKey: null });
+
+        await unlockLibrary({ recoveryKey: 'ABCD-EFGH' });
+        expect(invoke).toHaveBeenCalledWith('unlock_library', { password: null, recoveryKey: 'ABCD-EFGH' });
+    });
+});
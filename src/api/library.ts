@@ -0,0 +1,31 @@
+import { invoke } from '@tauri-apps/api/core';
+
+// 라이브러리가 잠겨 있을 때 커맨드가 반환하는 오류
+export const LIBRARY_LOCKED = 'LIBRARY_LOCKED';
+
+export interface LibraryStatus {
+    enabled: boolean;
+    unlocked: boolean;
+}
+
+// 라이브러리 암호화 상태 (암호화가 켜져 있고 잠겨 있으면 비밀번호 입력 필요)
+export const getLibraryStatus = (): Promise<LibraryStatus> => invoke<LibraryStatus>('get_library_status');
+
+export const isLibraryLocked = (status: LibraryStatus): boolean => status.enabled && !status.unlocked;
+
+// 마스터 비밀번호 또는 복구 키로 잠금 해제
+export const unlockLibrary = (secret: { password?: string; recoveryKey?: string }): Promise<void> =>
+    invoke('unlock_library', { password: secret.password ?? null, recoveryKey: secret.recoveryKey ?? null });
+
+export const lockLibrary = (): Promise<void> => invoke('lock_library');
+
+// 암호화를 켜고 복구 키 반환 (이 키는 다시 볼 수 없으므로 사용자에게 보관하도록 안내)
+export const enableLibraryEncryption = (password: string): Promise<string> =>
+    invoke<string>('enable_library_encryption', { password });
+
+export const disableLibraryEncryption = (password: string): Promise<void> =>
+    invoke('disable_library_encryption', { password });
+
+// 새 복구 키를 발급해 파일로 저장 (저장 다이얼로그 표시), 저장한 경로 반환
+export const exportRecoveryKey = (password: string): Promise<string> =>
+    invoke<string>('export_recovery_key', { password, outputPath: null });
@@ -1,16 +1,19 @@
 import { describe, it, expect, vi } from 'vitest';
-import { syncNote } from './sync';
+import { invoke } from '@tauri-apps/api/core';
+import { syncNow } from './sync';
 
-describe('Sync API', () => {
-    it('logs to console and returns true', async () => {
-        const consoleSpy = vi.spyOn(console, 'log');
-        const note = { content: 'test', updatedAt: new Date() };
+vi.mock('@tauri-apps/api/core', () => ({
+    invoke: vi.fn(),
+}));
 
-        const result = await syncNote(note);
+describe('Sync API', () => {
+    it('invokes sync_now and returns the report', async () => {
+        const report = { uploaded: 1, downloaded: 2, deletedLocal: 0, deletedRemote: 0, conflicts: 1, skipped: 0 };
+        vi.mocked(invoke).mockResolvedValue(report);
 
-        expect(consoleSpy).toHaveBeenCalledWith('Syncing note to server:', note);
-        expect(result).toBe(true);
+        const result = await syncNow();
 
-        consoleSpy.mockRestore();
+        expect(invoke).toHaveBeenCalledWith('sync_now');
+        expect(result).toEqual(report);
     });
 });
@@ -1,11 +1,13 @@
-export interface NoteState {
-    content: string;
-    updatedAt: Date;
+import { invoke } from '@tauri-apps/api/core';
+
+export interface SyncReport {
+    uploaded: number;
+    downloaded: number;
+    deletedLocal: number;
+    deletedRemote: number;
+    conflicts: number;
+    skipped: number;
 }
 
-// 서버 동기화 스텁 함수: 실제 서버 통신 대신 콘솔에 로그를 출력합니다.
-export const syncNote = async (note: NoteState): Promise<boolean> => {
-    console.log('Syncing note to server:', note);
-    // 성공적으로 동기화되었다고 가정하고 true 반환
-    return Promise.resolve(true);
-};
+// 설정된 동기화 폴더와 노트 라이브러리를 동기화합니다. (충돌 시 충돌 사본 생성)
+export const syncNow = (): Promise<SyncReport> => invoke<SyncReport>('sync_now');
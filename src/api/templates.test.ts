@@ -0,0 +1,44 @@
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { invoke } from '@tauri-apps/api/core';
+import { createNoteFromTemplate, listTemplates } from './templates';
+
+vi.mock('@tauri-apps/api/core', () => ({
+    invoke: vi.fn(),
+}));
+
+const mockClipboard = (readText: () => Promise<string>) => {
+    Object.defineProperty(navigator, 'clipboard', { value: { readText }, configurable: true });
+};
+
+describe('Templates API', () => {
+    beforeEach(() => {
+        vi.mocked(invoke).mockReset();
+    });
+
+    it('invokes list_templates', async () => {
+        vi.mocked(invoke).mockResolvedValue([]);
+
+        await listTemplates();
+
+        expect(invoke).toHaveBeenCalledWith('list_templates');
+    });
+
+    it('passes the clipboard text to create_note_from_template', async () => {
+        mockClipboard(() => Promise.resolve('copied text'));
+        vi.mocked(invoke).mockResolvedValue({ id: 'n1', file_path: '/n1.md', cursor: 3, existing: false });
+
+        const note = await createNoteFromTemplate('Meeting');
+
+        expect(invoke).toHaveBeenCalledWith('create_note_from_template', { name: 'Meeting', clipboard: 'copied text' });
+        expect(note.cursor).toBe(3);
+    });
+
+    it('creates the note without clipboard text when reading fails', async () => {
+        mockClipboard(() => Promise.reject(new Error('denied')));
+        vi.mocked(invoke).mockResolvedValue({ id: 'n1', file_path: '/n1.md', cursor: null, existing: false });
+
+        await createNoteFromTemplate('Meeting');
+
+        expect(invoke).toHaveBeenCalledWith('create_note_from_template', { name: 'Meeting', clipboard: null });
+    });
+});
@@ -0,0 +1,33 @@
+import { invoke } from '@tauri-apps/api/core';
+
+export interface TemplateInfo {
+    name: string;
+    file_path: string;
+    // front matter에 filename이 지정된 템플릿 (날짜별 파일 등)
+    fixed_file_name: string | null;
+}
+
+export interface TemplateNote {
+    id: string;
+    file_path: string;
+    // {{cursor}} 위치 (UTF-16 오프셋)
+    cursor: number | null;
+    // 새로 만들지 않고 기존 파일을 연 경우
+    existing: boolean;
+}
+
+// 저장소의 templates 폴더에 있는 템플릿 목록을 가져옵니다.
+export const listTemplates = (): Promise<TemplateInfo[]> => invoke<TemplateInfo[]>('list_templates');
+
+// 클립보드를 읽을 수 없으면 (권한 거부 등) {{clipboard}}는 빈 문자열로 치환됩니다.
+const readClipboard = async (): Promise<string | null> => {
+    try {
+        return await navigator.clipboard.readText();
+    } catch {
+        return null;
+    }
+};
+
+// 템플릿으로 노트를 만들고 노트 윈도우를 엽니다. {{clipboard}}에는 현재 클립보드 텍스트를 넣습니다.
+export const createNoteFromTemplate = async (name: string): Promise<TemplateNote> =>
+    invoke<TemplateNote>('create_note_from_template', { name, clipboard: await readClipboard() });
@@ -14,12 +14,15 @@ import { setupDropHandler, dropEventHandler } from './dropHandler';
 
 interface CodeMirrorEditorProps {
     initialContent: string;
+    // 처음 커서 위치 (UTF-16 오프셋, 템플릿의 {{cursor}})
+    initialCursor?: number;
     onChange: (content: string) => void;
     className?: string;
 }
 
 export const CodeMirrorEditor: React.FC<CodeMirrorEditorProps> = ({
     initialContent,
+    initialCursor,
     onChange,
     className,
 }) => {
@@ -85,6 +88,9 @@ export const CodeMirrorEditor: React.FC<CodeMirrorEditorProps> = ({
         // Initial State
         const startState = EditorState.create({
             doc: initialContent,
+            selection: initialCursor !== undefined
+                ? { anchor: Math.min(initialCursor, initialContent.length) }
+                : undefined,
             extensions: [
                 keymap.of([...defaultKeymap, ...historyKeymap, indentWithTab]),
                 history(),
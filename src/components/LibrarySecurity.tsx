@@ -0,0 +1,128 @@
+import React, { useEffect, useState } from 'react';
+import { X } from 'lucide-react';
+import {
+    LibraryStatus,
+    disableLibraryEncryption,
+    enableLibraryEncryption,
+    exportRecoveryKey,
+    getLibraryStatus,
+    lockLibrary,
+} from '../api/library';
+
+interface LibrarySecurityProps {
+    onClose: () => void;
+}
+
+// 라이브러리 암호화 설정 패널 (켜기/끄기, 지금 잠그기, 복구 키 내보내기)
+export const LibrarySecurity: React.FC<LibrarySecurityProps> = ({ onClose }) => {
+    const [status, setStatus] = useState<LibraryStatus | null>(null);
+    const [password, setPassword] = useState('');
+    const [confirm, setConfirm] = useState('');
+    // 암호화를 켠 직후 한 번만 보여주는 복구 키
+    const [recoveryKey, setRecoveryKey] = useState<string | null>(null);
+    const [message, setMessage] = useState<string | null>(null);
+    const [error, setError] = useState<string | null>(null);
+    const [busy, setBusy] = useState(false);
+
+    const refresh = () => getLibraryStatus().then(setStatus).catch(err => setError(String(err)));
+
+    useEffect(() => {
+        refresh();
+    }, []);
+
+    const run = async (action: () => Promise<void>) => {
+        setBusy(true);
+        setError(null);
+        setMessage(null);
+        try {
+            await action();
+            await refresh();
+        } catch (err) {
+            // 저장 다이얼로그를 취소한 경우는 오류로 표시하지 않음
+            if (err !== 'No file selected') setError(String(err));
+        } finally {
+            setBusy(false);
+        }
+    };
+
+    const handleEnable = () => run(async () => {
+        if (password !== confirm) throw 'Passwords do not match';
+        setRecoveryKey(await enableLibraryEncryption(password));
+        setConfirm('');
+    });
+
+    const handleExport = () => run(async () => {
+        const path = await exportRecoveryKey(password);
+        // 새 복구 키를 발급했으므로 이전 키는 더 이상 쓸 수 없음
+        setRecoveryKey(null);
+        setMessage(`Recovery key saved to ${path}. Previous recovery keys no longer work.`);
+    });
+
+    const handleDisable = () => run(async () => {
+        await disableLibraryEncryption(password);
+        setPassword('');
+        setRecoveryKey(null);
+        setMessage('Library encryption is off.');
+    });
+
+    const handleLock = () => run(async () => {
+        await lockLibrary();
+    });
+
+    const inputClass = 'border border-gray-300 rounded-md px-2 py-1 text-sm';
+    const buttonClass = 'rounded-md px-3 py-1 text-sm border border-gray-300 !bg-white hover:!bg-gray-100 disabled:opacity-50';
+
+    return (
+        <div
+            className="absolute inset-0 z-50 flex items-center justify-center bg-black/20"
+            onMouseDown={(e) => e.stopPropagation()}
+        >
+            <div className="w-80 flex flex-col gap-3 bg-white rounded-lg shadow-md p-5">
+                <div className="flex items-center">
+                    <h2 className="text-base font-semibold text-gray-800">Library Encryption</h2>
+                    <div className="flex-grow" />
+                    <button onClick={onClose} title="Close" className="!p-1 !bg-transparent !border-none !shadow-none">
+                        <X size={16} />
+                    </button>
+                </div>
+
+                {status && !status.enabled && (
+                    <>
+                        <p className="text-xs text-gray-500">
+                            Encrypts the index, notes and images. You will be asked for the master password every time the app starts.
+                        </p>
+                        <input type="password" value={password} onChange={(e) => setPassword(e.target.value)} placeholder="Master password" className={inputClass} />
+                        <input type="password" value={confirm} onChange={(e) => setConfirm(e.target.value)} placeholder="Confirm password" className={inputClass} />
+                        <button onClick={handleEnable} disabled={busy || !password} className={buttonClass}>
+                            {busy ? 'Encrypting...' : 'Turn on encryption'}
+                        </button>
+                    </>
+                )}
+
+                {status?.enabled && (
+                    <>
+                        <input type="password" value={password} onChange={(e) => setPassword(e.target.value)} placeholder="Master password" className={inputClass} />
+                        <div className="flex flex-wrap gap-2">
+                            <button onClick={handleExport} disabled={busy || !password} className={buttonClass}>Export recovery key</button>
+                            <button onClick={handleDisable} disabled={busy || !password} className={buttonClass}>Turn off encryption</button>
+                            <button onClick={handleLock} disabled={busy} className={buttonClass}>Lock now</button>
+                        </div>
+                    </>
+                )}
+
+                {recoveryKey && (
+                    <div className="flex flex-col gap-2 rounded-md bg-yellow-50 border border-yellow-200 p-2">
+                        <p className="text-xs text-gray-700">
+                            Store this recovery key somewhere safe. It is the only way to open the library if you forget the password.
+                        </p>
+                        <code className="text-xs break-all select-all">{recoveryKey}</code>
+                        <button onClick={handleExport} disabled={busy || !password} className={buttonClass}>Save to file</button>
+                    </div>
+                )}
+
+                {message && <p className="text-xs text-gray-600 break-all">{message}</p>}
+                {error && <p className="text-xs text-red-500">{error}</p>}
+            </div>
+        </div>
+    );
+};
@@ -2,15 +2,23 @@ import React, { useState, useEffect, useRef } from 'react';
 import { invoke } from '@tauri-apps/api/core';
 import { listen } from '@tauri-apps/api/event';
 import { getCurrentWindow } from '@tauri-apps/api/window';
-import { NotesIndex } from '../types/note';
+import { NoteMetadata, NotesIndex } from '../types/note';
 import { TitleBar } from './TitleBar';
 import { useWindowResize } from '../hooks/useWindowResize';
+import { useWindowSnap } from '../hooks/useWindowSnap';
 import { CodeMirrorEditor } from './Editor/CodeMirrorEditor';
 
 interface NoteProps {
     noteId?: string | null;
 }
 
+// 템플릿으로 만든 노트는 윈도우 URL의 cursor 파라미터로 커서 위치를 받음
+const readInitialCursor = (): number | undefined => {
+    const value = new URLSearchParams(window.location.search).get('cursor');
+    const cursor = value === null ? NaN : Number(value);
+    return Number.isInteger(cursor) && cursor >= 0 ? cursor : undefined;
+};
+
 export const Note = ({ noteId }: NoteProps) => {
     // 메모 내용 상태 관리
     const [content, setContent] = useState<string>('');
@@ -36,9 +44,11 @@ export const Note = ({ noteId }: NoteProps) => {
     const [isLoaded, setIsLoaded] = useState<boolean>(false);
     // 줌 레벨 상태 관리
     const [zoomLevel, setZoomLevel] = useState<number>(1);
+    const [initialCursor] = useState(readInitialCursor);
 
     // Window Resize Handler
     useWindowResize(noteId || null);
+    useWindowSnap();
 
     // 메뉴 이벤트 리스너
     useEffect(() => {
@@ -58,6 +68,10 @@ export const Note = ({ noteId }: NoteProps) => {
             } else if (id.startsWith('color_')) {
                 const color = id.replace('color_', '');
                 setBgColor(color);
+            } else if (id === 'copy_link' && noteId) {
+                invoke<string>('copy_note_link', { id: noteId })
+                    .then(link => navigator.clipboard.writeText(link))
+                    .catch(console.error);
             }
         });
 
@@ -72,7 +86,7 @@ export const Note = ({ noteId }: NoteProps) => {
             unlistenPromise.then(unlisten => unlisten());
             document.removeEventListener('dragover', handleGlobalDragOver);
         };
-    }, []);
+    }, [noteId]);
 
     // 노트 데이터 로드
     useEffect(() => {
@@ -90,10 +104,11 @@ export const Note = ({ noteId }: NoteProps) => {
                 setIsDirty(false);
 
                 // Load metadata to get filePath
-                const index = await invoke<NotesIndex>('get_notes_list');
+                const index = await invoke<NotesIndex>('get_notes_list', { includeArchived: true });
                 const note = index.notes.find(n => n.id === noteId);
                 if (note) {
                     setFilePath(note.file_path);
+                    if (note.color) setBgColor(note.color);
                     console.log(`Loaded file path: ${note.file_path}`);
                 }
                 setIsLoaded(true);
@@ -118,9 +133,18 @@ export const Note = ({ noteId }: NoteProps) => {
         try {
             const currentContent = contentRef.current;
             let currentPath = filePathRef.current;
-
-            if (!currentPath) {
-                // 새 파일: 다이얼로그로 저장
+            // save_note_by_id는 제목/수정 시각 갱신까지 처리하므로 register_note가 필요 없음
+            let registered = false;
+
+            if (!currentPath && noteId) {
+                // 새 노트: 노트 폴더에 제목 기반 파일 이름으로 저장하고 인덱스에 등록
+                const note = await invoke<NoteMetadata>('save_new_note', { id: noteId, content: currentContent });
+                currentPath = note.file_path;
+                setFilePath(currentPath);
+                filePathRef.current = currentPath;
+                registered = true;
+            } else if (!currentPath) {
+                // 노트 id 없이 연 편집기: 다이얼로그로 저장
                 console.log('New file, showing dialog...');
                 try {
                     currentPath = await invoke<string>('save_note_with_dialog', { content: currentContent });
@@ -134,13 +158,22 @@ export const Note = ({ noteId }: NoteProps) => {
                 } else {
                     return false;
                 }
+            } else if (noteId) {
+                // 기존 파일: 인덱스에 등록된 노트는 id로 저장, 아니면 경로로 저장 후 등록
+                try {
+                    await invoke('save_note_by_id', { id: noteId, content: currentContent });
+                    registered = true;
+                } catch (error) {
+                    // 인덱스에 없는 노트만 경로로 저장 (잠금, 쓰기 실패 등은 그대로 실패 처리)
+                    if (error !== 'Note not found') throw error;
+                    await invoke('save_note', { path: currentPath, content: currentContent });
+                }
             } else {
-                // 기존 파일: 즉시 저장
                 await invoke('save_note', { path: currentPath, content: currentContent });
             }
 
             // 인덱스 등록/갱신
-            if (noteId && currentPath) {
+            if (noteId && currentPath && !registered) {
                 const title = currentContent.split('\n')[0]?.replace(/^#+\s*/, '').trim().substring(0, 50) || 'Untitled Note';
                 await invoke('register_note', {
                     id: noteId,
@@ -158,6 +191,33 @@ export const Note = ({ noteId }: NoteProps) => {
         }
     };
 
+    // 백엔드에서 파일이 수정/이동된 경우(할 일 체크, 그룹 폴더 이동 등)
+    // 파일 경로는 항상 갱신하고, 저장되지 않은 변경이 없으면 내용도 다시 불러오기
+    useEffect(() => {
+        const unlistenPromise = listen<string>('note-file-changed', async (event) => {
+            if (!noteId || event.payload !== noteId) return;
+            try {
+                const index = await invoke<NotesIndex>('get_notes_list', { includeArchived: true });
+                const note = index.notes.find(n => n.id === noteId);
+                if (note) {
+                    setFilePath(note.file_path);
+                    if (note.color) setBgColor(note.color);
+                    filePathRef.current = note.file_path;
+                }
+
+                if (isDirtyRef.current) return;
+                const reloaded = await invoke<string>('load_note_content', { id: noteId });
+                setContent(reloaded);
+            } catch (error) {
+                console.error('Failed to reload note:', error);
+            }
+        });
+
+        return () => {
+            unlistenPromise.then(unlisten => unlisten());
+        };
+    }, [noteId]);
+
     // Ctrl+S Keydown Handler
     useEffect(() => {
         const handleKeyDown = async (e: KeyboardEvent) => {
@@ -258,6 +318,7 @@ export const Note = ({ noteId }: NoteProps) => {
                 {isLoaded && (
                     <CodeMirrorEditor
                         initialContent={content}
+                        initialCursor={initialCursor}
                         onChange={handleContentChange}
                         className="text-gray-800"
                     />
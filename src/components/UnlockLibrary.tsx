@@ -0,0 +1,67 @@
+import React, { useState } from 'react';
+import { Lock } from 'lucide-react';
+import { unlockLibrary } from '../api/library';
+
+interface UnlockLibraryProps {
+    onUnlocked: () => void;
+}
+
+// 라이브러리 암호화가 켜져 있을 때 앱 시작 시 표시하는 마스터 비밀번호 입력 화면
+export const UnlockLibrary: React.FC<UnlockLibraryProps> = ({ onUnlocked }) => {
+    const [secret, setSecret] = useState('');
+    // 비밀번호를 잊은 경우 복구 키로 잠금 해제
+    const [useRecoveryKey, setUseRecoveryKey] = useState(false);
+    const [error, setError] = useState<string | null>(null);
+    const [busy, setBusy] = useState(false);
+
+    const handleSubmit = async (e: React.FormEvent) => {
+        e.preventDefault();
+        if (!secret || busy) return;
+
+        setBusy(true);
+        setError(null);
+        try {
+            await unlockLibrary(useRecoveryKey ? { recoveryKey: secret.trim() } : { password: secret });
+            setSecret('');
+            onUnlocked();
+        } catch (err) {
+            setError(String(err));
+        } finally {
+            setBusy(false);
+        }
+    };
+
+    return (
+        <div className="h-screen w-screen flex items-center justify-center bg-[#F6F6F6] select-none">
+            <form onSubmit={handleSubmit} className="w-72 flex flex-col gap-3 bg-white rounded-lg shadow-md p-6">
+                <div className="flex items-center gap-2 text-gray-800">
+                    <Lock size={18} />
+                    <h1 className="text-base font-semibold">Library is locked</h1>
+                </div>
+                <input
+                    type="password"
+                    autoFocus
+                    value={secret}
+                    onChange={(e) => setSecret(e.target.value)}
+                    placeholder={useRecoveryKey ? 'Recovery key' : 'Master password'}
+                    className="border border-gray-300 rounded-md px-2 py-1 text-sm"
+                />
+                {error && <p className="text-xs text-red-500">{error}</p>}
+                <button
+                    type="submit"
+                    disabled={busy || !secret}
+                    className="rounded-md px-3 py-1 text-sm text-white bg-blue-500 hover:bg-blue-600 disabled:opacity-50"
+                >
+                    {busy ? 'Unlocking...' : 'Unlock'}
+                </button>
+                <button
+                    type="button"
+                    onClick={() => { setUseRecoveryKey(prev => !prev); setSecret(''); setError(null); }}
+                    className="text-xs text-gray-500 hover:text-gray-700 !bg-transparent !border-none !shadow-none"
+                >
+                    {useRecoveryKey ? 'Use master password' : 'Forgot password? Use recovery key'}
+                </button>
+            </form>
+        </div>
+    );
+};
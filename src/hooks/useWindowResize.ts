@@ -13,29 +13,25 @@ export const useWindowResize = (id: string | null) => {
 
             timeoutRef.current = setTimeout(async () => {
                 try {
-                    const window = getCurrentWindow();
-                    const size = await window.innerSize();
+                    console.log(`Saving window state for ${id || 'main'}`);
 
-                    console.log(`Saving window state for ${id || 'main'}: ${size.width}x${size.height}`);
-
-                    await invoke('save_window_state', {
-                        id: id,
-                        width: Number(size.width),
-                        height: Number(size.height)
-                    });
+                    // 위치(물리 좌표)와 크기(논리 크기)는 백엔드에서 윈도우의 배율로 변환해 저장
+                    await invoke('save_window_state', { id: id });
                 } catch (error) {
                     console.error('Failed to save window state:', error);
                 }
             }, 1000); // 1 second debounce
         };
 
-        const unlistenPromise = getCurrentWindow().onResized(handleResize);
+        const unlistenResizePromise = getCurrentWindow().onResized(handleResize);
+        const unlistenMovePromise = getCurrentWindow().onMoved(handleResize);
 
         return () => {
             if (timeoutRef.current) {
                 clearTimeout(timeoutRef.current);
             }
-            unlistenPromise.then(unlisten => unlisten());
+            unlistenResizePromise.then(unlisten => unlisten());
+            unlistenMovePromise.then(unlisten => unlisten());
         };
     }, [id]);
 };
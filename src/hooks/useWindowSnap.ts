@@ -0,0 +1,75 @@
+import { useEffect } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { getCurrentWindow } from '@tauri-apps/api/window';
+
+// 이동이 멈춘 뒤 붙이기까지 기다리는 시간 (ms)
+const SETTLE_MS = 150;
+
+// 드래그가 끝나면 (마우스 버튼을 놓으면) 화면 가장자리나 다른 노트에 붙이기
+// 버튼을 누른 채 잠시 멈춘 경우에는 붙이지 않음
+export const useWindowSnap = () => {
+    useEffect(() => {
+        let timeout: ReturnType<typeof setTimeout> | null = null;
+        // 드래그 영역을 누른 뒤 아직 놓지 않은 상태
+        let pressed = false;
+        // 누른 동안 윈도우가 움직였는지
+        let movedWhilePressed = false;
+
+        const scheduleSnap = () => {
+            if (timeout) {
+                clearTimeout(timeout);
+            }
+            timeout = setTimeout(async () => {
+                try {
+                    await invoke<boolean>('snap_note_window');
+                } catch (error) {
+                    console.error('Failed to snap window:', error);
+                }
+            }, SETTLE_MS);
+        };
+
+        const handleMove = () => {
+            if (pressed) {
+                movedWhilePressed = true;
+                return;
+            }
+            // 드래그가 아닌 이동(키보드, 정렬 명령 등)은 멈춘 뒤 바로 붙이기
+            scheduleSnap();
+        };
+
+        const handlePointerDown = (event: PointerEvent) => {
+            if (event.button === 0) {
+                pressed = true;
+                movedWhilePressed = false;
+            }
+        };
+
+        // OS의 윈도우 이동 중에는 pointerup이 전달되지 않을 수 있으므로
+        // 버튼이 눌리지 않은 pointermove도 놓은 것으로 처리
+        const handlePointerRelease = (event: PointerEvent) => {
+            if (!pressed || (event.type === 'pointermove' && event.buttons !== 0)) {
+                return;
+            }
+            pressed = false;
+            if (movedWhilePressed) {
+                movedWhilePressed = false;
+                scheduleSnap();
+            }
+        };
+
+        window.addEventListener('pointerdown', handlePointerDown, true);
+        window.addEventListener('pointerup', handlePointerRelease, true);
+        window.addEventListener('pointermove', handlePointerRelease, true);
+        const unlistenPromise = getCurrentWindow().onMoved(handleMove);
+
+        return () => {
+            if (timeout) {
+                clearTimeout(timeout);
+            }
+            window.removeEventListener('pointerdown', handlePointerDown, true);
+            window.removeEventListener('pointerup', handlePointerRelease, true);
+            window.removeEventListener('pointermove', handlePointerRelease, true);
+            unlistenPromise.then(unlisten => unlisten());
+        };
+    }, []);
+};
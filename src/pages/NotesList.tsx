@@ -1,15 +1,32 @@
-import React, { useState, useEffect } from 'react';
+import React, { useState, useEffect, useRef } from 'react';
 import { invoke } from '@tauri-apps/api/core';
 import { getCurrentWindow } from '@tauri-apps/api/window';
-import { listen } from '@tauri-apps/api/event';
+import { emit, listen } from '@tauri-apps/api/event';
 import { ask } from '@tauri-apps/plugin-dialog';
-import { Trash2, FolderOpen, SquarePen, Minus, X } from 'lucide-react';
-import type { NotesIndex, NoteMetadata } from '../types/note';
+import { Trash2, FolderOpen, SquarePen, Minus, X, Pin, Archive, Lock } from 'lucide-react';
+import type { NotesIndex, NoteMetadata, NoteSort } from '../types/note';
 import { useWindowResize } from '../hooks/useWindowResize';
+import { LibrarySecurity } from '../components/LibrarySecurity';
+import { LIBRARY_LOCKED } from '../api/library';
+import { TemplateInfo, createNoteFromTemplate, listTemplates } from '../api/templates';
+
+interface DeepLinkSearch {
+    query: string;
+    notes: NoteMetadata[];
+}
 
 export const NotesList: React.FC = () => {
     const [notes, setNotes] = useState<NoteMetadata[]>([]);
     const [loading, setLoading] = useState(true);
+    // 정렬 기준 (다음 실행 시에도 유지)
+    const [sort, setSort] = useState<NoteSort>(() => (localStorage.getItem('notes-sort') as NoteSort) || 'updated');
+    const sortRef = useRef(sort);
+    const [showSecurity, setShowSecurity] = useState(false);
+    const [templates, setTemplates] = useState<TemplateInfo[]>([]);
+    // 딥 링크(stickermd://search?q=...)로 받은 검색어와 결과, 없으면 전체 목록 표시
+    const [search, setSearch] = useState<DeepLinkSearch | null>(null);
+    const searchRef = useRef(search);
+    useEffect(() => { searchRef.current = search; }, [search]);
 
     // Window Resize Handler (Main Window)
     useWindowResize(null);
@@ -17,10 +34,12 @@ export const NotesList: React.FC = () => {
     // 메모 목록 로드
     const loadNotes = async () => {
         try {
-            const index = await invoke<NotesIndex>('get_notes_list');
+            const index = await invoke<NotesIndex>('get_notes_list', { sort: sortRef.current });
             setNotes(index.notes);
         } catch (error) {
             console.error('Failed to load notes:', error);
+            // 라이브러리가 잠겨 있으면 비밀번호 입력 화면으로 (App에서 처리)
+            if (error === LIBRARY_LOCKED) emit('library-locked');
         } finally {
             setLoading(false);
         }
@@ -29,10 +48,22 @@ export const NotesList: React.FC = () => {
     // 이벤트 리스너 설정
     useEffect(() => {
         loadNotes();
+        listTemplates().then(setTemplates).catch(error => console.error('Failed to load templates:', error));
 
         const unlistenPromise = listen('refresh-notes-list', () => {
             console.log('Refreshing notes list...');
             loadNotes();
+            // 검색 결과를 보고 있으면 같은 검색어로 다시 검색
+            const current = searchRef.current;
+            if (current) {
+                invoke<NoteMetadata[]>('search_notes', { query: current.query, includeArchived: false })
+                    .then(notes => setSearch(prev => prev && prev.query === current.query ? { query: current.query, notes } : prev))
+                    .catch(error => console.error('Failed to refresh search:', error));
+            }
+        });
+
+        const unlistenSearchPromise = listen<DeepLinkSearch>('deep-link-search', (event) => {
+            setSearch(event.payload);
         });
 
         const unlistenFileDropPromise = listen('tauri://drag-drop', (event) => {
@@ -55,6 +86,7 @@ export const NotesList: React.FC = () => {
 
         return () => {
             unlistenPromise.then(unlisten => unlisten());
+            unlistenSearchPromise.then(unlisten => unlisten());
             unlistenFileDropPromise.then(unlisten => unlisten());
         };
     }, []);
@@ -89,6 +121,57 @@ export const NotesList: React.FC = () => {
         }
     };
 
+    // 정렬 기준 변경
+    // 템플릿으로 새 메모 만들기 (백엔드가 윈도우를 열고 {{cursor}} 위치에 커서를 둠)
+    const handleCreateFromTemplate = async (name: string) => {
+        if (!name) return;
+        try {
+            await createNoteFromTemplate(name);
+        } catch (error) {
+            console.error('Failed to create note from template:', error);
+            alert(`Failed to create note from template: ${error}`);
+        }
+    };
+
+    const handleSortChange = (newSort: NoteSort) => {
+        sortRef.current = newSort;
+        setSort(newSort);
+        localStorage.setItem('notes-sort', newSort);
+        loadNotes();
+    };
+
+    // 메모 고정/해제
+    const handleTogglePin = async (note: NoteMetadata) => {
+        try {
+            await invoke('set_note_pinned', { id: note.id, pinned: !note.pinned });
+        } catch (error) {
+            console.error('Failed to pin note:', error);
+        }
+    };
+
+    // 메모 보관 (목록에서 숨기고 윈도우 닫기, 파일은 유지)
+    const handleArchiveNote = async (noteId: string) => {
+        try {
+            await invoke('archive_note', { id: noteId });
+        } catch (error) {
+            console.error('Failed to archive note:', error);
+        }
+    };
+
+    // 드래그로 순서 변경 (직접 정렬 모드에서만)
+    const [draggingId, setDraggingId] = useState<string | null>(null);
+    const handleDropOnNote = async (targetId: string) => {
+        if (!draggingId || draggingId === targetId) return;
+        const ids = notes.map(n => n.id).filter(id => id !== draggingId);
+        ids.splice(ids.indexOf(targetId), 0, draggingId);
+        setDraggingId(null);
+        try {
+            await invoke('reorder_notes', { ids });
+        } catch (error) {
+            console.error('Failed to reorder notes:', error);
+        }
+    };
+
     // 메모 윈도우 열기
     const handleOpenNote = async (noteId: string) => {
         await invoke('frontend_log', { message: `Attempting to open note window for ID: ${noteId}` });
@@ -158,6 +241,8 @@ export const NotesList: React.FC = () => {
         }
     };
 
+    const visibleNotes = search ? search.notes : notes;
+
     if (loading) {
         return (
             <div className="flex items-center justify-center h-screen">
@@ -168,9 +253,10 @@ export const NotesList: React.FC = () => {
 
     return (
         <div
-            className="h-full w-full bg-[#F6F6F6] flex flex-col border border-gray-300 rounded-lg overflow-hidden shadow-xl"
+            className="relative h-full w-full bg-[#F6F6F6] flex flex-col border border-gray-300 rounded-lg overflow-hidden shadow-xl"
             onContextMenu={(e) => e.preventDefault()}
         >
+            {showSecurity && <LibrarySecurity onClose={() => setShowSecurity(false)} />}
             {/* Header Area - Draggable */}
             <div
                 className="h-12 bg-white border-b border-gray-200 flex items-center px-4 shrink-0 select-none cursor-move"
@@ -182,6 +268,34 @@ export const NotesList: React.FC = () => {
                 {/* Spacer to push controls to right */}
                 <div className="flex-grow" />
 
+                {/* 2. Sort */}
+                <select
+                    value={sort}
+                    onChange={(e) => handleSortChange(e.target.value as NoteSort)}
+                    onMouseDown={(e) => e.stopPropagation()}
+                    className="text-sm text-gray-700 bg-white border border-gray-200 rounded-md px-2 py-1 cursor-pointer"
+                    title="Sort"
+                >
+                    <option value="updated">Last updated</option>
+                    <option value="created">Date created</option>
+                    <option value="title">Title</option>
+                    <option value="manual">Manual</option>
+                </select>
+
+                {/* Template */}
+                {templates.length > 0 && (
+                    <select
+                        value=""
+                        onChange={(e) => handleCreateFromTemplate(e.target.value)}
+                        onMouseDown={(e) => e.stopPropagation()}
+                        className="ml-2 text-sm text-gray-700 bg-white border border-gray-200 rounded-md px-2 py-1 cursor-pointer"
+                        title="New Note from Template"
+                    >
+                        <option value="" disabled>Template...</option>
+                        {templates.map(t => <option key={t.name} value={t.name}>{t.name}</option>)}
+                    </select>
+                )}
+
                 {/* 3. Right: Controls */}
                 <div className="flex items-center gap-1 ml-4">
                     {/* New Note Button */}
@@ -204,6 +318,16 @@ export const NotesList: React.FC = () => {
                         <FolderOpen size={20} strokeWidth={2} color="#000000" style={{ minWidth: '20px', minHeight: '20px', display: 'block' }} />
                     </button>
 
+                    {/* Library Encryption Button */}
+                    <button
+                        onClick={(e) => { e.stopPropagation(); setShowSecurity(true); }}
+                        className="w-8 h-8 flex items-center justify-center !bg-white hover:!bg-gray-100 rounded-md text-gray-600 transition-colors cursor-pointer z-50 !border-none !shadow-none"
+                        title="Library Encryption"
+                        onMouseDown={(e) => e.stopPropagation()}
+                    >
+                        <Lock size={20} strokeWidth={2} color="#000000" style={{ minWidth: '20px', minHeight: '20px', display: 'block' }} />
+                    </button>
+
                     <div className="w-px h-4 bg-gray-300 mx-1"></div>
 
                     {/* Window Controls */}
@@ -226,9 +350,30 @@ export const NotesList: React.FC = () => {
                 </div>
             </div>
 
+            {/* Search Results Bar */}
+            {search && (
+                <div className="h-9 bg-blue-50 border-b border-blue-100 flex items-center px-4 shrink-0 text-sm text-gray-700 select-none">
+                    <span className="truncate">
+                        {search.notes.length} result{search.notes.length === 1 ? '' : 's'} for "{search.query}"
+                    </span>
+                    <div className="flex-grow" />
+                    <button
+                        onClick={() => setSearch(null)}
+                        className="!p-1 !bg-transparent !border-none !shadow-none text-gray-500 hover:text-gray-800"
+                        title="Clear Search"
+                    >
+                        <X size={14} />
+                    </button>
+                </div>
+            )}
+
             {/* Notes Grid Area */}
             <div className="flex-1 overflow-auto p-6">
-                {notes.length === 0 ? (
+                {search && search.notes.length === 0 ? (
+                    <div className="text-center py-12 select-none">
+                        <p className="text-gray-400 text-lg font-medium">No matching notes</p>
+                    </div>
+                ) : visibleNotes.length === 0 ? (
                     <div className="text-center py-12 select-none">
                         <p className="text-gray-400 text-lg font-medium">No notes yet</p>
                         <div className="text-gray-500 text-sm mt-2 flex items-center justify-center gap-1">
@@ -237,11 +382,15 @@ export const NotesList: React.FC = () => {
                     </div>
                 ) : (
                     <div className="grid grid-cols-1 md:grid-cols-2 lg:grid-cols-3 gap-4">
-                        {notes.map((note) => (
+                        {visibleNotes.map((note) => (
                             <div
                                 key={note.id}
                                 className="relative bg-[#FFF7D1] rounded-lg shadow-sm hover:shadow-md transition-all duration-200 p-4 cursor-pointer border border-gray-200/50 hover:border-blue-400 group"
                                 onClick={() => handleOpenNote(note.id)}
+                                draggable={sort === 'manual' && !search}
+                                onDragStart={() => setDraggingId(note.id)}
+                                onDragOver={(e) => e.preventDefault()}
+                                onDrop={(e) => { e.preventDefault(); handleDropOnNote(note.id); }}
                             >
                                 <h3 className="font-semibold text-gray-900 mb-2 truncate text-base group-hover:text-blue-600 transition-colors">
                                     {note.title}
@@ -250,6 +399,26 @@ export const NotesList: React.FC = () => {
                                     <p>Created: {note.created_at ? new Date(note.created_at).toLocaleString('ko-KR', { year: 'numeric', month: '2-digit', day: '2-digit', hour: '2-digit', minute: '2-digit', second: '2-digit', hour12: false }) : 'Unknown'}</p>
                                     <p>Updated: {note.updated_at ? new Date(note.updated_at).toLocaleString('ko-KR', { year: 'numeric', month: '2-digit', day: '2-digit', hour: '2-digit', minute: '2-digit', second: '2-digit', hour12: false }) : 'Unknown'}</p>
                                 </div>
+                                <button
+                                    onClick={(e) => {
+                                        e.stopPropagation();
+                                        handleTogglePin(note);
+                                    }}
+                                    className={`absolute top-2 right-16 !p-1.5 rounded-full !bg-transparent !border-none !shadow-none hover:!bg-gray-200 transition-all duration-200 z-10 ${note.pinned ? 'text-gray-700 opacity-100' : 'text-gray-400 opacity-0 group-hover:opacity-100'}`}
+                                    title={note.pinned ? 'Unpin Note' : 'Pin Note'}
+                                >
+                                    <Pin size={16} fill={note.pinned ? 'currentColor' : 'none'} />
+                                </button>
+                                <button
+                                    onClick={(e) => {
+                                        e.stopPropagation();
+                                        handleArchiveNote(note.id);
+                                    }}
+                                    className="absolute top-2 right-9 !p-1.5 rounded-full !bg-transparent !border-none !shadow-none hover:!bg-gray-200 text-gray-400 hover:text-gray-700 opacity-0 group-hover:opacity-100 transition-all duration-200 z-10"
+                                    title="Archive Note"
+                                >
+                                    <Archive size={16} />
+                                </button>
                                 <button
                                     onClick={(e) => {
                                         e.stopPropagation();
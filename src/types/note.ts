@@ -5,9 +5,33 @@ export interface NoteMetadata {
     file_path: string;    // 절대 경로
     created_at: string;   // ISO 8601 string
     updated_at: string;   // ISO 8601 string
+    color?: string | null; // 배경색 (예: #FFF7D1)
+    pinned?: boolean;
+    reminder_at?: string | null;         // ISO 8601 string
+    reminder_recurrence?: 'daily' | 'weekdays' | 'weekly' | 'monthly' | 'yearly' | null;
+    snoozed_until?: string | null;       // ISO 8601 string
+    reminder_fired?: boolean;
+    links?: string[];                    // [[위키 링크]] 대상
+    group_id?: string | null;            // 소속 노트북(그룹)
+    word_count?: number;
+    char_count?: number;
+    content_hash?: string | null;        // 본문 SHA-256
+    archived?: boolean;                  // 보관됨 (기본 목록에서 숨김)
+    archived_at?: string | null;         // ISO 8601 string
+}
+
+// 노트북(그룹) 타입 정의
+export interface NoteGroup {
+    id: string;
+    name: string;
+    color?: string | null;
 }
 
 // 메모 인덱스 타입 정의
 export interface NotesIndex {
     notes: NoteMetadata[];
+    groups?: NoteGroup[];  // 표시 순서대로 정렬됨
 }
+
+// 메모 목록 정렬 기준 (고정된 메모는 항상 맨 위)
+export type NoteSort = 'updated' | 'created' | 'title' | 'manual';